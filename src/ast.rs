@@ -5,16 +5,26 @@
 //! actual query building is in the [visitor](../visitor/index.html) module.
 //!
 //! For prelude, all important imports are in `quaint::ast::*`.
+mod alter_table;
+#[cfg(all(feature = "array", feature = "postgresql"))]
+mod array;
+mod case;
 mod column;
 mod compare;
 mod conditions;
 mod conjuctive;
+mod create_index;
+mod create_table;
+mod cte;
 mod delete;
+mod drop;
 mod expression;
 mod function;
 mod grouping;
 mod insert;
 mod join;
+#[cfg(feature = "json-1")]
+mod json;
 mod ops;
 mod ordering;
 mod over;
@@ -26,21 +36,36 @@ mod union;
 mod update;
 mod values;
 
+pub use alter_table::AlterTable;
+pub(crate) use alter_table::AlterTableChange;
+#[cfg(all(feature = "array", feature = "postgresql"))]
+pub use array::ArrayComparable;
+pub use case::{case, Case};
 pub use column::Column;
-pub use compare::{Comparable, Compare};
+pub use compare::{exists, not_exists, Comparable, Compare, ComparisonOperator};
 pub use conditions::ConditionTree;
 pub use conjuctive::Conjuctive;
+pub use create_index::CreateIndex;
+pub(crate) use create_table::DefaultValue;
+pub use create_table::{ColumnDefinition, ColumnType, CreateTable, ForeignKey, ReferentialAction};
+pub use cte::{CommonTableExpression, With};
 pub use delete::Delete;
+pub use drop::{DropIndex, DropTable};
 pub use expression::Expression;
 pub use function::*;
 pub use grouping::*;
 pub use insert::*;
 pub use join::{Join, JoinData, Joinable};
+#[cfg(feature = "json-1")]
+pub(crate) use json::json_path;
+#[cfg(feature = "json-1")]
+pub use json::{JsonComparable, JsonExtract, JsonPathElement};
 pub use ops::*;
 pub use ordering::{IntoOrderDefinition, Order, OrderDefinition, Orderable, Ordering};
 pub use over::*;
 pub use query::Query;
 pub use row::Row;
+pub(crate) use select::Distinct;
 pub use select::Select;
 pub use table::*;
 pub use union::Union;
@@ -0,0 +1,88 @@
+use crate::ast::*;
+use std::borrow::Cow;
+
+/// A column change of an `ALTER TABLE` statement.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum AlterTableChange<'a> {
+    Add(ColumnDefinition<'a>),
+    Drop(Cow<'a, str>),
+    Rename(Cow<'a, str>, Cow<'a, str>),
+}
+
+/// A builder for an `ALTER TABLE` statement. Every statement holds one
+/// change, SQLite can't do more in one statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterTable<'a> {
+    pub(crate) table: Table<'a>,
+    pub(crate) change: AlterTableChange<'a>,
+}
+
+impl<'a> From<AlterTable<'a>> for Query<'a> {
+    #[inline]
+    fn from(alter_table: AlterTable<'a>) -> Self {
+        Query::AlterTable(Box::new(alter_table))
+    }
+}
+
+impl<'a> AlterTable<'a> {
+    /// Adds a column to the table.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let column = ColumnDefinition::new("active", ColumnType::Boolean).not_null().default(true);
+    /// let (sql, _) = Postgres::build(AlterTable::add_column("users", column));
+    ///
+    /// assert_eq!(
+    ///     "ALTER TABLE \"users\" ADD COLUMN \"active\" BOOLEAN NOT NULL DEFAULT TRUE",
+    ///     sql
+    /// );
+    /// ```
+    pub fn add_column<T>(table: T, column: ColumnDefinition<'a>) -> Self
+    where
+        T: Into<Table<'a>>,
+    {
+        Self {
+            table: table.into(),
+            change: AlterTableChange::Add(column),
+        }
+    }
+
+    /// Drops a column of the table. Not supported on SQLite.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// let (sql, _) = Mysql::build(AlterTable::drop_column("users", "active"));
+    ///
+    /// assert_eq!("ALTER TABLE `users` DROP COLUMN `active`", sql);
+    /// ```
+    pub fn drop_column<T, S>(table: T, column: S) -> Self
+    where
+        T: Into<Table<'a>>,
+        S: Into<Cow<'a, str>>,
+    {
+        Self {
+            table: table.into(),
+            change: AlterTableChange::Drop(column.into()),
+        }
+    }
+
+    /// Renames a column of the table. Needs MySQL 8.0 or later.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let (sql, _) = Sqlite::build(AlterTable::rename_column("users", "name", "full_name"));
+    ///
+    /// assert_eq!("ALTER TABLE `users` RENAME COLUMN `name` TO `full_name`", sql);
+    /// ```
+    pub fn rename_column<T, S, R>(table: T, from: S, to: R) -> Self
+    where
+        T: Into<Table<'a>>,
+        S: Into<Cow<'a, str>>,
+        R: Into<Cow<'a, str>>,
+    {
+        Self {
+            table: table.into(),
+            change: AlterTableChange::Rename(from.into(), to.into()),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::ast::{Column, Compare, DatabaseValue};
+
+/// Comparisons of PostgreSQL arrays. The right side is typically a column or
+/// an array bound with [ParameterizedValue::array](enum.ParameterizedValue.html#method.array).
+/// MySQL and SQLite return an `UnsupportedFeature` error for these.
+pub trait ArrayComparable<'a> {
+    /// Tests if the left side has all the elements of the right side, `@>`.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let query = Select::from_table("posts").so_that("tags".array_contains(ParameterizedValue::array(vec!["rust"])));
+    /// let (sql, params) = Postgres::build(query);
+    ///
+    /// assert_eq!("SELECT \"posts\".* FROM \"posts\" WHERE \"tags\" @> $1", sql);
+    /// assert_eq!(vec![ParameterizedValue::array(vec!["rust"])], params);
+    /// ```
+    fn array_contains<T>(self, array: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>;
+
+    /// Tests if the right side has all the elements of the left side, `<@`.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let allowed = ParameterizedValue::array(vec!["rust", "sql"]);
+    /// let query = Select::from_table("posts").so_that("tags".array_contained_by(allowed));
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!("SELECT \"posts\".* FROM \"posts\" WHERE \"tags\" <@ $1", sql);
+    /// ```
+    fn array_contained_by<T>(self, array: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>;
+
+    /// Tests if the sides have any elements in common, `&&`.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let query = Select::from_table("posts").so_that("tags".array_overlaps(Column::from("featured_tags")));
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!("SELECT \"posts\".* FROM \"posts\" WHERE \"tags\" && \"featured_tags\"", sql);
+    /// ```
+    fn array_overlaps<T>(self, array: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>;
+
+    /// Tests if the left side equals any element of the right side array,
+    /// `= ANY(..)`.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let query = Select::from_table("posts").so_that(DatabaseValue::from("rust").equals_any(Column::from("tags")));
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!("SELECT \"posts\".* FROM \"posts\" WHERE $1 = ANY(\"tags\")", sql);
+    /// ```
+    fn equals_any<T>(self, array: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>;
+}
+
+impl<'a> ArrayComparable<'a> for DatabaseValue<'a> {
+    #[inline]
+    fn array_contains<T>(self, array: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>,
+    {
+        Compare::ArrayContains(Box::new(self), Box::new(array.into()))
+    }
+
+    #[inline]
+    fn array_contained_by<T>(self, array: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>,
+    {
+        Compare::ArrayContainedBy(Box::new(self), Box::new(array.into()))
+    }
+
+    #[inline]
+    fn array_overlaps<T>(self, array: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>,
+    {
+        Compare::ArrayOverlaps(Box::new(self), Box::new(array.into()))
+    }
+
+    #[inline]
+    fn equals_any<T>(self, array: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>,
+    {
+        Compare::EqualsAny(Box::new(self), Box::new(array.into()))
+    }
+}
+
+impl<'a, U> ArrayComparable<'a> for U
+where
+    U: Into<Column<'a>>,
+{
+    #[inline]
+    fn array_contains<T>(self, array: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.array_contains(array)
+    }
+
+    #[inline]
+    fn array_contained_by<T>(self, array: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.array_contained_by(array)
+    }
+
+    #[inline]
+    fn array_overlaps<T>(self, array: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.array_overlaps(array)
+    }
+
+    #[inline]
+    fn equals_any<T>(self, array: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.equals_any(array)
+    }
+}
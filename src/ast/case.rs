@@ -0,0 +1,137 @@
+use crate::ast::*;
+
+/// A conditional `CASE WHEN` expression, evaluating to the value of the first
+/// matching condition, or to the `ELSE` value when nothing matches.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Case<'a> {
+    pub(crate) conditions: Vec<(ConditionTree<'a>, DatabaseValue<'a>)>,
+    pub(crate) otherwise: Option<DatabaseValue<'a>>,
+}
+
+/// Starts a `CASE` expression. Without an `otherwise` value, the expression
+/// is `NULL` when no condition matches. Without any `when` branches, only the
+/// `otherwise` value, or `NULL`, is rendered.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let status = case()
+///     .when("deleted_at".is_not_null(), "deleted")
+///     .when("confirmed".equals(true), "active")
+///     .otherwise("pending");
+///
+/// let query = Select::from_table("users").column("id").value(status);
+/// let (sql, params) = Sqlite::build(query);
+///
+/// assert_eq!(
+///     "SELECT `id`, CASE WHEN `deleted_at` IS NOT NULL THEN ? \
+///      WHEN `confirmed` = ? THEN ? ELSE ? END FROM `users`",
+///     sql
+/// );
+///
+/// assert_eq!(
+///     vec![
+///         ParameterizedValue::from("deleted"),
+///         ParameterizedValue::from(true),
+///         ParameterizedValue::from("active"),
+///         ParameterizedValue::from("pending"),
+///     ],
+///     params
+/// );
+/// ```
+#[inline]
+pub fn case<'a>() -> Case<'a> {
+    Case {
+        conditions: Vec::new(),
+        otherwise: None,
+    }
+}
+
+impl<'a> Case<'a> {
+    /// Adds a `WHEN` branch, evaluating to `value` if the condition matches
+    /// and no previous branch did.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let price = case()
+    ///     .when("quantity".greater_than(100), DatabaseValue::from(Column::from("price")) * 0.9.into())
+    ///     .otherwise(Column::from("price"));
+    ///
+    /// let query = Update::table("orders").set("price", price);
+    /// let (sql, _) = Sqlite::build(query);
+    ///
+    /// assert_eq!(
+    ///     "UPDATE `orders` SET `price` = CASE WHEN `quantity` > ? THEN (`price` * ?) ELSE `price` END",
+    ///     sql
+    /// );
+    /// ```
+    pub fn when<C, V>(mut self, condition: C, value: V) -> Self
+    where
+        C: Into<ConditionTree<'a>>,
+        V: Into<DatabaseValue<'a>>,
+    {
+        self.conditions.push((condition.into(), value.into()));
+        self
+    }
+
+    /// The `ELSE` value, used when none of the conditions match.
+    pub fn otherwise<V>(mut self, value: V) -> Self
+    where
+        V: Into<DatabaseValue<'a>>,
+    {
+        self.otherwise = Some(value.into());
+        self
+    }
+}
+
+impl<'a> From<Case<'a>> for DatabaseValue<'a> {
+    #[inline]
+    fn from(case: Case<'a>) -> Self {
+        DatabaseValue::Case(Box::new(case))
+    }
+}
+
+impl<'a> IntoOrderDefinition<'a> for Case<'a> {
+    #[inline]
+    fn into_order_definition(self) -> OrderDefinition<'a> {
+        (self.into(), None)
+    }
+}
+
+impl<'a> Orderable<'a> for Case<'a> {
+    /// Order by a custom sort order.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let priority = case()
+    ///     .when("status".equals("urgent"), 0)
+    ///     .when("status".equals("normal"), 1)
+    ///     .otherwise(2);
+    ///
+    /// let query = Select::from_table("tickets").order_by(priority.ascend());
+    /// let (sql, _) = Sqlite::build(query);
+    ///
+    /// assert_eq!(
+    ///     "SELECT `tickets`.* FROM `tickets` ORDER BY \
+    ///      CASE WHEN `status` = ? THEN ? WHEN `status` = ? THEN ? ELSE ? END ASC",
+    ///     sql
+    /// );
+    /// ```
+    #[inline]
+    fn order(self, order: Option<Order>) -> OrderDefinition<'a> {
+        (self.into(), order)
+    }
+}
+
+impl<'a> IntoGroupByDefinition<'a> for Case<'a> {
+    #[inline]
+    fn into_group_by_definition(self) -> GroupByDefinition<'a> {
+        self.into()
+    }
+}
+
+impl<'a> Groupable<'a> for Case<'a> {
+    #[inline]
+    fn group(self) -> GroupByDefinition<'a> {
+        self.into()
+    }
+}
@@ -1,5 +1,5 @@
-use crate::ast::{Column, ConditionTree, DatabaseValue, Expression};
-use std::borrow::Cow;
+use crate::ast::{Column, ConditionTree, DatabaseValue, Expression, Select};
+use std::{borrow::Cow, fmt};
 
 /// For modeling comparison expression
 #[derive(Debug, Clone, PartialEq)]
@@ -32,6 +32,33 @@ pub enum Compare<'a> {
     EndsInto(Box<DatabaseValue<'a>>, Cow<'a, str>),
     /// `left NOT LIKE %..`
     NotEndsInto(Box<DatabaseValue<'a>>, Cow<'a, str>),
+    /// `left ILIKE %..%`
+    ILike(Box<DatabaseValue<'a>>, Cow<'a, str>),
+    /// `left NOT ILIKE %..%`
+    NotILike(Box<DatabaseValue<'a>>, Cow<'a, str>),
+    /// `left ~ ..`
+    MatchesRegex(Box<DatabaseValue<'a>>, Cow<'a, str>),
+    #[cfg(feature = "json-1")]
+    /// `left @> right`
+    JsonContains(Box<DatabaseValue<'a>>, Box<DatabaseValue<'a>>),
+    #[cfg(feature = "json-1")]
+    /// `left ? key`
+    JsonHasKey(Box<DatabaseValue<'a>>, Cow<'a, str>),
+    #[cfg(feature = "json-1")]
+    /// `left ?| ARRAY[keys]`
+    JsonHasAnyKey(Box<DatabaseValue<'a>>, Vec<Cow<'a, str>>),
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    /// `left @> right`
+    ArrayContains(Box<DatabaseValue<'a>>, Box<DatabaseValue<'a>>),
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    /// `left <@ right`
+    ArrayContainedBy(Box<DatabaseValue<'a>>, Box<DatabaseValue<'a>>),
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    /// `left && right`
+    ArrayOverlaps(Box<DatabaseValue<'a>>, Box<DatabaseValue<'a>>),
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    /// `left = ANY(right)`
+    EqualsAny(Box<DatabaseValue<'a>>, Box<DatabaseValue<'a>>),
     /// `value IS NULL`
     Null(Box<DatabaseValue<'a>>),
     /// `value IS NOT NULL`
@@ -40,6 +67,102 @@ pub enum Compare<'a> {
     Between(Box<DatabaseValue<'a>>, Box<DatabaseValue<'a>>, Box<DatabaseValue<'a>>),
     /// `value` NOT BETWEEN `left` AND `right`
     NotBetween(Box<DatabaseValue<'a>>, Box<DatabaseValue<'a>>, Box<DatabaseValue<'a>>),
+    /// `EXISTS (SELECT ..)`
+    Exists(Box<Select<'a>>),
+    /// `NOT EXISTS (SELECT ..)`
+    NotExists(Box<Select<'a>>),
+    /// `left > ANY (SELECT ..)`
+    Any(Box<DatabaseValue<'a>>, ComparisonOperator, Box<Select<'a>>),
+    /// `left > ALL (SELECT ..)`
+    All(Box<DatabaseValue<'a>>, ComparisonOperator, Box<Select<'a>>),
+}
+
+/// The operator of a quantified `ANY` or `ALL` comparison.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComparisonOperator {
+    /// `=`
+    Equals,
+    /// `<>`
+    NotEquals,
+    /// `<`
+    LessThan,
+    /// `<=`
+    LessThanOrEquals,
+    /// `>`
+    GreaterThan,
+    /// `>=`
+    GreaterThanOrEquals,
+}
+
+impl ComparisonOperator {
+    /// Compares two values with the operator.
+    pub(crate) fn compare<'a>(self, left: DatabaseValue<'a>, right: DatabaseValue<'a>) -> Compare<'a> {
+        match self {
+            Self::Equals => left.equals(right),
+            Self::NotEquals => left.not_equals(right),
+            Self::LessThan => left.less_than(right),
+            Self::LessThanOrEquals => left.less_than_or_equals(right),
+            Self::GreaterThan => left.greater_than(right),
+            Self::GreaterThanOrEquals => left.greater_than_or_equals(right),
+        }
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Equals => write!(f, "="),
+            Self::NotEquals => write!(f, "<>"),
+            Self::LessThan => write!(f, "<"),
+            Self::LessThanOrEquals => write!(f, "<="),
+            Self::GreaterThan => write!(f, ">"),
+            Self::GreaterThanOrEquals => write!(f, ">="),
+        }
+    }
+}
+
+/// Tests if the query returns any rows.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let orders = Select::from_table("orders").so_that(("orders", "user_id").equals(Column::from(("users", "id"))));
+/// let query = Select::from_table("users").so_that(exists(orders));
+/// let (sql, _) = Sqlite::build(query);
+///
+/// assert_eq!(
+///     "SELECT `users`.* FROM `users` WHERE EXISTS \
+///      (SELECT `orders`.* FROM `orders` WHERE `orders`.`user_id` = `users`.`id`)",
+///     sql
+/// );
+/// ```
+#[inline]
+pub fn exists<'a, T>(selection: T) -> Compare<'a>
+where
+    T: Into<Select<'a>>,
+{
+    Compare::Exists(Box::new(selection.into()))
+}
+
+/// Tests if the query returns no rows.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let orders = Select::from_table("orders").so_that(("orders", "user_id").equals(Column::from(("users", "id"))));
+/// let query = Select::from_table("users").so_that(not_exists(orders));
+/// let (sql, _) = Sqlite::build(query);
+///
+/// assert_eq!(
+///     "SELECT `users`.* FROM `users` WHERE NOT EXISTS \
+///      (SELECT `orders`.* FROM `orders` WHERE `orders`.`user_id` = `users`.`id`)",
+///     sql
+/// );
+/// ```
+#[inline]
+pub fn not_exists<'a, T>(selection: T) -> Compare<'a>
+where
+    T: Into<Select<'a>>,
+{
+    Compare::NotExists(Box::new(selection.into()))
 }
 
 impl<'a> From<Compare<'a>> for ConditionTree<'a> {
@@ -180,6 +303,12 @@ pub trait Comparable<'a> {
 
     /// Tests if the left side is included in the right side collection.
     ///
+    /// Long lists of values would hit the parameter limit of the database.
+    /// PostgreSQL binds lists of more than 1000 values of the same type as a
+    /// single array. SQLite, with the `json-1` feature, binds lists of more
+    /// than 100 integers and strings as a single JSON array. Otherwise SQLite
+    /// returns an error for more than 999 parameters.
+    ///
     /// ```rust
     /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
     /// let query = Select::from_table("users").so_that("foo".in_selection(vec![1, 2]));
@@ -333,6 +462,71 @@ pub trait Comparable<'a> {
     where
         T: Into<Cow<'a, str>>;
 
+    /// Tests if the left side includes the right side string, ignoring the
+    /// case. Rendered as `ILIKE` on PostgreSQL and by comparing the lowercase
+    /// values elsewhere.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let query = Select::from_table("users").so_that("foo".ilike("bar"));
+    /// let (sql, params) = Sqlite::build(query);
+    ///
+    /// assert_eq!("SELECT `users`.* FROM `users` WHERE LOWER(`foo`) LIKE LOWER(?)", sql);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         ParameterizedValue::from("%bar%"),
+    ///     ],
+    ///     params
+    /// );
+    /// ```
+    fn ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>;
+
+    /// Tests if the left side does not include the right side string,
+    /// ignoring the case.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let query = Select::from_table("users").so_that("foo".not_ilike("bar"));
+    /// let (sql, params) = Postgres::build(query);
+    ///
+    /// assert_eq!("SELECT \"users\".* FROM \"users\" WHERE \"foo\" NOT ILIKE $1", sql);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         ParameterizedValue::from("%bar%"),
+    ///     ],
+    ///     params
+    /// );
+    /// ```
+    fn not_ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>;
+
+    /// Tests if the left side matches the regular expression. Uses `~` on
+    /// PostgreSQL and `REGEXP` on MySQL and SQLite, where the matching is
+    /// done with the [regex](https://docs.rs/regex) crate.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let query = Select::from_table("users").so_that("email".matches_regex("@example\\.(com|org)$"));
+    /// let (sql, params) = Sqlite::build(query);
+    ///
+    /// assert_eq!("SELECT `users`.* FROM `users` WHERE `email` REGEXP ?", sql);
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         ParameterizedValue::from("@example\\.(com|org)$"),
+    ///     ],
+    ///     params
+    /// );
+    /// ```
+    fn matches_regex<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>;
+
     /// Tests if the left side is `NULL`.
     ///
     /// ```rust
@@ -392,6 +586,44 @@ pub trait Comparable<'a> {
     where
         T: Into<DatabaseValue<'a>>,
         V: Into<DatabaseValue<'a>>;
+
+    /// Tests if the comparison is true for at least one row of the query.
+    /// SQLite emulates this with `EXISTS`, returning false where the other
+    /// databases return `NULL` because of a `NULL` comparison.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let prices = Select::from_table("offers").column("price");
+    /// let query = Select::from_table("products").so_that("price".any(ComparisonOperator::GreaterThan, prices));
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!(
+    ///     "SELECT \"products\".* FROM \"products\" WHERE \"price\" > ANY (SELECT \"price\" FROM \"offers\")",
+    ///     sql
+    /// );
+    /// ```
+    fn any<T>(self, operator: ComparisonOperator, selection: T) -> Compare<'a>
+    where
+        T: Into<Select<'a>>;
+
+    /// Tests if the comparison is true for every row of the query.
+    /// SQLite emulates this with `NOT EXISTS`, returning false where the
+    /// other databases return `NULL` because of a `NULL` comparison.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let prices = Select::from_table("offers").column("price");
+    /// let query = Select::from_table("products").so_that("price".all(ComparisonOperator::LessThan, prices));
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!(
+    ///     "SELECT \"products\".* FROM \"products\" WHERE \"price\" < ALL (SELECT \"price\" FROM \"offers\")",
+    ///     sql
+    /// );
+    /// ```
+    fn all<T>(self, operator: ComparisonOperator, selection: T) -> Compare<'a>
+    where
+        T: Into<Select<'a>>;
 }
 
 impl<'a, U> Comparable<'a> for U
@@ -539,6 +771,36 @@ where
         val.not_ends_into(pattern)
     }
 
+    #[inline]
+    fn ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.ilike(pattern)
+    }
+
+    #[inline]
+    fn not_ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.not_ilike(pattern)
+    }
+
+    #[inline]
+    fn matches_regex<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.matches_regex(pattern)
+    }
+
     #[inline]
     fn is_null(self) -> Compare<'a> {
         let col: Column<'a> = self.into();
@@ -574,4 +836,24 @@ where
         let val: DatabaseValue<'a> = col.into();
         val.not_between(left, right)
     }
+
+    #[inline]
+    fn any<T>(self, operator: ComparisonOperator, selection: T) -> Compare<'a>
+    where
+        T: Into<Select<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.any(operator, selection)
+    }
+
+    #[inline]
+    fn all<T>(self, operator: ComparisonOperator, selection: T) -> Compare<'a>
+    where
+        T: Into<Select<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.all(operator, selection)
+    }
 }
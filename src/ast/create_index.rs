@@ -0,0 +1,97 @@
+use crate::ast::*;
+use std::borrow::Cow;
+
+/// A builder for a `CREATE INDEX` statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateIndex<'a> {
+    pub(crate) name: Cow<'a, str>,
+    pub(crate) table: Table<'a>,
+    pub(crate) columns: Vec<Cow<'a, str>>,
+    pub(crate) unique: bool,
+    pub(crate) if_not_exists: bool,
+    pub(crate) conditions: Option<ConditionTree<'a>>,
+}
+
+impl<'a> From<CreateIndex<'a>> for Query<'a> {
+    #[inline]
+    fn from(create_index: CreateIndex<'a>) -> Self {
+        Query::CreateIndex(Box::new(create_index))
+    }
+}
+
+impl<'a> CreateIndex<'a> {
+    /// Creates a new `CREATE INDEX` statement over the given columns of the
+    /// table.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let query = CreateIndex::new("users_name_idx", "users", vec!["last_name", "first_name"]);
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!(
+    ///     "CREATE INDEX \"users_name_idx\" ON \"users\" (\"last_name\", \"first_name\")",
+    ///     sql
+    /// );
+    /// ```
+    pub fn new<S, T, I, C>(name: S, table: T, columns: I) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Table<'a>>,
+        I: IntoIterator<Item = C>,
+        C: Into<Cow<'a, str>>,
+    {
+        Self {
+            name: name.into(),
+            table: table.into(),
+            columns: columns.into_iter().map(Into::into).collect(),
+            unique: false,
+            if_not_exists: false,
+            conditions: None,
+        }
+    }
+
+    /// Makes the index unique.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// let query = CreateIndex::new("users_email_key", "users", vec!["email"]).unique();
+    /// let (sql, _) = Mysql::build(query);
+    ///
+    /// assert_eq!("CREATE UNIQUE INDEX `users_email_key` ON `users` (`email`)", sql);
+    /// ```
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Does nothing if the index already exists. Not supported on MySQL.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Indexes only the rows matching the conditions, making a partial index.
+    /// The values are written into the statement. Not supported on MySQL.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let query = CreateIndex::new("users_email_key", "users", vec!["email"])
+    ///     .unique()
+    ///     .so_that("deleted".equals(false));
+    ///
+    /// let (sql, params) = Sqlite::build(query);
+    ///
+    /// assert_eq!(
+    ///     "CREATE UNIQUE INDEX `users_email_key` ON `users` (`email`) WHERE `deleted` = FALSE",
+    ///     sql
+    /// );
+    /// assert!(params.is_empty());
+    /// ```
+    pub fn so_that<T>(mut self, conditions: T) -> Self
+    where
+        T: Into<ConditionTree<'a>>,
+    {
+        self.conditions = Some(conditions.into());
+        self
+    }
+}
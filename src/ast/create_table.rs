@@ -0,0 +1,364 @@
+use crate::ast::*;
+use std::{borrow::Cow, fmt};
+
+/// The type of a column in a `CREATE TABLE` or `ALTER TABLE` statement. Every
+/// database renders the type with its own name, e.g. `Bytes` is a `BYTEA` on
+/// PostgreSQL and a `BLOB` on MySQL and SQLite.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnType<'a> {
+    /// A 32-bit integer.
+    Integer,
+    /// A 64-bit integer.
+    BigInt,
+    Boolean,
+    Text,
+    /// A string of at most the given number of characters.
+    Varchar(u32),
+    /// A double precision floating point number.
+    Double,
+    /// An exact number with the given precision and scale.
+    Decimal(u8, u8),
+    Date,
+    Time,
+    /// A point in time.
+    DateTime,
+    Bytes,
+    Json,
+    Uuid,
+    /// A type written as given, for anything not covered by the other types.
+    Custom(Cow<'a, str>),
+}
+
+/// The default value of a column.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DefaultValue<'a> {
+    /// A value, rendered as a literal.
+    Value(DatabaseValue<'a>),
+    /// An SQL expression, written as given.
+    Expression(Cow<'a, str>),
+}
+
+/// A column of a `CREATE TABLE` or `ALTER TABLE ... ADD COLUMN` statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnDefinition<'a> {
+    pub(crate) name: Cow<'a, str>,
+    pub(crate) column_type: ColumnType<'a>,
+    pub(crate) nullable: bool,
+    pub(crate) default: Option<DefaultValue<'a>>,
+    pub(crate) primary_key: bool,
+    pub(crate) unique: bool,
+    pub(crate) auto_increment: bool,
+}
+
+impl<'a> ColumnDefinition<'a> {
+    /// A nullable column of the given type.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let query = CreateTable::new("users").column(ColumnDefinition::new("name", ColumnType::Varchar(255)));
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!("CREATE TABLE \"users\" (\"name\" VARCHAR(255))", sql);
+    /// ```
+    pub fn new<S>(name: S, column_type: ColumnType<'a>) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self {
+            name: name.into(),
+            column_type,
+            nullable: true,
+            default: None,
+            primary_key: false,
+            unique: false,
+            auto_increment: false,
+        }
+    }
+
+    /// Adds a `NOT NULL` constraint.
+    pub fn not_null(mut self) -> Self {
+        self.nullable = false;
+        self
+    }
+
+    /// Makes the column the primary key of the table.
+    pub fn primary_key(mut self) -> Self {
+        self.primary_key = true;
+        self
+    }
+
+    /// Adds a `UNIQUE` constraint.
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Generates the value of an integer column from a sequence. The column
+    /// should be the primary key, SQLite only allows `AUTOINCREMENT` on an
+    /// `INTEGER PRIMARY KEY`.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite, Postgres, Mysql}};
+    /// let query = CreateTable::new("users")
+    ///     .column(ColumnDefinition::new("id", ColumnType::Integer).primary_key().auto_increment());
+    ///
+    /// let (sqlite, _) = Sqlite::build(query.clone());
+    /// let (psql, _) = Postgres::build(query.clone());
+    /// let (mysql, _) = Mysql::build(query);
+    ///
+    /// assert_eq!("CREATE TABLE `users` (`id` INTEGER PRIMARY KEY AUTOINCREMENT)", sqlite);
+    /// assert_eq!("CREATE TABLE \"users\" (\"id\" SERIAL PRIMARY KEY)", psql);
+    /// assert_eq!("CREATE TABLE `users` (`id` INT PRIMARY KEY AUTO_INCREMENT)", mysql);
+    /// ```
+    pub fn auto_increment(mut self) -> Self {
+        self.auto_increment = true;
+        self
+    }
+
+    /// The default value of the column. Databases don't take parameters in
+    /// schema definitions, so the value is written into the statement.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let column = ColumnDefinition::new("name", ColumnType::Text).not_null().default("it's me");
+    /// let (sql, params) = Sqlite::build(CreateTable::new("users").column(column));
+    ///
+    /// assert_eq!("CREATE TABLE `users` (`name` TEXT NOT NULL DEFAULT 'it''s me')", sql);
+    /// assert!(params.is_empty());
+    /// ```
+    pub fn default<V>(mut self, value: V) -> Self
+    where
+        V: Into<DatabaseValue<'a>>,
+    {
+        self.default = Some(DefaultValue::Value(value.into()));
+        self
+    }
+
+    /// An SQL expression as the default value, written as given.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let column = ColumnDefinition::new("created_at", ColumnType::DateTime).default_expression("CURRENT_TIMESTAMP");
+    /// let (sql, _) = Postgres::build(CreateTable::new("users").column(column));
+    ///
+    /// assert_eq!(
+    ///     "CREATE TABLE \"users\" (\"created_at\" TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP)",
+    ///     sql
+    /// );
+    /// ```
+    pub fn default_expression<S>(mut self, expression: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.default = Some(DefaultValue::Expression(expression.into()));
+        self
+    }
+}
+
+/// What happens to the referencing rows when the referenced row is deleted
+/// or updated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReferentialAction {
+    Cascade,
+    Restrict,
+    NoAction,
+    SetNull,
+    SetDefault,
+}
+
+impl fmt::Display for ReferentialAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReferentialAction::Cascade => write!(f, "CASCADE"),
+            ReferentialAction::Restrict => write!(f, "RESTRICT"),
+            ReferentialAction::NoAction => write!(f, "NO ACTION"),
+            ReferentialAction::SetNull => write!(f, "SET NULL"),
+            ReferentialAction::SetDefault => write!(f, "SET DEFAULT"),
+        }
+    }
+}
+
+/// A foreign key constraint of a `CREATE TABLE` statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForeignKey<'a> {
+    pub(crate) columns: Vec<Cow<'a, str>>,
+    pub(crate) referenced_table: Table<'a>,
+    pub(crate) referenced_columns: Vec<Cow<'a, str>>,
+    pub(crate) on_delete: Option<ReferentialAction>,
+    pub(crate) on_update: Option<ReferentialAction>,
+}
+
+impl<'a> ForeignKey<'a> {
+    /// A foreign key from the columns to the referenced columns of the
+    /// referenced table, matching by position.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let query = CreateTable::new("posts")
+    ///     .column(ColumnDefinition::new("user_id", ColumnType::Integer).not_null())
+    ///     .foreign_key(ForeignKey::new(vec!["user_id"], "users", vec!["id"]).on_delete(ReferentialAction::Cascade));
+    ///
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!(
+    ///     "CREATE TABLE \"posts\" (\"user_id\" INTEGER NOT NULL, FOREIGN KEY (\"user_id\") REFERENCES \"users\" (\"id\") ON DELETE CASCADE)",
+    ///     sql
+    /// );
+    /// ```
+    pub fn new<I, S, T, J, R>(columns: I, referenced_table: T, referenced_columns: J) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'a, str>>,
+        T: Into<Table<'a>>,
+        J: IntoIterator<Item = R>,
+        R: Into<Cow<'a, str>>,
+    {
+        Self {
+            columns: columns.into_iter().map(Into::into).collect(),
+            referenced_table: referenced_table.into(),
+            referenced_columns: referenced_columns.into_iter().map(Into::into).collect(),
+            on_delete: None,
+            on_update: None,
+        }
+    }
+
+    /// The `ON DELETE` action.
+    pub fn on_delete(mut self, action: ReferentialAction) -> Self {
+        self.on_delete = Some(action);
+        self
+    }
+
+    /// The `ON UPDATE` action.
+    pub fn on_update(mut self, action: ReferentialAction) -> Self {
+        self.on_update = Some(action);
+        self
+    }
+}
+
+/// A builder for a `CREATE TABLE` statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateTable<'a> {
+    pub(crate) table: Table<'a>,
+    pub(crate) columns: Vec<ColumnDefinition<'a>>,
+    pub(crate) primary_key: Vec<Cow<'a, str>>,
+    pub(crate) unique: Vec<Vec<Cow<'a, str>>>,
+    pub(crate) foreign_keys: Vec<ForeignKey<'a>>,
+    pub(crate) if_not_exists: bool,
+}
+
+impl<'a> From<CreateTable<'a>> for Query<'a> {
+    #[inline]
+    fn from(create_table: CreateTable<'a>) -> Self {
+        Query::CreateTable(Box::new(create_table))
+    }
+}
+
+impl<'a> CreateTable<'a> {
+    /// Creates a new `CREATE TABLE` statement for the given table.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// let query = CreateTable::new("users")
+    ///     .column(ColumnDefinition::new("id", ColumnType::BigInt).primary_key().auto_increment())
+    ///     .column(ColumnDefinition::new("email", ColumnType::Varchar(255)).not_null().unique());
+    ///
+    /// let (sql, _) = Mysql::build(query);
+    ///
+    /// assert_eq!(
+    ///     "CREATE TABLE `users` (`id` BIGINT PRIMARY KEY AUTO_INCREMENT, `email` VARCHAR(255) NOT NULL UNIQUE)",
+    ///     sql
+    /// );
+    /// ```
+    pub fn new<T>(table: T) -> Self
+    where
+        T: Into<Table<'a>>,
+    {
+        Self {
+            table: table.into(),
+            columns: Vec::new(),
+            primary_key: Vec::new(),
+            unique: Vec::new(),
+            foreign_keys: Vec::new(),
+            if_not_exists: false,
+        }
+    }
+
+    /// Adds a column to the table.
+    pub fn column(mut self, column: ColumnDefinition<'a>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// A primary key of the given columns, for keys of more than one column.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let query = CreateTable::new("memberships")
+    ///     .column(ColumnDefinition::new("user_id", ColumnType::Integer))
+    ///     .column(ColumnDefinition::new("group_id", ColumnType::Integer))
+    ///     .primary_key(vec!["user_id", "group_id"]);
+    ///
+    /// let (sql, _) = Sqlite::build(query);
+    ///
+    /// assert_eq!(
+    ///     "CREATE TABLE `memberships` (`user_id` INTEGER, `group_id` INTEGER, PRIMARY KEY (`user_id`, `group_id`))",
+    ///     sql
+    /// );
+    /// ```
+    pub fn primary_key<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'a, str>>,
+    {
+        self.primary_key = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// A unique constraint over the given columns.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let query = CreateTable::new("users")
+    ///     .column(ColumnDefinition::new("first_name", ColumnType::Text))
+    ///     .column(ColumnDefinition::new("last_name", ColumnType::Text))
+    ///     .unique(vec!["first_name", "last_name"]);
+    ///
+    /// let (sql, _) = Sqlite::build(query);
+    ///
+    /// assert_eq!(
+    ///     "CREATE TABLE `users` (`first_name` TEXT, `last_name` TEXT, UNIQUE (`first_name`, `last_name`))",
+    ///     sql
+    /// );
+    /// ```
+    pub fn unique<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'a, str>>,
+    {
+        self.unique.push(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Adds a foreign key constraint.
+    pub fn foreign_key(mut self, foreign_key: ForeignKey<'a>) -> Self {
+        self.foreign_keys.push(foreign_key);
+        self
+    }
+
+    /// Does nothing if the table already exists.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let query = CreateTable::new("users")
+    ///     .column(ColumnDefinition::new("id", ColumnType::Uuid).primary_key())
+    ///     .if_not_exists();
+    ///
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!("CREATE TABLE IF NOT EXISTS \"users\" (\"id\" UUID PRIMARY KEY)", sql);
+    /// ```
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+}
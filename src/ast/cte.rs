@@ -0,0 +1,127 @@
+use crate::ast::*;
+use std::borrow::Cow;
+
+/// A named query in a `WITH` clause. The name can be used as a table in the
+/// main query.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CommonTableExpression<'a> {
+    pub(crate) name: Cow<'a, str>,
+    pub(crate) columns: Vec<Cow<'a, str>>,
+    pub(crate) query: Box<Query<'a>>,
+}
+
+impl<'a> CommonTableExpression<'a> {
+    /// A new expression with the given name, typically from a `Select` or a
+    /// `Union`.
+    pub fn new<N, Q>(name: N, query: Q) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+        Q: Into<Query<'a>>,
+    {
+        Self {
+            name: name.into(),
+            columns: Vec::new(),
+            query: Box::new(query.into()),
+        }
+    }
+
+    /// Names the columns of the expression.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let cte = CommonTableExpression::new("nums", Select::default().value(1).value(2)).columns(vec!["a", "b"]);
+    /// let query = Select::from_table("nums").with(With::new().cte(cte));
+    /// let (sql, _) = Sqlite::build(query);
+    ///
+    /// assert_eq!("WITH `nums` (`a`, `b`) AS (SELECT ?, ?) SELECT `nums`.* FROM `nums`", sql);
+    /// ```
+    pub fn columns<C, I>(mut self, columns: I) -> Self
+    where
+        C: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = C>,
+    {
+        self.columns = columns.into_iter().map(|c| c.into()).collect();
+        self
+    }
+}
+
+/// A `WITH` clause of common table expressions, attached to a `SELECT`,
+/// `INSERT`, `UPDATE` or `DELETE` statement.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let adults = Select::from_table("users").so_that("age".greater_than_or_equals(18));
+/// let with = With::new().query("adults", adults);
+///
+/// let query = Select::from_table("adults").with(with);
+/// let (sql, params) = Sqlite::build(query);
+///
+/// assert_eq!(
+///     "WITH `adults` AS (SELECT `users`.* FROM `users` WHERE `age` >= ?) SELECT `adults`.* FROM `adults`",
+///     sql
+/// );
+///
+/// assert_eq!(vec![ParameterizedValue::from(18)], params);
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct With<'a> {
+    pub(crate) recursive: bool,
+    pub(crate) expressions: Vec<CommonTableExpression<'a>>,
+}
+
+impl<'a> With<'a> {
+    /// An empty `WITH` clause.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a named query to the clause.
+    pub fn query<N, Q>(self, name: N, query: Q) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+        Q: Into<Query<'a>>,
+    {
+        self.cte(CommonTableExpression::new(name, query))
+    }
+
+    /// Adds a common table expression to the clause.
+    pub fn cte(mut self, cte: CommonTableExpression<'a>) -> Self {
+        self.expressions.push(cte);
+        self
+    }
+
+    /// Allows the expressions to refer to themselves with `WITH RECURSIVE`.
+    /// The query of a recursive expression is a `Union` of the starting rows
+    /// and a `Select` joining the expression itself.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let start = Select::from_table("categories")
+    ///     .columns(vec!["id", "parent_id"])
+    ///     .so_that("id".equals(1));
+    ///
+    /// let children = Select::from_table("categories")
+    ///     .column(("categories", "id"))
+    ///     .column(("categories", "parent_id"))
+    ///     .inner_join("tree".on(("categories", "parent_id").equals(Column::from(("tree", "id")))));
+    ///
+    /// let tree = CommonTableExpression::new("tree", Union::new(start).all(children)).columns(vec!["id", "parent_id"]);
+    /// let query = Select::from_table("tree").with(With::new().recursive().cte(tree));
+    ///
+    /// let (sql, _) = Sqlite::build(query);
+    ///
+    /// assert_eq!(
+    ///     "WITH RECURSIVE `tree` (`id`, `parent_id`) AS (\
+    ///      SELECT `id`, `parent_id` FROM `categories` WHERE `id` = ? \
+    ///      UNION ALL \
+    ///      SELECT `categories`.`id`, `categories`.`parent_id` FROM `categories` \
+    ///      INNER JOIN `tree` ON `categories`.`parent_id` = `tree`.`id`) \
+    ///      SELECT `tree`.* FROM `tree`",
+    ///     sql
+    /// );
+    /// ```
+    pub fn recursive(mut self) -> Self {
+        self.recursive = true;
+        self
+    }
+}
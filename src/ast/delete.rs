@@ -5,6 +5,8 @@ use crate::ast::*;
 pub struct Delete<'a> {
     pub(crate) table: Table<'a>,
     pub(crate) conditions: Option<ConditionTree<'a>>,
+    pub(crate) with: Option<With<'a>>,
+    pub(crate) returning: Option<Vec<Column<'a>>>,
 }
 
 impl<'a> From<Delete<'a>> for Query<'a> {
@@ -32,6 +34,8 @@ impl<'a> Delete<'a> {
         Self {
             table: table.into(),
             conditions: None,
+            with: None,
+            returning: None,
         }
     }
 
@@ -53,4 +57,47 @@ impl<'a> Delete<'a> {
         self.conditions = Some(conditions.into());
         self
     }
+
+    /// Sets the columns returned from the deleted rows. Only rendered on
+    /// PostgreSQL; MySQL and SQLite return an `UnsupportedFeature` error.
+    /// `RETURNING` came in SQLite 3.35, the bundled SQLite is older.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let query = Delete::from_table("users").so_that("bar".equals(false)).returning(vec!["id"]);
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!("DELETE FROM \"users\" WHERE \"bar\" = $1 RETURNING \"id\"", sql);
+    /// ```
+    pub fn returning<K, I>(mut self, columns: I) -> Self
+    where
+        K: Into<Column<'a>>,
+        I: IntoIterator<Item = K>,
+    {
+        self.returning = Some(columns.into_iter().map(|k| k.into()).collect());
+        self
+    }
+
+    /// Adds a `WITH` clause of common table expressions to the query.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let banned = Select::from_table("bans").column("user_id");
+    ///
+    /// let query = Delete::from_table("users")
+    ///     .so_that("id".in_selection(Select::from_table("banned").column("user_id")))
+    ///     .with(With::new().query("banned", banned));
+    ///
+    /// let (sql, _) = Sqlite::build(query);
+    ///
+    /// assert_eq!(
+    ///     "WITH `banned` AS (SELECT `user_id` FROM `bans`) \
+    ///      DELETE FROM `users` WHERE `id` IN (SELECT `user_id` FROM `banned`)",
+    ///     sql
+    /// );
+    /// ```
+    pub fn with(mut self, with: With<'a>) -> Self {
+        self.with = Some(with);
+        self
+    }
 }
@@ -0,0 +1,98 @@
+use crate::ast::*;
+use std::borrow::Cow;
+
+/// A builder for a `DROP TABLE` statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropTable<'a> {
+    pub(crate) table: Table<'a>,
+    pub(crate) if_exists: bool,
+}
+
+impl<'a> From<DropTable<'a>> for Query<'a> {
+    #[inline]
+    fn from(drop_table: DropTable<'a>) -> Self {
+        Query::DropTable(Box::new(drop_table))
+    }
+}
+
+impl<'a> DropTable<'a> {
+    /// Creates a new `DROP TABLE` statement for the given table.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let (sql, _) = Postgres::build(DropTable::new("users"));
+    ///
+    /// assert_eq!("DROP TABLE \"users\"", sql);
+    /// ```
+    pub fn new<T>(table: T) -> Self
+    where
+        T: Into<Table<'a>>,
+    {
+        Self {
+            table: table.into(),
+            if_exists: false,
+        }
+    }
+
+    /// Does nothing if the table doesn't exist.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let (sql, _) = Sqlite::build(DropTable::new("users").if_exists());
+    ///
+    /// assert_eq!("DROP TABLE IF EXISTS `users`", sql);
+    /// ```
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+/// A builder for a `DROP INDEX` statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropIndex<'a> {
+    pub(crate) name: Cow<'a, str>,
+    pub(crate) table: Table<'a>,
+    pub(crate) if_exists: bool,
+}
+
+impl<'a> From<DropIndex<'a>> for Query<'a> {
+    #[inline]
+    fn from(drop_index: DropIndex<'a>) -> Self {
+        Query::DropIndex(Box::new(drop_index))
+    }
+}
+
+impl<'a> DropIndex<'a> {
+    /// Creates a new `DROP INDEX` statement for the index of the given table.
+    /// MySQL needs the table, the other databases use only its database or
+    /// schema.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres, Mysql}};
+    /// let query = DropIndex::new("users_email_key", Table::from("users").database("app"));
+    ///
+    /// let (psql, _) = Postgres::build(query.clone());
+    /// let (mysql, _) = Mysql::build(query);
+    ///
+    /// assert_eq!("DROP INDEX \"app\".\"users_email_key\"", psql);
+    /// assert_eq!("DROP INDEX `users_email_key` ON `app`.`users`", mysql);
+    /// ```
+    pub fn new<S, T>(name: S, table: T) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Table<'a>>,
+    {
+        Self {
+            name: name.into(),
+            table: table.into(),
+            if_exists: false,
+        }
+    }
+
+    /// Does nothing if the index doesn't exist. Not supported on MySQL.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+}
@@ -1,12 +1,52 @@
+mod abs;
 mod aggregate_to_string;
+#[cfg(all(feature = "array", feature = "postgresql"))]
+mod array_length;
+mod average;
+mod cast;
+mod coalesce;
+mod concat;
 mod count;
+mod current_timestamp;
+mod dense_rank;
+mod first_value;
+mod lag;
+mod last_value;
+mod lead;
+mod length;
+mod lower;
+mod maximum;
+mod minimum;
+mod rank;
 mod row_number;
+mod sum;
+mod upper;
 
+pub use abs::*;
 pub use aggregate_to_string::*;
+#[cfg(all(feature = "array", feature = "postgresql"))]
+pub use array_length::*;
+pub use average::*;
+pub use cast::*;
+pub use coalesce::*;
+pub use concat::*;
 pub use count::*;
+pub use current_timestamp::*;
+pub use dense_rank::*;
+pub use first_value::*;
+pub use lag::*;
+pub use last_value::*;
+pub use lead::*;
+pub use length::*;
+pub use lower::*;
+pub use maximum::*;
+pub use minimum::*;
+pub use rank::*;
 pub use row_number::*;
+pub use sum::*;
+pub use upper::*;
 
-use super::DatabaseValue;
+use super::{Column, DatabaseValue, IntoOrderDefinition, Over};
 use std::borrow::Cow;
 
 /// A database function definition
@@ -14,6 +54,7 @@ use std::borrow::Cow;
 pub struct Function<'a> {
     pub(crate) typ_: FunctionType<'a>,
     pub(crate) alias: Option<Cow<'a, str>>,
+    pub(crate) over: Option<Box<Over<'a>>>,
 }
 
 /// A database function type
@@ -22,6 +63,26 @@ pub(crate) enum FunctionType<'a> {
     RowNumber(RowNumber<'a>),
     Count(Count<'a>),
     AggregateToString(AggregateToString<'a>),
+    Sum(Sum<'a>),
+    Average(Average<'a>),
+    Minimum(Minimum<'a>),
+    Maximum(Maximum<'a>),
+    Coalesce(Coalesce<'a>),
+    Lower(Lower<'a>),
+    Upper(Upper<'a>),
+    Length(Length<'a>),
+    Abs(Abs<'a>),
+    Concat(Concat<'a>),
+    Cast(Cast<'a>),
+    CurrentTimestamp,
+    Rank(Rank<'a>),
+    DenseRank(DenseRank<'a>),
+    Lag(Lag<'a>),
+    Lead(Lead<'a>),
+    FirstValue(FirstValue<'a>),
+    LastValue(LastValue<'a>),
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    ArrayLength(ArrayLength<'a>),
 }
 
 impl<'a> Function<'a> {
@@ -44,6 +105,7 @@ macro_rules! function {
                     Function {
                         typ_: FunctionType::$kind(f),
                         alias: None,
+                        over: None,
                     }
                 }
             }
@@ -58,4 +120,98 @@ macro_rules! function {
     );
 }
 
-function!(RowNumber, Count, AggregateToString);
+function!(
+    RowNumber,
+    Count,
+    AggregateToString,
+    Sum,
+    Average,
+    Minimum,
+    Maximum,
+    Coalesce,
+    Lower,
+    Upper,
+    Length,
+    Abs,
+    Concat,
+    Cast,
+    Rank,
+    DenseRank,
+    Lag,
+    Lead,
+    FirstValue,
+    LastValue
+);
+
+#[cfg(all(feature = "array", feature = "postgresql"))]
+function!(ArrayLength);
+
+macro_rules! aggregate_over {
+    ($($kind:ident),*) => (
+        $(
+            impl<'a> $kind<'a> {
+                /// Calculates the aggregate over a window of rows instead of a
+                /// group.
+                pub fn over(self, over: Over<'a>) -> Function<'a> {
+                    let mut fun = Function::from(self);
+                    fun.over = Some(Box::new(over));
+                    fun
+                }
+            }
+        )*
+    );
+}
+
+aggregate_over!(Count, Sum, Average, Minimum, Maximum);
+
+macro_rules! window_function {
+    ($($kind:ident),*) => (
+        $(
+            impl<'a> $kind<'a> {
+                /// Define the order of the window.
+                pub fn order_by<T>(mut self, value: T) -> Self
+                where
+                    T: IntoOrderDefinition<'a>,
+                {
+                    self.over = self.over.order_by(value);
+                    self
+                }
+
+                /// Define the partitioning of the window.
+                pub fn partition_by<T>(mut self, partition: T) -> Self
+                where
+                    T: Into<Column<'a>>,
+                {
+                    self.over = self.over.partition_by(partition);
+                    self
+                }
+
+                /// Replaces the window with the given definition.
+                pub fn over(mut self, over: Over<'a>) -> Self {
+                    self.over = over;
+                    self
+                }
+            }
+        )*
+    );
+}
+
+window_function!(Rank, DenseRank, Lag, Lead, FirstValue, LastValue);
+
+impl<'a> From<CurrentTimestamp> for Function<'a> {
+    #[inline]
+    fn from(_: CurrentTimestamp) -> Self {
+        Function {
+            typ_: FunctionType::CurrentTimestamp,
+            alias: None,
+            over: None,
+        }
+    }
+}
+
+impl<'a> From<CurrentTimestamp> for DatabaseValue<'a> {
+    #[inline]
+    fn from(f: CurrentTimestamp) -> Self {
+        Function::from(f).into()
+    }
+}
@@ -0,0 +1,25 @@
+use crate::ast::DatabaseValue;
+
+#[derive(Debug, Clone, PartialEq)]
+/// The absolute value of a number.
+pub struct Abs<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+}
+
+/// The absolute value of the given column or expression.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let query = Select::from_table("accounts").value(abs(Column::new("balance")));
+/// let (sql, _) = Sqlite::build(query);
+/// assert_eq!("SELECT ABS(`balance`) FROM `accounts`", sql);
+/// ```
+#[inline]
+pub fn abs<'a, T>(expr: T) -> Abs<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+{
+    Abs {
+        expr: Box::new(expr.into()),
+    }
+}
@@ -0,0 +1,27 @@
+use crate::ast::DatabaseValue;
+
+#[derive(Debug, Clone, PartialEq)]
+/// The number of elements in an array.
+pub struct ArrayLength<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+}
+
+/// The number of elements in the first dimension of the given array. `NULL`
+/// for an empty array. PostgreSQL only.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// let query = Select::from_table("posts").value(array_length(Column::new("tags")));
+/// let (sql, _) = Postgres::build(query);
+///
+/// assert_eq!("SELECT ARRAY_LENGTH(\"tags\", 1) FROM \"posts\"", sql);
+/// ```
+#[inline]
+pub fn array_length<'a, T>(expr: T) -> ArrayLength<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+{
+    ArrayLength {
+        expr: Box::new(expr.into()),
+    }
+}
@@ -0,0 +1,25 @@
+use crate::ast::DatabaseValue;
+
+#[derive(Debug, Clone, PartialEq)]
+/// An aggregate function that calculates the average of the given values.
+pub struct Average<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+}
+
+/// Calculates the average of the given column or expression.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let query = Select::from_table("users").value(avg(Column::new("age")));
+/// let (sql, _) = Sqlite::build(query);
+/// assert_eq!("SELECT AVG(`age`) FROM `users`", sql);
+/// ```
+#[inline]
+pub fn avg<'a, T>(expr: T) -> Average<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+{
+    Average {
+        expr: Box::new(expr.into()),
+    }
+}
@@ -0,0 +1,50 @@
+use crate::ast::DatabaseValue;
+use std::borrow::Cow;
+
+/// The type to convert a value into with [cast](fn.cast.html).
+#[derive(Debug, Clone, PartialEq)]
+pub enum CastType<'a> {
+    /// A 64-bit integer, `BIGINT` on PostgreSQL, `SIGNED` on MySQL and
+    /// `INTEGER` on SQLite.
+    Int,
+    /// A double precision float, `DOUBLE PRECISION` on PostgreSQL, `DOUBLE`
+    /// on MySQL and `REAL` on SQLite.
+    Float,
+    /// An exact decimal, `NUMERIC` on PostgreSQL and SQLite and
+    /// `DECIMAL(65, 30)` on MySQL.
+    Decimal,
+    /// A string, `TEXT` on PostgreSQL and SQLite and `CHAR` on MySQL.
+    Text,
+    /// A database-specific type name, written to the query as-is.
+    Custom(Cow<'a, str>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Converts a value into another type.
+pub struct Cast<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+    pub(crate) typ: CastType<'a>,
+}
+
+/// Converts the given value into another type.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mysql, Postgres}};
+/// let query = Select::from_table("users").value(cast(Column::new("age"), CastType::Text));
+///
+/// let (sql, _) = Postgres::build(query.clone());
+/// assert_eq!("SELECT CAST(\"age\" AS TEXT) FROM \"users\"", sql);
+///
+/// let (sql, _) = Mysql::build(query);
+/// assert_eq!("SELECT CAST(`age` AS CHAR) FROM `users`", sql);
+/// ```
+#[inline]
+pub fn cast<'a, T>(expr: T, typ: CastType<'a>) -> Cast<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+{
+    Cast {
+        expr: Box::new(expr.into()),
+        typ,
+    }
+}
@@ -0,0 +1,29 @@
+use crate::ast::DatabaseValue;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Returns the first non-null value of the given expressions.
+pub struct Coalesce<'a> {
+    pub(crate) exprs: Vec<DatabaseValue<'a>>,
+}
+
+/// Returns the first of the given values that is not null.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let values = vec![Column::new("nickname").into(), Column::new("name").into(), DatabaseValue::from("Anonymous")];
+/// let query = Select::from_table("users").value(coalesce(values));
+/// let (sql, params) = Sqlite::build(query);
+///
+/// assert_eq!("SELECT COALESCE(`nickname`, `name`, ?) FROM `users`", sql);
+/// assert_eq!(vec![ParameterizedValue::from("Anonymous")], params);
+/// ```
+#[inline]
+pub fn coalesce<'a, T, V>(exprs: V) -> Coalesce<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+    V: IntoIterator<Item = T>,
+{
+    Coalesce {
+        exprs: exprs.into_iter().map(|e| e.into()).collect(),
+    }
+}
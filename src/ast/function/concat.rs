@@ -0,0 +1,36 @@
+use crate::ast::DatabaseValue;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Concatenates strings.
+pub struct Concat<'a> {
+    pub(crate) exprs: Vec<DatabaseValue<'a>>,
+}
+
+/// Concatenates the given values into one string. Rendered as `CONCAT` on
+/// PostgreSQL and MySQL, and with the `||` operator on SQLite.
+///
+/// Null values are handled differently: PostgreSQL skips them, MySQL and
+/// SQLite return null. PostgreSQL can't infer the type of a parameter in
+/// `CONCAT`, so parameters should be wrapped in a [cast](fn.cast.html).
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mysql, Sqlite}};
+/// let values = vec![Column::new("first_name").into(), DatabaseValue::from(" "), Column::new("last_name").into()];
+/// let query = Select::from_table("users").value(concat(values));
+///
+/// let (sql, _) = Mysql::build(query.clone());
+/// assert_eq!("SELECT CONCAT(`first_name`, ?, `last_name`) FROM `users`", sql);
+///
+/// let (sql, _) = Sqlite::build(query);
+/// assert_eq!("SELECT (`first_name` || ? || `last_name`) FROM `users`", sql);
+/// ```
+#[inline]
+pub fn concat<'a, T, V>(exprs: V) -> Concat<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+    V: IntoIterator<Item = T>,
+{
+    Concat {
+        exprs: exprs.into_iter().map(|e| e.into()).collect(),
+    }
+}
@@ -4,6 +4,22 @@ use crate::ast::DatabaseValue;
 /// Returns the number of rows that matches a specified criteria.
 pub struct Count<'a> {
     pub(crate) exprs: Vec<DatabaseValue<'a>>,
+    pub(crate) distinct: bool,
+}
+
+impl<'a> Count<'a> {
+    /// Counts only the distinct values of the expression.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let query = Select::from_table("posts").value(count(Column::new("user_id")).distinct());
+    /// let (sql, _) = Sqlite::build(query);
+    /// assert_eq!("SELECT COUNT(DISTINCT `user_id`) FROM `posts`", sql);
+    /// ```
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
 }
 
 /// Count of the underlying table where the given expression is not null.
@@ -21,5 +37,6 @@ where
 {
     Count {
         exprs: vec![expr.into()],
+        distinct: false,
     }
 }
@@ -0,0 +1,34 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The date and time at the start of the current transaction on PostgreSQL,
+/// or of the current statement on MySQL and SQLite.
+pub struct CurrentTimestamp;
+
+/// The current date and time. SQLite has no datetime type, so the value is
+/// written like the connector stores datetimes, by default as milliseconds
+/// since the Unix epoch. See
+/// [DateTimeFormat](../connector/enum.DateTimeFormat.html).
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mysql, Sqlite}};
+/// let query = Update::table("users").set("updated_at", now());
+/// let (sql, _) = Mysql::build(query.clone());
+///
+/// assert_eq!("UPDATE `users` SET `updated_at` = CURRENT_TIMESTAMP", sql);
+///
+/// let (sql, _) = Sqlite::build(query);
+///
+/// assert_eq!(
+///     "UPDATE `users` SET `updated_at` = (CAST(ROUND((julianday('now') - 2440587.5) * 86400000) AS INTEGER))",
+///     sql
+/// );
+/// ```
+#[inline]
+pub fn now() -> CurrentTimestamp {
+    CurrentTimestamp
+}
+
+/// The current date and time, the same as [now](fn.now.html).
+#[inline]
+pub fn current_timestamp() -> CurrentTimestamp {
+    CurrentTimestamp
+}
@@ -0,0 +1,22 @@
+use crate::ast::Over;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+/// A window function that numbers the rows by their ordering, without gaps
+/// after rows with the same value.
+pub struct DenseRank<'a> {
+    pub(crate) over: Over<'a>,
+}
+
+/// The rank of the row in its partition, without gaps.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let query = Select::from_table("scores").value(dense_rank().partition_by("game").order_by("points"));
+/// let (sql, _) = Sqlite::build(query);
+///
+/// assert_eq!("SELECT DENSE_RANK() OVER(PARTITION BY `game` ORDER BY `points`) FROM `scores`", sql);
+/// ```
+#[inline]
+pub fn dense_rank<'a>() -> DenseRank<'a> {
+    DenseRank::default()
+}
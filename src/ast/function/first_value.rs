@@ -0,0 +1,29 @@
+use crate::ast::{DatabaseValue, Over};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A window function that returns a value from the first row of the window
+/// frame.
+pub struct FirstValue<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+    pub(crate) over: Over<'a>,
+}
+
+/// The value of the expression in the first row of the window frame.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let query = Select::from_table("prices").value(first_value(Column::new("price")).partition_by("item").order_by("day"));
+/// let (sql, _) = Sqlite::build(query);
+///
+/// assert_eq!("SELECT FIRST_VALUE(`price`) OVER(PARTITION BY `item` ORDER BY `day`) FROM `prices`", sql);
+/// ```
+#[inline]
+pub fn first_value<'a, T>(expr: T) -> FirstValue<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+{
+    FirstValue {
+        expr: Box::new(expr.into()),
+        over: Over::default(),
+    }
+}
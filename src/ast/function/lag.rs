@@ -0,0 +1,50 @@
+use crate::ast::{DatabaseValue, Over};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A window function that returns a value from a row before the current row.
+pub struct Lag<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+    pub(crate) offset: Option<u64>,
+    pub(crate) default: Option<Box<DatabaseValue<'a>>>,
+    pub(crate) over: Over<'a>,
+}
+
+impl<'a> Lag<'a> {
+    /// The number of rows before the current row, one by default.
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// The value to return if the row does not exist, null by default.
+    pub fn default<T>(mut self, value: T) -> Self
+    where
+        T: Into<DatabaseValue<'a>>,
+    {
+        self.default = Some(Box::new(value.into()));
+        self
+    }
+}
+
+/// The value of the expression in the preceding row of the window.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let query = Select::from_table("prices").value(lag(Column::new("price")).offset(2).default(0).order_by("day"));
+/// let (sql, params) = Sqlite::build(query);
+///
+/// assert_eq!("SELECT LAG(`price`, 2, ?) OVER(ORDER BY `day`) FROM `prices`", sql);
+/// assert_eq!(vec![ParameterizedValue::from(0)], params);
+/// ```
+#[inline]
+pub fn lag<'a, T>(expr: T) -> Lag<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+{
+    Lag {
+        expr: Box::new(expr.into()),
+        offset: None,
+        default: None,
+        over: Over::default(),
+    }
+}
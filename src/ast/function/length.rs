@@ -0,0 +1,30 @@
+use crate::ast::DatabaseValue;
+
+#[derive(Debug, Clone, PartialEq)]
+/// The number of characters in a string.
+pub struct Length<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+}
+
+/// The number of characters in the given value. Rendered as `CHAR_LENGTH` on
+/// MySQL, where `LENGTH` counts bytes.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mysql, Sqlite}};
+/// let query = Select::from_table("users").value(length(Column::new("name")));
+///
+/// let (sql, _) = Sqlite::build(query.clone());
+/// assert_eq!("SELECT LENGTH(`name`) FROM `users`", sql);
+///
+/// let (sql, _) = Mysql::build(query);
+/// assert_eq!("SELECT CHAR_LENGTH(`name`) FROM `users`", sql);
+/// ```
+#[inline]
+pub fn length<'a, T>(expr: T) -> Length<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+{
+    Length {
+        expr: Box::new(expr.into()),
+    }
+}
@@ -0,0 +1,25 @@
+use crate::ast::DatabaseValue;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Converts a string to lower case.
+pub struct Lower<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+}
+
+/// Converts the given value to lower case.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let query = Select::from_table("users").value(lower(Column::new("name")));
+/// let (sql, _) = Sqlite::build(query);
+/// assert_eq!("SELECT LOWER(`name`) FROM `users`", sql);
+/// ```
+#[inline]
+pub fn lower<'a, T>(expr: T) -> Lower<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+{
+    Lower {
+        expr: Box::new(expr.into()),
+    }
+}
@@ -0,0 +1,25 @@
+use crate::ast::DatabaseValue;
+
+#[derive(Debug, Clone, PartialEq)]
+/// An aggregate function that returns the largest of the given values.
+pub struct Maximum<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+}
+
+/// Returns the largest value of the given column or expression.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let query = Select::from_table("users").value(max(Column::new("age")));
+/// let (sql, _) = Sqlite::build(query);
+/// assert_eq!("SELECT MAX(`age`) FROM `users`", sql);
+/// ```
+#[inline]
+pub fn max<'a, T>(expr: T) -> Maximum<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+{
+    Maximum {
+        expr: Box::new(expr.into()),
+    }
+}
@@ -0,0 +1,25 @@
+use crate::ast::DatabaseValue;
+
+#[derive(Debug, Clone, PartialEq)]
+/// An aggregate function that returns the smallest of the given values.
+pub struct Minimum<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+}
+
+/// Returns the smallest value of the given column or expression.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let query = Select::from_table("users").value(min(Column::new("age")));
+/// let (sql, _) = Sqlite::build(query);
+/// assert_eq!("SELECT MIN(`age`) FROM `users`", sql);
+/// ```
+#[inline]
+pub fn min<'a, T>(expr: T) -> Minimum<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+{
+    Minimum {
+        expr: Box::new(expr.into()),
+    }
+}
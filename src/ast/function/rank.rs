@@ -0,0 +1,22 @@
+use crate::ast::Over;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+/// A window function that numbers the rows by their ordering, leaving gaps
+/// after rows with the same value.
+pub struct Rank<'a> {
+    pub(crate) over: Over<'a>,
+}
+
+/// The rank of the row in its partition, with gaps.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let query = Select::from_table("scores").value(rank().order_by("points".descend()));
+/// let (sql, _) = Sqlite::build(query);
+///
+/// assert_eq!("SELECT RANK() OVER(ORDER BY `points` DESC) FROM `scores`", sql);
+/// ```
+#[inline]
+pub fn rank<'a>() -> Rank<'a> {
+    Rank::default()
+}
@@ -25,6 +25,12 @@ impl<'a> RowNumber<'a> {
         self.over.partitioning.push(partition.into());
         self
     }
+
+    /// Replaces the window with the given definition.
+    pub fn over(mut self, over: Over<'a>) -> Self {
+        self.over = over;
+        self
+    }
 }
 
 /// A number from 1 to n in specified order
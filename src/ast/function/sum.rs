@@ -0,0 +1,25 @@
+use crate::ast::DatabaseValue;
+
+#[derive(Debug, Clone, PartialEq)]
+/// An aggregate function that calculates the sum of the given values.
+pub struct Sum<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+}
+
+/// Calculates the sum of the given column or expression.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let query = Select::from_table("orders").value(sum(Column::new("amount")));
+/// let (sql, _) = Sqlite::build(query);
+/// assert_eq!("SELECT SUM(`amount`) FROM `orders`", sql);
+/// ```
+#[inline]
+pub fn sum<'a, T>(expr: T) -> Sum<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+{
+    Sum {
+        expr: Box::new(expr.into()),
+    }
+}
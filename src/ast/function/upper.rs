@@ -0,0 +1,25 @@
+use crate::ast::DatabaseValue;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Converts a string to upper case.
+pub struct Upper<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+}
+
+/// Converts the given value to upper case.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let query = Select::from_table("users").value(upper(Column::new("name")));
+/// let (sql, _) = Sqlite::build(query);
+/// assert_eq!("SELECT UPPER(`name`) FROM `users`", sql);
+/// ```
+#[inline]
+pub fn upper<'a, T>(expr: T) -> Upper<'a>
+where
+    T: Into<DatabaseValue<'a>>,
+{
+    Upper {
+        expr: Box::new(expr.into()),
+    }
+}
@@ -1,4 +1,5 @@
 use crate::ast::*;
+use std::borrow::Cow;
 
 /// A builder for an `INSERT` statement.
 #[derive(Clone, Debug, PartialEq)]
@@ -6,8 +7,9 @@ pub struct Insert<'a> {
     pub(crate) table: Table<'a>,
     pub(crate) columns: Vec<Column<'a>>,
     pub(crate) values: Vec<Row<'a>>,
-    pub(crate) on_conflict: Option<OnConflict>,
+    pub(crate) on_conflict: Option<OnConflict<'a>>,
     pub(crate) returning: Option<Vec<Column<'a>>>,
+    pub(crate) with: Option<With<'a>>,
 }
 
 /// A builder for an `INSERT` statement for a single row.
@@ -18,15 +20,16 @@ pub struct SingleRowInsert<'a> {
 }
 
 /// A builder for an `INSERT` statement for multiple rows.
+#[derive(Clone, Debug, PartialEq)]
 pub struct MultiRowInsert<'a> {
     pub(crate) table: Table<'a>,
     pub(crate) columns: Vec<Column<'a>>,
     pub(crate) values: Vec<Row<'a>>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 /// `INSERT` conflict resolution strategies.
-pub enum OnConflict {
+pub enum OnConflict<'a> {
     /// When a row already exists, do nothing.
     ///
     /// ```rust
@@ -38,6 +41,148 @@ pub enum OnConflict {
     /// assert_eq!("INSERT OR IGNORE INTO `users` DEFAULT VALUES", sql);
     /// ```
     DoNothing,
+    /// When a row already exists, update it with the given values.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let update = ConflictUpdate::columns(vec!["id"]).set("name", excluded("name"));
+    ///
+    /// let query = Insert::single_into("users").value("id", 1).value("name", "Musti");
+    /// let (sql, params) = Postgres::build(Insert::from(query).on_conflict(OnConflict::DoUpdate(update)));
+    ///
+    /// assert_eq!(
+    ///     "INSERT INTO \"users\" (\"id\",\"name\") VALUES ($1,$2) ON CONFLICT (\"id\") DO UPDATE SET \"name\" = excluded.\"name\"",
+    ///     sql
+    /// );
+    ///
+    /// assert_eq!(vec![ParameterizedValue::from(1), ParameterizedValue::from("Musti")], params);
+    /// ```
+    DoUpdate(ConflictUpdate<'a>),
+}
+
+/// The unique index or constraint that triggers the conflict.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConflictTarget<'a> {
+    /// The columns of a unique index.
+    Columns(Vec<Column<'a>>),
+    /// A named constraint. Only supported on PostgreSQL; SQLite returns an
+    /// `UnsupportedFeature` error and MySQL updates on any unique key conflict.
+    Constraint(Cow<'a, str>),
+}
+
+/// A builder for the `DO UPDATE` part of an upsert.
+///
+/// The values can refer to the row proposed for insertion using
+/// [excluded](fn.excluded.html). On MySQL the target is always the unique
+/// key that caused the conflict. At least one column must be set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConflictUpdate<'a> {
+    pub(crate) target: ConflictTarget<'a>,
+    pub(crate) columns: Vec<Column<'a>>,
+    pub(crate) values: Vec<DatabaseValue<'a>>,
+    pub(crate) conditions: Option<ConditionTree<'a>>,
+}
+
+impl<'a> ConflictUpdate<'a> {
+    /// Update the existing row when the given columns conflict.
+    #[inline]
+    pub fn columns<K, I>(columns: I) -> Self
+    where
+        K: Into<Column<'a>>,
+        I: IntoIterator<Item = K>,
+    {
+        let columns = columns.into_iter().map(|c| c.into()).collect();
+        Self::new(ConflictTarget::Columns(columns))
+    }
+
+    /// Update the existing row when the given constraint is violated.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let update = ConflictUpdate::constraint("users_pkey").set("visits", 1);
+    /// let query = Insert::from(Insert::single_into("users").value("id", 1));
+    /// let (sql, _) = Postgres::build(query.on_conflict(OnConflict::DoUpdate(update)));
+    ///
+    /// assert_eq!(
+    ///     "INSERT INTO \"users\" (\"id\") VALUES ($1) ON CONFLICT ON CONSTRAINT \"users_pkey\" DO UPDATE SET \"visits\" = $2",
+    ///     sql
+    /// );
+    /// ```
+    #[inline]
+    pub fn constraint<S>(name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::new(ConflictTarget::Constraint(name.into()))
+    }
+
+    fn new(target: ConflictTarget<'a>) -> Self {
+        Self {
+            target,
+            columns: Vec::new(),
+            values: Vec::new(),
+            conditions: None,
+        }
+    }
+
+    /// Add another column value assignment to the update.
+    pub fn set<K, V>(mut self, column: K, value: V) -> Self
+    where
+        K: Into<Column<'a>>,
+        V: Into<DatabaseValue<'a>>,
+    {
+        self.columns.push(column.into());
+        self.values.push(value.into());
+
+        self
+    }
+
+    /// Only update the existing row if the conditions hold. MySQL returns an
+    /// `UnsupportedFeature` error for conditions.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let update = ConflictUpdate::columns(vec!["id"])
+    ///     .set("name", excluded("name"))
+    ///     .so_that(Column::from(("users", "locked")).equals(false));
+    ///
+    /// let query = Insert::single_into("users").value("id", 1).value("name", "Musti");
+    /// let (sql, _) = Sqlite::build(Insert::from(query).on_conflict(OnConflict::DoUpdate(update)));
+    ///
+    /// assert_eq!(
+    ///     "INSERT INTO `users` (`id`, `name`) VALUES (?,?) ON CONFLICT (`id`) DO UPDATE SET `name` = excluded.`name` WHERE `users`.`locked` = ?",
+    ///     sql
+    /// );
+    /// ```
+    pub fn so_that<T>(mut self, conditions: T) -> Self
+    where
+        T: Into<ConditionTree<'a>>,
+    {
+        self.conditions = Some(conditions.into());
+        self
+    }
+}
+
+/// A column of the row proposed for insertion, usable in the values of a
+/// [ConflictUpdate](struct.ConflictUpdate.html).
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+/// let update = ConflictUpdate::columns(vec!["id"]).set("name", excluded("name"));
+/// let query = Insert::single_into("users").value("id", 1).value("name", "Musti");
+/// let (sql, _) = Mysql::build(Insert::from(query).on_conflict(OnConflict::DoUpdate(update)));
+///
+/// assert_eq!(
+///     "INSERT INTO `users` (`id`,`name`) VALUES (?,?) ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)",
+///     sql
+/// );
+/// ```
+#[inline]
+pub fn excluded<'a, T>(column: T) -> DatabaseValue<'a>
+where
+    T: Into<Column<'a>>,
+{
+    DatabaseValue::Excluded(Box::new(column.into()))
 }
 
 impl<'a> From<Insert<'a>> for Query<'a> {
@@ -61,6 +206,7 @@ impl<'a> From<SingleRowInsert<'a>> for Insert<'a> {
             values,
             on_conflict: None,
             returning: None,
+            with: None,
         }
     }
 }
@@ -74,6 +220,7 @@ impl<'a> From<MultiRowInsert<'a>> for Insert<'a> {
             values: insert.values,
             on_conflict: None,
             returning: None,
+            with: None,
         }
     }
 }
@@ -131,12 +278,17 @@ impl<'a> Insert<'a> {
 
     /// Sets the conflict resolution strategy.
     #[inline]
-    pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
+    pub fn on_conflict(mut self, on_conflict: OnConflict<'a>) -> Self {
         self.on_conflict = Some(on_conflict);
         self
     }
 
-    /// Sets the returned columns.
+    /// Sets the returned columns. Rendered on PostgreSQL, ignored on SQLite
+    /// which returns the last insert id. MySQL emulates it by selecting the
+    /// inserted rows with their auto-increment key, so the table must have
+    /// one, and the keys must be either all given or all generated. If MySQL
+    /// skips or updates some of the rows, the insert returns an error but the
+    /// other rows stay inserted, unless it runs in a transaction.
     ///
     /// ```rust
     /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
@@ -146,7 +298,6 @@ impl<'a> Insert<'a> {
     ///
     /// assert_eq!("INSERT INTO \"users\" DEFAULT VALUES RETURNING \"id\"", sql);
     /// ```
-    #[cfg(feature = "postgresql")]
     pub fn returning<K, I>(mut self, columns: I) -> Self
     where
         K: Into<Column<'a>>,
@@ -155,6 +306,27 @@ impl<'a> Insert<'a> {
         self.returning = Some(columns.into_iter().map(|k| k.into()).collect());
         self
     }
+
+    /// Adds a `WITH` clause of common table expressions to the query. MySQL
+    /// only allows `WITH` in an `INSERT ... SELECT`, and returns an
+    /// `UnsupportedFeature` error.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let defaults = Select::default().value(1).value("Musti");
+    /// let insert = Insert::single_into("users").value("id", 1).build();
+    ///
+    /// let (sql, _) = Postgres::build(insert.with(With::new().query("defaults", defaults)));
+    ///
+    /// assert_eq!(
+    ///     "WITH \"defaults\" AS (SELECT $1, $2) INSERT INTO \"users\" (\"id\") VALUES ($3)",
+    ///     sql
+    /// );
+    /// ```
+    pub fn with(mut self, with: With<'a>) -> Self {
+        self.with = Some(with);
+        self
+    }
 }
 
 impl<'a> SingleRowInsert<'a> {
@@ -212,6 +384,51 @@ impl<'a> MultiRowInsert<'a> {
         self
     }
 
+    /// Splits the rows into inserts binding at most `max_parameters` values
+    /// each, assuming one parameter per inserted value. Every batch has at
+    /// least one row.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let query = Insert::multi_into("users", vec!["id", "name"])
+    ///     .values((1, "Musti"))
+    ///     .values((2, "Naukio"))
+    ///     .values((3, "Belka"));
+    ///
+    /// let batches: Vec<_> = query.into_batches(4).into_iter().map(Sqlite::build).collect();
+    ///
+    /// assert_eq!(2, batches.len());
+    /// assert_eq!("INSERT INTO `users` (`id`, `name`) VALUES (?,?), (?,?)", batches[0].0);
+    /// assert_eq!("INSERT INTO `users` (`id`, `name`) VALUES (?,?)", batches[1].0);
+    /// ```
+    pub fn into_batches(self, max_parameters: usize) -> Vec<MultiRowInsert<'a>> {
+        let rows_per_batch = self.rows_per_batch(max_parameters);
+
+        if self.values.len() <= rows_per_batch {
+            return vec![self];
+        }
+
+        let MultiRowInsert { table, columns, values } = self;
+        let mut batches = Vec::new();
+        let mut rows = values.into_iter().peekable();
+
+        while rows.peek().is_some() {
+            batches.push(MultiRowInsert {
+                table: table.clone(),
+                columns: columns.clone(),
+                values: rows.by_ref().take(rows_per_batch).collect(),
+            });
+        }
+
+        batches
+    }
+
+    /// The number of rows fitting into a statement with the given number of
+    /// parameters, always at least one.
+    pub(crate) fn rows_per_batch(&self, max_parameters: usize) -> usize {
+        std::cmp::max(1, max_parameters / std::cmp::max(1, self.columns.len()))
+    }
+
     /// Convert into a common `Insert` statement.
     pub fn build(self) -> Insert<'a> {
         Insert::from(self)
@@ -0,0 +1,350 @@
+use crate::ast::{Column, Compare, DatabaseValue};
+use std::borrow::Cow;
+
+/// A step into a JSON document: an object key or an array index.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonPathElement<'a> {
+    Key(Cow<'a, str>),
+    Index(usize),
+}
+
+impl<'a> From<&'a str> for JsonPathElement<'a> {
+    #[inline]
+    fn from(key: &'a str) -> Self {
+        JsonPathElement::Key(key.into())
+    }
+}
+
+impl<'a> From<String> for JsonPathElement<'a> {
+    #[inline]
+    fn from(key: String) -> Self {
+        JsonPathElement::Key(key.into())
+    }
+}
+
+impl<'a> From<usize> for JsonPathElement<'a> {
+    #[inline]
+    fn from(index: usize) -> Self {
+        JsonPathElement::Index(index)
+    }
+}
+
+/// A value extracted from a JSON document, either as JSON or as text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonExtract<'a> {
+    pub(crate) expr: Box<DatabaseValue<'a>>,
+    pub(crate) path: Vec<JsonPathElement<'a>>,
+    pub(crate) as_text: bool,
+}
+
+impl<'a> From<JsonExtract<'a>> for DatabaseValue<'a> {
+    #[inline]
+    fn from(extract: JsonExtract<'a>) -> Self {
+        DatabaseValue::JsonExtract(Box::new(extract))
+    }
+}
+
+/// The path in the `$."key"[0]` syntax of MySQL and SQLite.
+pub(crate) fn json_path(path: &[JsonPathElement]) -> String {
+    let mut result = String::from("$");
+
+    for element in path {
+        match element {
+            JsonPathElement::Key(key) => {
+                result.push_str(".\"");
+                result.push_str(&key.replace('\\', "\\\\").replace('"', "\\\""));
+                result.push('"');
+            }
+            JsonPathElement::Index(index) => {
+                result.push_str(&format!("[{}]", index));
+            }
+        }
+    }
+
+    result
+}
+
+/// Reading and filtering JSON values. The operators are the ones of
+/// PostgreSQL `JSONB` columns, with MySQL and SQLite using their JSON
+/// functions instead.
+pub trait JsonComparable<'a> {
+    /// The value of an object key or an array element as JSON, `->` on
+    /// PostgreSQL.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres, Mysql}};
+    /// let query = Select::from_table("users").value("data".json_get("tags").json_get(0));
+    ///
+    /// let (sql, params) = Postgres::build(query.clone());
+    /// assert_eq!("SELECT ((\"data\" -> $1) -> 0) FROM \"users\"", sql);
+    /// assert_eq!(vec![ParameterizedValue::from("tags")], params);
+    ///
+    /// let (sql, params) = Mysql::build(query);
+    /// assert_eq!("SELECT JSON_EXTRACT(JSON_EXTRACT(`data`, ?), ?) FROM `users`", sql);
+    /// assert_eq!(vec![ParameterizedValue::from("$.\"tags\""), ParameterizedValue::from("$[0]")], params);
+    /// ```
+    fn json_get<T>(self, element: T) -> DatabaseValue<'a>
+    where
+        T: Into<JsonPathElement<'a>>;
+
+    /// The value of an object key or an array element as text, `->>` on
+    /// PostgreSQL. SQLite returns strings without quotes for both
+    /// `json_get` and `json_get_text`.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres, Mysql}};
+    /// let query = Select::from_table("users").so_that("data".json_get_text("name").equals("Musti"));
+    ///
+    /// let (sql, _) = Postgres::build(query.clone());
+    /// assert_eq!("SELECT \"users\".* FROM \"users\" WHERE (\"data\" ->> $1) = $2", sql);
+    ///
+    /// let (sql, _) = Mysql::build(query);
+    /// assert_eq!("SELECT `users`.* FROM `users` WHERE JSON_UNQUOTE(JSON_EXTRACT(`data`, ?)) = ?", sql);
+    /// ```
+    fn json_get_text<T>(self, element: T) -> DatabaseValue<'a>
+    where
+        T: Into<JsonPathElement<'a>>;
+
+    /// The value at the given path as JSON, `#>` on PostgreSQL.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres, Sqlite}};
+    /// let path: Vec<JsonPathElement> = vec!["address".into(), "lines".into(), 1.into()];
+    /// let query = Select::from_table("users").value("data".json_get_path(path));
+    ///
+    /// let (sql, params) = Postgres::build(query.clone());
+    /// assert_eq!("SELECT (\"data\" #> ARRAY[$1, $2, $3]) FROM \"users\"", sql);
+    /// assert_eq!(
+    ///     vec![ParameterizedValue::from("address"), ParameterizedValue::from("lines"), ParameterizedValue::from("1")],
+    ///     params
+    /// );
+    ///
+    /// let (sql, params) = Sqlite::build(query);
+    /// assert_eq!("SELECT json_extract(`data`, ?) FROM `users`", sql);
+    /// assert_eq!(vec![ParameterizedValue::from("$.\"address\".\"lines\"[1]")], params);
+    /// ```
+    fn json_get_path<T, I>(self, path: I) -> DatabaseValue<'a>
+    where
+        T: Into<JsonPathElement<'a>>,
+        I: IntoIterator<Item = T>;
+
+    /// The value at the given path as text, `#>>` on PostgreSQL.
+    fn json_get_path_text<T, I>(self, path: I) -> DatabaseValue<'a>
+    where
+        T: Into<JsonPathElement<'a>>,
+        I: IntoIterator<Item = T>;
+
+    /// Tests if the left side contains the given JSON document, `@>` on
+    /// PostgreSQL.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres, Mysql}};
+    /// # use serde_json::json;
+    /// let query = Select::from_table("users").so_that("data".json_contains(json!({ "tags": ["cat"] })));
+    ///
+    /// let (sql, params) = Postgres::build(query.clone());
+    /// assert_eq!("SELECT \"users\".* FROM \"users\" WHERE \"data\" @> $1", sql);
+    /// assert_eq!(vec![ParameterizedValue::from(json!({ "tags": ["cat"] }))], params);
+    ///
+    /// let (sql, _) = Mysql::build(query);
+    /// assert_eq!("SELECT `users`.* FROM `users` WHERE JSON_CONTAINS(`data`, ?)", sql);
+    /// ```
+    fn json_contains<T>(self, value: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>;
+
+    /// Tests if the left side is an object with the given key, `?` on
+    /// PostgreSQL.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres, Sqlite}};
+    /// let query = Select::from_table("users").so_that("data".json_has_key("email"));
+    ///
+    /// let (sql, _) = Postgres::build(query.clone());
+    /// assert_eq!("SELECT \"users\".* FROM \"users\" WHERE \"data\" ? $1", sql);
+    ///
+    /// let (sql, params) = Sqlite::build(query);
+    /// assert_eq!("SELECT `users`.* FROM `users` WHERE json_type(`data`, ?) IS NOT NULL", sql);
+    /// assert_eq!(vec![ParameterizedValue::from("$.\"email\"")], params);
+    /// ```
+    fn json_has_key<T>(self, key: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>;
+
+    /// Tests if the left side is an object with any of the given keys, `?|`
+    /// on PostgreSQL. Without any keys, the comparison is always false.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres, Mysql}};
+    /// let query = Select::from_table("users").so_that("data".json_has_any_key(vec!["email", "phone"]));
+    ///
+    /// let (sql, _) = Postgres::build(query.clone());
+    /// assert_eq!("SELECT \"users\".* FROM \"users\" WHERE \"data\" ?| ARRAY[$1, $2]", sql);
+    ///
+    /// let (sql, _) = Mysql::build(query);
+    /// assert_eq!("SELECT `users`.* FROM `users` WHERE JSON_CONTAINS_PATH(`data`, 'one', ?, ?)", sql);
+    /// ```
+    fn json_has_any_key<T, I>(self, keys: I) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = T>;
+}
+
+impl<'a> JsonComparable<'a> for DatabaseValue<'a> {
+    #[inline]
+    fn json_get<T>(self, element: T) -> DatabaseValue<'a>
+    where
+        T: Into<JsonPathElement<'a>>,
+    {
+        JsonExtract {
+            expr: Box::new(self),
+            path: vec![element.into()],
+            as_text: false,
+        }
+        .into()
+    }
+
+    #[inline]
+    fn json_get_text<T>(self, element: T) -> DatabaseValue<'a>
+    where
+        T: Into<JsonPathElement<'a>>,
+    {
+        JsonExtract {
+            expr: Box::new(self),
+            path: vec![element.into()],
+            as_text: true,
+        }
+        .into()
+    }
+
+    #[inline]
+    fn json_get_path<T, I>(self, path: I) -> DatabaseValue<'a>
+    where
+        T: Into<JsonPathElement<'a>>,
+        I: IntoIterator<Item = T>,
+    {
+        JsonExtract {
+            expr: Box::new(self),
+            path: path.into_iter().map(|e| e.into()).collect(),
+            as_text: false,
+        }
+        .into()
+    }
+
+    #[inline]
+    fn json_get_path_text<T, I>(self, path: I) -> DatabaseValue<'a>
+    where
+        T: Into<JsonPathElement<'a>>,
+        I: IntoIterator<Item = T>,
+    {
+        JsonExtract {
+            expr: Box::new(self),
+            path: path.into_iter().map(|e| e.into()).collect(),
+            as_text: true,
+        }
+        .into()
+    }
+
+    #[inline]
+    fn json_contains<T>(self, value: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>,
+    {
+        Compare::JsonContains(Box::new(self), Box::new(value.into()))
+    }
+
+    #[inline]
+    fn json_has_key<T>(self, key: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Compare::JsonHasKey(Box::new(self), key.into())
+    }
+
+    #[inline]
+    fn json_has_any_key<T, I>(self, keys: I) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = T>,
+    {
+        Compare::JsonHasAnyKey(Box::new(self), keys.into_iter().map(|k| k.into()).collect())
+    }
+}
+
+impl<'a, U> JsonComparable<'a> for U
+where
+    U: Into<Column<'a>>,
+{
+    #[inline]
+    fn json_get<T>(self, element: T) -> DatabaseValue<'a>
+    where
+        T: Into<JsonPathElement<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.json_get(element)
+    }
+
+    #[inline]
+    fn json_get_text<T>(self, element: T) -> DatabaseValue<'a>
+    where
+        T: Into<JsonPathElement<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.json_get_text(element)
+    }
+
+    #[inline]
+    fn json_get_path<T, I>(self, path: I) -> DatabaseValue<'a>
+    where
+        T: Into<JsonPathElement<'a>>,
+        I: IntoIterator<Item = T>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.json_get_path(path)
+    }
+
+    #[inline]
+    fn json_get_path_text<T, I>(self, path: I) -> DatabaseValue<'a>
+    where
+        T: Into<JsonPathElement<'a>>,
+        I: IntoIterator<Item = T>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.json_get_path_text(path)
+    }
+
+    #[inline]
+    fn json_contains<T>(self, value: T) -> Compare<'a>
+    where
+        T: Into<DatabaseValue<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.json_contains(value)
+    }
+
+    #[inline]
+    fn json_has_key<T>(self, key: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.json_has_key(key)
+    }
+
+    #[inline]
+    fn json_has_any_key<T, I>(self, keys: I) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = T>,
+    {
+        let col: Column<'a> = self.into();
+        let val: DatabaseValue<'a> = col.into();
+        val.json_has_any_key(keys)
+    }
+}
@@ -1,15 +1,144 @@
-use crate::ast::{Column, Ordering};
+use crate::ast::{Column, IntoOrderDefinition, Ordering};
+use std::{borrow::Cow, fmt};
+
+/// Whether a window frame is defined in rows or in values of the ordering.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum FrameUnits {
+    Rows,
+    Range,
+}
+
+impl fmt::Display for FrameUnits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FrameUnits::Rows => write!(f, "ROWS"),
+            FrameUnits::Range => write!(f, "RANGE"),
+        }
+    }
+}
+
+/// The start or the end of a window frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrameBound {
+    /// The first row of the partition.
+    UnboundedPreceding,
+    /// The given number of rows, or a range of values, before the current row.
+    Preceding(u64),
+    /// The current row, or its peers with `RANGE`.
+    CurrentRow,
+    /// The given number of rows, or a range of values, after the current row.
+    Following(u64),
+    /// The last row of the partition.
+    UnboundedFollowing,
+}
+
+impl fmt::Display for FrameBound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FrameBound::UnboundedPreceding => write!(f, "UNBOUNDED PRECEDING"),
+            FrameBound::Preceding(n) => write!(f, "{} PRECEDING", n),
+            FrameBound::CurrentRow => write!(f, "CURRENT ROW"),
+            FrameBound::Following(n) => write!(f, "{} FOLLOWING", n),
+            FrameBound::UnboundedFollowing => write!(f, "UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+
+/// The rows of the partition a window function is applied to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Frame {
+    pub(crate) units: FrameUnits,
+    pub(crate) start: FrameBound,
+    pub(crate) end: FrameBound,
+}
 
 #[derive(Debug, Default, Clone, PartialEq)]
 /// Determines the partitioning and ordering of a rowset before the associated
 /// window function is applied.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// let over = Over::new()
+///     .partition_by("user_id")
+///     .order_by("created_at")
+///     .rows(FrameBound::Preceding(2), FrameBound::CurrentRow);
+///
+/// let query = Select::from_table("orders").value(avg(Column::new("amount")).over(over));
+/// let (sql, _) = Sqlite::build(query);
+///
+/// assert_eq!(
+///     "SELECT AVG(`amount`) OVER(PARTITION BY `user_id` ORDER BY `created_at` \
+///      ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) FROM `orders`",
+///     sql
+/// );
+/// ```
 pub struct Over<'a> {
+    pub(crate) window: Option<Cow<'a, str>>,
     pub(crate) ordering: Ordering<'a>,
     pub(crate) partitioning: Vec<Column<'a>>,
+    pub(crate) frame: Option<Frame>,
 }
 
 impl<'a> Over<'a> {
+    /// An empty window, containing all rows of the query.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A window based on a named window of the `SELECT`. See
+    /// [Select::window](struct.Select.html#method.window).
+    pub fn window<S>(name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self {
+            window: Some(name.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a column to the partitioning of the window.
+    pub fn partition_by<T>(mut self, partition: T) -> Self
+    where
+        T: Into<Column<'a>>,
+    {
+        self.partitioning.push(partition.into());
+        self
+    }
+
+    /// Adds an ordering to the window.
+    pub fn order_by<T>(mut self, value: T) -> Self
+    where
+        T: IntoOrderDefinition<'a>,
+    {
+        self.ordering = self.ordering.append(value.into_order_definition());
+        self
+    }
+
+    /// Limits the window to the rows between the given bounds.
+    pub fn rows(mut self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame = Some(Frame {
+            units: FrameUnits::Rows,
+            start,
+            end,
+        });
+
+        self
+    }
+
+    /// Limits the window to the rows with the ordering value between the given
+    /// bounds. The offsets need exactly one ordering column.
+    pub fn range(mut self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame = Some(Frame {
+            units: FrameUnits::Range,
+            start,
+            end,
+        });
+
+        self
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.ordering.is_empty() && self.partitioning.is_empty()
+        self.window.is_none() && self.ordering.is_empty() && self.partitioning.is_empty() && self.frame.is_none()
     }
 }
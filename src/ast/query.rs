@@ -1,4 +1,4 @@
-use crate::ast::{Delete, Insert, Select, Union, Update};
+use crate::ast::{AlterTable, CreateIndex, CreateTable, Delete, DropIndex, DropTable, Insert, Select, Union, Update};
 use std::borrow::Cow;
 
 /// A database query
@@ -11,6 +11,11 @@ pub enum Query<'a> {
     Delete(Box<Delete<'a>>),
     Union(Union<'a>),
     Raw(Cow<'a, str>),
+    CreateTable(Box<CreateTable<'a>>),
+    AlterTable(Box<AlterTable<'a>>),
+    CreateIndex(Box<CreateIndex<'a>>),
+    DropTable(Box<DropTable<'a>>),
+    DropIndex(Box<DropIndex<'a>>),
 }
 
 impl<'a, T> From<T> for Query<'a>
@@ -62,4 +67,17 @@ impl<'a> Query<'a> {
             false
         }
     }
+
+    /// True for statements changing the schema, such as `CREATE TABLE`.
+    /// They are rendered without parameters.
+    pub fn is_ddl(&self) -> bool {
+        matches!(
+            self,
+            Query::CreateTable(_)
+                | Query::AlterTable(_)
+                | Query::CreateIndex(_)
+                | Query::DropTable(_)
+                | Query::DropIndex(_)
+        )
+    }
 }
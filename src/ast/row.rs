@@ -1,4 +1,4 @@
-use crate::ast::{Comparable, Compare, DatabaseValue};
+use crate::ast::{Comparable, Compare, ComparisonOperator, DatabaseValue, Select};
 use std::borrow::Cow;
 
 /// A collection of values surrounded by parentheses.
@@ -228,6 +228,33 @@ impl<'a> Comparable<'a> for Row<'a> {
         value.not_ends_into(pattern)
     }
 
+    #[inline]
+    fn ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let value: DatabaseValue<'a> = self.into();
+        value.ilike(pattern)
+    }
+
+    #[inline]
+    fn not_ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let value: DatabaseValue<'a> = self.into();
+        value.not_ilike(pattern)
+    }
+
+    #[inline]
+    fn matches_regex<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let value: DatabaseValue<'a> = self.into();
+        value.matches_regex(pattern)
+    }
+
     #[inline]
     fn is_null(self) -> Compare<'a> {
         let value: DatabaseValue<'a> = self.into();
@@ -259,4 +286,22 @@ impl<'a> Comparable<'a> for Row<'a> {
         let value: DatabaseValue<'a> = self.into();
         value.not_between(left, right)
     }
+
+    #[inline]
+    fn any<T>(self, operator: ComparisonOperator, selection: T) -> Compare<'a>
+    where
+        T: Into<Select<'a>>,
+    {
+        let value: DatabaseValue<'a> = self.into();
+        value.any(operator, selection)
+    }
+
+    #[inline]
+    fn all<T>(self, operator: ComparisonOperator, selection: T) -> Compare<'a>
+    where
+        T: Into<Select<'a>>,
+    {
+        let value: DatabaseValue<'a> = self.into();
+        value.all(operator, selection)
+    }
 }
@@ -1,17 +1,31 @@
 use crate::ast::*;
+use std::borrow::Cow;
+
+/// Removes duplicate rows from the result.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Distinct<'a> {
+    /// `DISTINCT`, comparing all selected values.
+    All,
+    /// `DISTINCT ON`, keeping the first row of every combination of the
+    /// given columns. Only supported on PostgreSQL.
+    On(Vec<Column<'a>>),
+}
 
 /// A builder for a `SELECT` statement.
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct Select<'a> {
+    pub(crate) distinct: Option<Distinct<'a>>,
     pub(crate) table: Option<Box<Table<'a>>>,
     pub(crate) columns: Vec<DatabaseValue<'a>>,
     pub(crate) conditions: Option<ConditionTree<'a>>,
     pub(crate) ordering: Ordering<'a>,
     pub(crate) grouping: Grouping<'a>,
     pub(crate) having: Option<ConditionTree<'a>>,
+    pub(crate) windows: Vec<(Cow<'a, str>, Over<'a>)>,
     pub(crate) limit: Option<ParameterizedValue<'a>>,
     pub(crate) offset: Option<ParameterizedValue<'a>>,
     pub(crate) joins: Vec<Join<'a>>,
+    pub(crate) with: Option<With<'a>>,
 }
 
 impl<'a> From<Select<'a>> for DatabaseValue<'a> {
@@ -171,6 +185,50 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Selects only distinct rows.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let query = Select::from_table("users").column("name").distinct();
+    /// let (sql, _) = Sqlite::build(query);
+    ///
+    /// assert_eq!("SELECT DISTINCT `name` FROM `users`", sql);
+    /// ```
+    pub fn distinct(mut self) -> Self {
+        self.distinct = Some(Distinct::All);
+        self
+    }
+
+    /// Keeps only the first row of every distinct combination of the given
+    /// columns, in the order of the query. The `ORDER BY` must start with the
+    /// same columns.
+    ///
+    /// Only supported on PostgreSQL; MySQL and SQLite return an
+    /// `UnsupportedFeature` error.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let query = Select::from_table("posts")
+    ///     .distinct_on(vec!["user_id"])
+    ///     .order_by("user_id")
+    ///     .order_by("created_at".descend());
+    ///
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!(
+    ///     "SELECT DISTINCT ON (\"user_id\") \"posts\".* FROM \"posts\" ORDER BY \"user_id\", \"created_at\" DESC",
+    ///     sql
+    /// );
+    /// ```
+    pub fn distinct_on<T, C>(mut self, columns: T) -> Self
+    where
+        T: IntoIterator<Item = C>,
+        C: Into<Column<'a>>,
+    {
+        self.distinct = Some(Distinct::On(columns.into_iter().map(|c| c.into()).collect()));
+        self
+    }
+
     /// Adds `WHERE` conditions to the query, replacing the previous conditions.
     /// See [Comparable](trait.Comparable.html#required-methods) for more
     /// examples.
@@ -425,6 +483,32 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Defines a named window in the `WINDOW` clause, usable in the window
+    /// functions of the query with [Over::window](struct.Over.html#method.window).
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let query = Select::from_table("orders")
+    ///     .value(sum(Column::new("amount")).over(Over::window("w")))
+    ///     .value(rank().over(Over::window("w").order_by("amount")))
+    ///     .window("w", Over::new().partition_by("user_id"));
+    ///
+    /// let (sql, _) = Sqlite::build(query);
+    ///
+    /// assert_eq!(
+    ///     "SELECT SUM(`amount`) OVER `w`, RANK() OVER(`w` ORDER BY `amount`) \
+    ///      FROM `orders` WINDOW `w` AS (PARTITION BY `user_id`)",
+    ///     sql
+    /// );
+    /// ```
+    pub fn window<S>(mut self, name: S, over: Over<'a>) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.windows.push((name.into(), over));
+        self
+    }
+
     /// Sets the `LIMIT` value.
     ///
     /// ```rust
@@ -452,4 +536,25 @@ impl<'a> Select<'a> {
         self.offset = Some(ParameterizedValue::from(offset));
         self
     }
+
+    /// Adds a `WITH` clause of common table expressions to the query. See
+    /// [With](struct.With.html) for more examples.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let admins = Select::from_table("users").so_that("admin".equals(true));
+    /// let query = Select::from_table("admins").with(With::new().query("admins", admins));
+    /// let (sql, params) = Sqlite::build(query);
+    ///
+    /// assert_eq!(
+    ///     "WITH `admins` AS (SELECT `users`.* FROM `users` WHERE `admin` = ?) SELECT `admins`.* FROM `admins`",
+    ///     sql
+    /// );
+    ///
+    /// assert_eq!(vec![ParameterizedValue::from(true)], params);
+    /// ```
+    pub fn with(mut self, with: With<'a>) -> Self {
+        self.with = Some(with);
+        self
+    }
 }
@@ -16,7 +16,8 @@ impl fmt::Display for UnionType {
     }
 }
 
-/// A builder for a `UNION`s over multiple `SELECT` statements.
+/// A builder for a `UNION`s over multiple `SELECT` statements. On SQLite the
+/// statements are not surrounded with parentheses.
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct Union<'a> {
     pub(crate) selects: Vec<Select<'a>>,
@@ -42,17 +43,21 @@ impl<'a> Union<'a> {
     /// statement, allowing duplicates.
     ///
     /// ```rust
-    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres, Sqlite}};
     /// let s1 = Select::default().value(1);
     /// let s2 = Select::default().value(2);
-    /// let (sql, params) = Sqlite::build(Union::new(s1).all(s2));
+    /// let (sql, params) = Sqlite::build(Union::new(s1.clone()).all(s2.clone()));
     ///
-    /// assert_eq!("(SELECT ?) UNION ALL (SELECT ?)", sql);
+    /// assert_eq!("SELECT ? UNION ALL SELECT ?", sql);
     ///
     /// assert_eq!(vec![
     ///     ParameterizedValue::from(1),
     ///     ParameterizedValue::from(2)
     /// ], params);
+    ///
+    /// let (sql, _) = Postgres::build(Union::new(s1).all(s2));
+    ///
+    /// assert_eq!("(SELECT $1) UNION ALL (SELECT $2)", sql);
     /// ```
     pub fn all(mut self, q: Select<'a>) -> Self {
         self.selects.push(q);
@@ -64,17 +69,21 @@ impl<'a> Union<'a> {
     /// statement, selecting only distinct values.
     ///
     /// ```rust
-    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres, Sqlite}};
     /// let s1 = Select::default().value(1);
     /// let s2 = Select::default().value(2);
-    /// let (sql, params) = Sqlite::build(Union::new(s1).distinct(s2));
+    /// let (sql, params) = Sqlite::build(Union::new(s1.clone()).distinct(s2.clone()));
     ///
-    /// assert_eq!("(SELECT ?) UNION (SELECT ?)", sql);
+    /// assert_eq!("SELECT ? UNION SELECT ?", sql);
     ///
     /// assert_eq!(vec![
     ///     ParameterizedValue::from(1),
     ///     ParameterizedValue::from(2)
     /// ], params);
+    ///
+    /// let (sql, _) = Postgres::build(Union::new(s1).distinct(s2));
+    ///
+    /// assert_eq!("(SELECT $1) UNION (SELECT $2)", sql);
     /// ```
     pub fn distinct(mut self, q: Select<'a>) -> Self {
         self.selects.push(q);
@@ -7,6 +7,8 @@ pub struct Update<'a> {
     pub(crate) columns: Vec<Column<'a>>,
     pub(crate) values: Vec<DatabaseValue<'a>>,
     pub(crate) conditions: Option<ConditionTree<'a>>,
+    pub(crate) with: Option<With<'a>>,
+    pub(crate) returning: Option<Vec<Column<'a>>>,
 }
 
 impl<'a> From<Update<'a>> for Query<'a> {
@@ -28,6 +30,8 @@ impl<'a> Update<'a> {
             columns: Vec::new(),
             values: Vec::new(),
             conditions: None,
+            with: None,
+            returning: None,
         }
     }
 
@@ -106,4 +110,52 @@ impl<'a> Update<'a> {
         self.conditions = Some(conditions.into());
         self
     }
+
+    /// Sets the columns returned from the updated rows. Only rendered on
+    /// PostgreSQL; MySQL and SQLite return an `UnsupportedFeature` error.
+    /// `RETURNING` came in SQLite 3.35, the bundled SQLite is older.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// let query = Update::table("users").set("foo", 1).so_that("bar".equals(false)).returning(vec!["id", "foo"]);
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!(
+    ///     "UPDATE \"users\" SET \"foo\" = $1 WHERE \"bar\" = $2 RETURNING \"id\", \"foo\"",
+    ///     sql
+    /// );
+    /// ```
+    pub fn returning<K, I>(mut self, columns: I) -> Self
+    where
+        K: Into<Column<'a>>,
+        I: IntoIterator<Item = K>,
+    {
+        self.returning = Some(columns.into_iter().map(|k| k.into()).collect());
+        self
+    }
+
+    /// Adds a `WITH` clause of common table expressions to the query.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// let inactive = Select::from_table("logins").column("user_id").so_that("age".greater_than(30));
+    /// let with = With::new().query("inactive", inactive);
+    ///
+    /// let query = Update::table("users")
+    ///     .set("active", false)
+    ///     .so_that("id".in_selection(Select::from_table("inactive").column("user_id")))
+    ///     .with(with);
+    ///
+    /// let (sql, _) = Sqlite::build(query);
+    ///
+    /// assert_eq!(
+    ///     "WITH `inactive` AS (SELECT `user_id` FROM `logins` WHERE `age` > ?) \
+    ///      UPDATE `users` SET `active` = ? WHERE `id` IN (SELECT `user_id` FROM `inactive`)",
+    ///     sql
+    /// );
+    /// ```
+    pub fn with(mut self, with: With<'a>) -> Self {
+        self.with = Some(with);
+        self
+    }
 }
@@ -17,7 +17,7 @@ use serde_json::{Number, Value};
 use uuid::Uuid;
 
 #[cfg(feature = "chrono-0_4")]
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Utc};
 
 /// A value we must parameterize for the prepared statement.
 #[derive(Debug, Clone, PartialEq)]
@@ -29,6 +29,7 @@ pub enum ParameterizedValue<'a> {
     Enum(Cow<'a, str>),
     Boolean(bool),
     Char(char),
+    Bytes(Cow<'a, [u8]>),
     #[cfg(all(feature = "array", feature = "postgresql"))]
     Array(Vec<ParameterizedValue<'a>>),
     #[cfg(feature = "json-1")]
@@ -37,6 +38,13 @@ pub enum ParameterizedValue<'a> {
     Uuid(Uuid),
     #[cfg(feature = "chrono-0_4")]
     DateTime(DateTime<Utc>),
+    /// A point in time with the offset it was given in.
+    #[cfg(feature = "chrono-0_4")]
+    DateTimeTz(DateTime<FixedOffset>),
+    #[cfg(feature = "chrono-0_4")]
+    Date(NaiveDate),
+    #[cfg(feature = "chrono-0_4")]
+    Time(NaiveTime),
 }
 
 pub(crate) struct Params<'a>(pub(crate) &'a [ParameterizedValue<'a>]);
@@ -67,6 +75,7 @@ impl<'a> fmt::Display for ParameterizedValue<'a> {
             ParameterizedValue::Enum(val) => write!(f, "\"{}\"", val),
             ParameterizedValue::Boolean(val) => write!(f, "{}", val),
             ParameterizedValue::Char(val) => write!(f, "'{}'", val),
+            ParameterizedValue::Bytes(val) => write!(f, "<{} bytes blob>", val.len()),
             #[cfg(feature = "array")]
             ParameterizedValue::Array(vals) => {
                 let len = vals.len();
@@ -87,6 +96,12 @@ impl<'a> fmt::Display for ParameterizedValue<'a> {
             ParameterizedValue::Uuid(val) => write!(f, "{}", val),
             #[cfg(feature = "chrono-0_4")]
             ParameterizedValue::DateTime(val) => write!(f, "{}", val),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::DateTimeTz(val) => write!(f, "{}", val),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Date(val) => write!(f, "{}", val),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Time(val) => write!(f, "{}", val),
         }
     }
 }
@@ -108,6 +123,7 @@ impl<'a> From<ParameterizedValue<'a>> for Value {
                     .to_string();
                 Value::String(s)
             }
+            ParameterizedValue::Bytes(bytes) => Value::String(base64::encode(&bytes)),
             ParameterizedValue::Json(v) => v,
             #[cfg(feature = "array")]
             ParameterizedValue::Array(v) => Value::Array(v.into_iter().map(Value::from).collect()),
@@ -115,6 +131,12 @@ impl<'a> From<ParameterizedValue<'a>> for Value {
             ParameterizedValue::Uuid(u) => Value::String(u.to_hyphenated().to_string()),
             #[cfg(feature = "chrono-0_4")]
             ParameterizedValue::DateTime(dt) => Value::String(dt.to_rfc3339()),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::DateTimeTz(dt) => Value::String(dt.to_rfc3339()),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Date(date) => Value::String(date.to_string()),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Time(time) => Value::String(time.to_string()),
         }
     }
 }
@@ -169,6 +191,28 @@ impl<'a> ParameterizedValue<'a> {
         }
     }
 
+    /// `true` if the `ParameterizedValue` is a binary value.
+    pub fn is_bytes(&self) -> bool {
+        matches!(self, ParameterizedValue::Bytes(_))
+    }
+
+    /// Returns a byte slice if the value is binary, otherwise `None`.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            ParameterizedValue::Bytes(bytes) => Some(bytes.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Transforms the `ParameterizedValue` to a `Vec<u8>` if it's binary,
+    /// otherwise `None`.
+    pub fn into_bytes(self) -> Option<Vec<u8>> {
+        match self {
+            ParameterizedValue::Bytes(bytes) => Some(bytes.into_owned()),
+            _ => None,
+        }
+    }
+
     /// `true` if the `ParameterizedValue` is an integer.
     pub fn is_integer(&self) -> bool {
         match self {
@@ -265,6 +309,34 @@ impl<'a> ParameterizedValue<'a> {
         }
     }
 
+    /// Returns a DateTime with its offset if the value is a DateTimeTz,
+    /// otherwise `None`.
+    #[cfg(feature = "chrono-0_4")]
+    pub fn as_datetime_tz(&self) -> Option<DateTime<FixedOffset>> {
+        match self {
+            ParameterizedValue::DateTimeTz(dt) => Some(*dt),
+            _ => None,
+        }
+    }
+
+    /// Returns a date if the value is a Date, otherwise `None`.
+    #[cfg(feature = "chrono-0_4")]
+    pub fn as_date(&self) -> Option<NaiveDate> {
+        match self {
+            ParameterizedValue::Date(date) => Some(*date),
+            _ => None,
+        }
+    }
+
+    /// Returns a time of day if the value is a Time, otherwise `None`.
+    #[cfg(feature = "chrono-0_4")]
+    pub fn as_time(&self) -> Option<NaiveTime> {
+        match self {
+            ParameterizedValue::Time(time) => Some(*time),
+            _ => None,
+        }
+    }
+
     /// `true` if the `ParameterizedValue` is a JSON value.
     #[cfg(feature = "json-1")]
     pub fn is_json(&self) -> bool {
@@ -292,6 +364,17 @@ impl<'a> ParameterizedValue<'a> {
         }
     }
 
+    /// An array of the given values, for PostgreSQL array columns and
+    /// operators.
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    pub fn array<I, T>(values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<ParameterizedValue<'a>>,
+    {
+        ParameterizedValue::Array(values.into_iter().map(|v| v.into()).collect())
+    }
+
     /// Returns a Vec<T> if the value is an array of T, otherwise `None`.
     #[cfg(all(feature = "array", feature = "postgresql"))]
     pub fn into_vec<T>(self) -> Option<Vec<T>>
@@ -331,6 +414,13 @@ pub enum DatabaseValue<'a> {
     Op(Box<SqlOp<'a>>),
     /// A `VALUES` statement
     Values(Box<Values<'a>>),
+    /// A column of the row proposed for insertion in an upsert
+    Excluded(Box<Column<'a>>),
+    /// A conditional `CASE WHEN` expression
+    Case(Box<Case<'a>>),
+    #[cfg(feature = "json-1")]
+    /// A value extracted from a JSON document
+    JsonExtract(Box<JsonExtract<'a>>),
 }
 
 /// A quick alias to create an asterisk to a table.
@@ -363,6 +453,18 @@ impl<'a> From<String> for ParameterizedValue<'a> {
     }
 }
 
+impl<'a> From<&'a [u8]> for ParameterizedValue<'a> {
+    fn from(that: &'a [u8]) -> Self {
+        ParameterizedValue::Bytes(that.into())
+    }
+}
+
+impl<'a> From<Vec<u8>> for ParameterizedValue<'a> {
+    fn from(that: Vec<u8>) -> Self {
+        ParameterizedValue::Bytes(that.into())
+    }
+}
+
 impl<'a> From<usize> for ParameterizedValue<'a> {
     #[inline]
     fn from(that: usize) -> Self {
@@ -418,6 +520,16 @@ impl<'a> TryFrom<ParameterizedValue<'a>> for String {
     }
 }
 
+impl<'a> TryFrom<ParameterizedValue<'a>> for Vec<u8> {
+    type Error = Error;
+
+    fn try_from(value: ParameterizedValue<'a>) -> Result<Vec<u8>, Self::Error> {
+        value
+            .into_bytes()
+            .ok_or_else(|| Error::builder(ErrorKind::ConversionError("Not bytes")).build())
+    }
+}
+
 impl<'a> TryFrom<ParameterizedValue<'a>> for bool {
     type Error = Error;
 
@@ -439,6 +551,39 @@ impl<'a> TryFrom<ParameterizedValue<'a>> for DateTime<Utc> {
     }
 }
 
+#[cfg(feature = "chrono-0_4")]
+impl<'a> TryFrom<ParameterizedValue<'a>> for DateTime<FixedOffset> {
+    type Error = Error;
+
+    fn try_from(value: ParameterizedValue<'a>) -> Result<DateTime<FixedOffset>, Self::Error> {
+        value
+            .as_datetime_tz()
+            .ok_or_else(|| Error::builder(ErrorKind::ConversionError("Not a datetime with an offset")).build())
+    }
+}
+
+#[cfg(feature = "chrono-0_4")]
+impl<'a> TryFrom<ParameterizedValue<'a>> for NaiveDate {
+    type Error = Error;
+
+    fn try_from(value: ParameterizedValue<'a>) -> Result<NaiveDate, Self::Error> {
+        value
+            .as_date()
+            .ok_or_else(|| Error::builder(ErrorKind::ConversionError("Not a date")).build())
+    }
+}
+
+#[cfg(feature = "chrono-0_4")]
+impl<'a> TryFrom<ParameterizedValue<'a>> for NaiveTime {
+    type Error = Error;
+
+    fn try_from(value: ParameterizedValue<'a>) -> Result<NaiveTime, Self::Error> {
+        value
+            .as_time()
+            .ok_or_else(|| Error::builder(ErrorKind::ConversionError("Not a time")).build())
+    }
+}
+
 #[macro_export]
 /// Marks a given string as a value. Useful when using a value in calculations,
 /// e.g.
@@ -493,6 +638,20 @@ impl<'a> From<DateTime<Utc>> for ParameterizedValue<'a> {
     }
 }
 
+#[cfg(feature = "chrono-0_4")]
+parameterized_value!(NaiveDate, Date);
+
+#[cfg(feature = "chrono-0_4")]
+parameterized_value!(NaiveTime, Time);
+
+#[cfg(feature = "chrono-0_4")]
+impl<'a> From<DateTime<FixedOffset>> for ParameterizedValue<'a> {
+    #[inline]
+    fn from(that: DateTime<FixedOffset>) -> Self {
+        ParameterizedValue::DateTimeTz(that)
+    }
+}
+
 impl<'a> From<f64> for ParameterizedValue<'a> {
     #[inline]
     fn from(that: f64) -> Self {
@@ -674,6 +833,30 @@ impl<'a> Comparable<'a> for DatabaseValue<'a> {
         Compare::NotEndsInto(Box::new(self), pattern.into())
     }
 
+    #[inline]
+    fn ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Compare::ILike(Box::new(self), pattern.into())
+    }
+
+    #[inline]
+    fn not_ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Compare::NotILike(Box::new(self), pattern.into())
+    }
+
+    #[inline]
+    fn matches_regex<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Compare::MatchesRegex(Box::new(self), pattern.into())
+    }
+
     #[inline]
     fn is_null(self) -> Compare<'a> {
         Compare::Null(Box::new(self))
@@ -701,6 +884,22 @@ impl<'a> Comparable<'a> for DatabaseValue<'a> {
     {
         Compare::NotBetween(Box::new(self), Box::new(left.into()), Box::new(right.into()))
     }
+
+    #[inline]
+    fn any<T>(self, operator: ComparisonOperator, selection: T) -> Compare<'a>
+    where
+        T: Into<Select<'a>>,
+    {
+        Compare::Any(Box::new(self), operator, Box::new(selection.into()))
+    }
+
+    #[inline]
+    fn all<T>(self, operator: ComparisonOperator, selection: T) -> Compare<'a>
+    where
+        T: Into<Select<'a>>,
+    {
+        Compare::All(Box::new(self), operator, Box::new(selection.into()))
+    }
 }
 
 #[cfg(all(test, feature = "array", feature = "postgresql"))]
@@ -764,6 +963,15 @@ mod tests {
 
         assert!(rslt.is_none());
     }
+
+    #[test]
+    #[cfg(feature = "json-1")]
+    fn bytes_are_converted_into_base64_json() {
+        let pv = ParameterizedValue::from(&b"quaint"[..]);
+        let json: serde_json::Value = pv.into();
+
+        assert_eq!(serde_json::Value::String("cXVhaW50".into()), json);
+    }
 }
 
 /// An in-memory temporary table. Can be used in some of the databases in a
@@ -7,8 +7,11 @@
 //! [PostgreSQL](struct.PostgreSql.html) and [SQLite](struct.Sqlite.html) connect
 //! to the corresponding databases and implement the
 //! [Queryable](trait.Queryable.html) trait for generalized querying interface.
+mod bulk_load;
+mod introspect;
 mod queryable;
 mod result_set;
+mod result_stream;
 mod transaction;
 
 mod dbio;
@@ -32,6 +35,12 @@ pub use connection_info::*;
 
 pub(crate) mod metrics;
 pub use self::result_set::*;
+pub use self::result_stream::*;
+pub use bulk_load::*;
 pub use dbio::DBIO;
+pub use introspect::{
+    ColumnDescription, EnumDescription, ForeignKeyDescription, IndexDescription, Introspect, SchemaDescription,
+    TableDescription,
+};
 pub use queryable::*;
 pub use transaction::*;
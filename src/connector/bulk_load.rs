@@ -0,0 +1,52 @@
+use super::{Queryable, DBIO};
+use crate::ast::*;
+use futures::stream::{Stream, StreamExt};
+
+/// A connection that can load large amounts of rows into a table.
+pub trait BulkLoad: Queryable {
+    /// Inserts the rows of the stream into the given columns of the table,
+    /// returning the number of inserted rows.
+    ///
+    /// By default the rows are inserted with multi-row `INSERT` statements
+    /// binding as many values as the database allows. PostgreSQL loads the
+    /// rows with a binary `COPY FROM STDIN` instead, see
+    /// [PostgreSql::copy_in](struct.PostgreSql.html#method.copy_in).
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, connector::*};
+    /// # use futures::stream;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), quaint::error::Error> {
+    /// let conn = Sqlite::new("db/test.db")?;
+    /// conn.raw_cmd("CREATE TABLE users (id INTEGER, name TEXT)").await?;
+    ///
+    /// let rows = stream::iter((0..5000).map(|i| vec![ParameterizedValue::from(i), "Musti".into()]));
+    /// let loaded = conn.bulk_load("users", vec!["id", "name"], rows).await?;
+    ///
+    /// assert_eq!(5000, loaded);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn bulk_load<'a, T, C, S>(&'a self, table: T, columns: Vec<C>, rows: S) -> DBIO<'a, u64>
+    where
+        T: Into<Table<'a>>,
+        C: Into<Column<'a>>,
+        S: Stream<Item = Vec<ParameterizedValue<'a>>> + Send + 'a,
+    {
+        let columns: Vec<Column<'a>> = columns.into_iter().map(|c| c.into()).collect();
+        let insert = Insert::multi_into(table, columns);
+
+        DBIO::new(async move {
+            let rows_per_batch = insert.rows_per_batch(self.sql_family().max_bind_values());
+            let mut batches = Box::pin(rows.chunks(rows_per_batch));
+            let mut changes = 0;
+
+            while let Some(batch) = batches.next().await {
+                let insert = batch.into_iter().fold(insert.clone(), |insert, row| insert.values(row));
+                changes += self.execute(insert.build().into()).await?;
+            }
+
+            Ok(changes)
+        })
+    }
+}
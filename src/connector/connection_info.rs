@@ -53,10 +53,16 @@ impl ConnectionInfo {
         let url = url_result?;
 
         let sql_family = SqlFamily::from_scheme(url.scheme()).ok_or_else(|| {
-            let kind =
-                ErrorKind::DatabaseUrlIsInvalid(format!("{} is not a supported database URL scheme.", url.scheme()));
+            let message = match Self::feature_for_scheme(url.scheme()) {
+                Some(feature) => format!(
+                    "{} is not a supported database URL scheme, quaint was compiled without the `{}` feature.",
+                    url.scheme(),
+                    feature
+                ),
+                None => format!("{} is not a supported database URL scheme.", url.scheme()),
+            };
 
-            Error::builder(kind).build()
+            Error::builder(ErrorKind::DatabaseUrlIsInvalid(message)).build()
         })?;
 
         match sql_family {
@@ -76,6 +82,16 @@ impl ConnectionInfo {
         }
     }
 
+    /// The cargo feature enabling the database of a URL scheme.
+    fn feature_for_scheme(scheme: &str) -> Option<&'static str> {
+        match scheme {
+            "sqlite" | "file" => Some("sqlite"),
+            "postgres" | "postgresql" => Some("postgresql"),
+            "mysql" => Some("mysql"),
+            _ => None,
+        }
+    }
+
     /// The provided database name. This will be `None` on SQLite.
     pub fn dbname(&self) -> Option<&str> {
         match self {
@@ -202,6 +218,19 @@ impl SqlFamily {
         }
     }
 
+    /// The maximum number of parameters a single query can bind.
+    pub fn max_bind_values(self) -> usize {
+        match self {
+            #[cfg(feature = "postgresql")]
+            SqlFamily::Postgres => 32767,
+            #[cfg(feature = "mysql")]
+            SqlFamily::Mysql => 65535,
+            // The default of `SQLITE_MAX_VARIABLE_NUMBER` before SQLite 3.32.
+            #[cfg(feature = "sqlite")]
+            SqlFamily::Sqlite => 999,
+        }
+    }
+
     /// Convert url scheme to an SqlFamily.
     pub fn from_scheme(url_scheme: &str) -> Option<Self> {
         match url_scheme {
@@ -266,4 +295,30 @@ mod tests {
             _ => panic!("wrong"),
         }
     }
+
+    #[test]
+    #[cfg(feature = "sqlite")]
+    fn unknown_schemes_should_return_an_error() {
+        let err = ConnectionInfo::from_url("mssql://localhost/master").unwrap_err();
+
+        match err.kind() {
+            ErrorKind::DatabaseUrlIsInvalid(message) => {
+                assert_eq!("mssql is not a supported database URL scheme.", message)
+            }
+            e => panic!("Expected `DatabaseUrlIsInvalid`, got {:?}", e),
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "sqlite", not(feature = "mysql")))]
+    fn schemes_of_disabled_features_should_name_the_feature() {
+        let err = ConnectionInfo::from_url("mysql://localhost/test").unwrap_err();
+
+        match err.kind() {
+            ErrorKind::DatabaseUrlIsInvalid(message) => {
+                assert!(message.contains("compiled without the `mysql` feature"))
+            }
+            e => panic!("Expected `DatabaseUrlIsInvalid`, got {:?}", e),
+        }
+    }
 }
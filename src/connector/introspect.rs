@@ -0,0 +1,230 @@
+use super::{Queryable, SqlFamily, DBIO};
+use crate::ast::ParameterizedValue;
+use std::collections::BTreeMap;
+
+/// The tables and enums of a database schema.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaDescription {
+    /// The tables, ordered by name.
+    pub tables: Vec<TableDescription>,
+    /// The enum types, ordered by name. MySQL defines the enums per column,
+    /// they are named as `table_column`.
+    pub enums: Vec<EnumDescription>,
+}
+
+impl SchemaDescription {
+    /// The table with the given name, if it exists.
+    pub fn table(&self, name: &str) -> Option<&TableDescription> {
+        self.tables.iter().find(|t| t.name == name)
+    }
+}
+
+/// A table of a schema.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableDescription {
+    pub name: String,
+    /// The columns, in the order of their definition.
+    pub columns: Vec<ColumnDescription>,
+    /// The columns of the primary key, empty if the table has none.
+    pub primary_key: Vec<String>,
+    /// The indexes of the table, excluding the primary key.
+    pub indexes: Vec<IndexDescription>,
+    pub foreign_keys: Vec<ForeignKeyDescription>,
+}
+
+impl TableDescription {
+    /// The column with the given name, if it exists.
+    pub fn column(&self, name: &str) -> Option<&ColumnDescription> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+}
+
+/// A column of a table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnDescription {
+    pub name: String,
+    /// The type as written by the database, such as `character varying(255)`
+    /// on PostgreSQL or `int(11)` on MySQL.
+    pub data_type: String,
+    pub nullable: bool,
+    /// The default value as an SQL expression.
+    pub default: Option<String>,
+}
+
+/// An index of a table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndexDescription {
+    pub name: String,
+    /// The indexed columns in the index order. Expressions are left out.
+    pub columns: Vec<String>,
+    pub unique: bool,
+}
+
+/// A foreign key of a table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ForeignKeyDescription {
+    /// The name of the constraint. SQLite foreign keys have no name.
+    pub name: Option<String>,
+    pub columns: Vec<String>,
+    pub referenced_table: String,
+    /// The referenced columns, matching `columns` by position.
+    pub referenced_columns: Vec<String>,
+}
+
+/// An enum type and its values in their sort order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EnumDescription {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+/// A connection that can describe the contents of the database.
+pub trait Introspect: Queryable {
+    /// Describes the tables and enums of the given schema: the attached
+    /// database name on SQLite, the schema on PostgreSQL and the database on
+    /// MySQL. See
+    /// [ConnectionInfo::schema_name](struct.ConnectionInfo.html#method.schema_name).
+    ///
+    /// ```rust
+    /// # use quaint::connector::*;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), quaint::error::Error> {
+    /// let conn = Sqlite::new("db/test.db")?;
+    /// conn.raw_cmd("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)").await?;
+    ///
+    /// let schema = conn.describe_schema("main").await?;
+    /// let users = schema.table("users").unwrap();
+    ///
+    /// assert_eq!(vec!["id"], users.primary_key);
+    /// assert_eq!("TEXT", users.column("name").unwrap().data_type);
+    /// assert!(!users.column("name").unwrap().nullable);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn describe_schema<'a>(&'a self, schema: &'a str) -> DBIO<'a, SchemaDescription> {
+        DBIO::new(async move {
+            match self.sql_family() {
+                #[cfg(feature = "postgresql")]
+                SqlFamily::Postgres => super::postgres::introspect::describe(self, schema).await,
+                #[cfg(feature = "mysql")]
+                SqlFamily::Mysql => super::mysql::introspect::describe(self, schema).await,
+                #[cfg(feature = "sqlite")]
+                SqlFamily::Sqlite => super::sqlite::introspect::describe(self, schema).await,
+            }
+        })
+    }
+}
+
+/// Collects the tables of a schema by name while reading the descriptions.
+#[derive(Default)]
+pub(crate) struct SchemaBuilder {
+    tables: BTreeMap<String, TableDescription>,
+    enums: Vec<EnumDescription>,
+}
+
+impl SchemaBuilder {
+    pub(crate) fn add_table(&mut self, name: String) {
+        self.tables.entry(name.clone()).or_insert_with(|| TableDescription {
+            name,
+            ..Default::default()
+        });
+    }
+
+    /// The table of the given name. Values of tables not added before, such
+    /// as views, are collected into a table that is thrown away.
+    pub(crate) fn table(&mut self, name: &str) -> &mut TableDescription {
+        self.tables.entry(name.to_string()).or_default()
+    }
+
+    /// Adds a column to the index, or a new index if the last index of the
+    /// table has a different name.
+    pub(crate) fn add_index_column(&mut self, table: &str, index: String, column: String, unique: bool) {
+        let indexes = &mut self.table(table).indexes;
+
+        match indexes.last_mut() {
+            Some(last) if last.name == index => last.columns.push(column),
+            _ => indexes.push(IndexDescription {
+                name: index,
+                columns: vec![column],
+                unique,
+            }),
+        }
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    /// Adds a column pair to the foreign key, or a new foreign key if the
+    /// last foreign key of the table has a different name.
+    pub(crate) fn add_foreign_key_column(
+        &mut self,
+        table: &str,
+        name: Option<String>,
+        column: String,
+        referenced_table: String,
+        referenced_column: String,
+    ) {
+        let foreign_keys = &mut self.table(table).foreign_keys;
+
+        match foreign_keys.last_mut() {
+            Some(last) if last.name == name && last.referenced_table == referenced_table => {
+                last.columns.push(column);
+                last.referenced_columns.push(referenced_column);
+            }
+            _ => foreign_keys.push(ForeignKeyDescription {
+                name,
+                columns: vec![column],
+                referenced_table,
+                referenced_columns: vec![referenced_column],
+            }),
+        }
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    /// Adds a value to the enum, or a new enum if the last enum has a
+    /// different name.
+    pub(crate) fn add_enum_value(&mut self, name: String, value: String) {
+        match self.enums.last_mut() {
+            Some(last) if last.name == name => last.values.push(value),
+            _ => self.enums.push(EnumDescription {
+                name,
+                values: vec![value],
+            }),
+        }
+    }
+
+    pub(crate) fn build(self) -> SchemaDescription {
+        let mut enums = self.enums;
+        enums.sort_by(|a, b| a.name.cmp(&b.name));
+
+        SchemaDescription {
+            tables: self
+                .tables
+                .into_values()
+                .filter(|table| !table.name.is_empty())
+                .collect(),
+            enums,
+        }
+    }
+}
+
+/// A string value of an introspection query, also accepting the binary
+/// strings some MySQL versions return from `information_schema`.
+pub(crate) fn string_value(value: &ParameterizedValue<'_>) -> Option<String> {
+    match value {
+        ParameterizedValue::Text(s) | ParameterizedValue::Enum(s) => Some(s.to_string()),
+        ParameterizedValue::Bytes(b) => Some(String::from_utf8_lossy(b).into_owned()),
+        ParameterizedValue::Integer(i) => Some(i.to_string()),
+        _ => None,
+    }
+}
+
+/// A flag of an introspection query, as a boolean, a number or `YES`/`NO`.
+pub(crate) fn bool_value(value: &ParameterizedValue<'_>) -> bool {
+    match value {
+        ParameterizedValue::Boolean(b) => *b,
+        ParameterizedValue::Integer(i) => *i != 0,
+        value => match string_value(value) {
+            Some(s) => s.eq_ignore_ascii_case("yes") || s == "1",
+            None => false,
+        },
+    }
+}
@@ -1,17 +1,24 @@
 mod conversion;
 mod error;
+pub(crate) mod introspect;
 
+use futures::{channel::mpsc, future::FutureExt, stream, task::Poll};
 use mysql_async::{self as my, prelude::Queryable as _};
 use percent_encoding::percent_decode;
-use std::{borrow::Cow, future::Future, path::Path, time::Duration};
+use std::{borrow::Cow, convert::TryFrom, future::Future, path::Path, time::Duration};
 use tokio::time::timeout;
 use url::Url;
 
 use crate::{
-    ast::{ParameterizedValue, Query},
-    connector::{metrics, queryable::*, ResultSet, DBIO},
+    ast::{
+        Column, Comparable, DatabaseValue, Insert, OnConflict, Orderable, ParameterizedValue, Query, Select, Table,
+        TableType,
+    },
+    connector::{
+        metrics, queryable::*, BulkLoad, Introspect, PendingRollback, ResultSet, ResultStream, SqlFamily, DBIO,
+    },
     error::{Error, ErrorKind},
-    visitor::{self, Visitor},
+    visitor,
 };
 
 /// A connector interface for the MySQL database.
@@ -19,6 +26,7 @@ use crate::{
 pub struct Mysql {
     pub(crate) pool: my::Pool,
     pub(crate) url: MysqlUrl,
+    pending_rollback: PendingRollback,
     socket_timeout: Option<Duration>,
     connect_timeout: Duration,
 }
@@ -232,10 +240,22 @@ impl Mysql {
             socket_timeout: url.query_params.socket_timeout,
             connect_timeout: url.query_params.connect_timeout,
             pool: my::Pool::new(opts),
+            pending_rollback: PendingRollback::default(),
             url,
         })
     }
 
+    /// Gets the connection, first rolling back a transaction dropped since
+    /// the last query.
+    async fn get_conn(&self) -> crate::Result<my::Conn> {
+        let conn = timeout(self.connect_timeout, self.pool.get_conn()).await??;
+
+        match self.pending_rollback.take() {
+            Some(rollback) => self.timeout(conn.drop_query(rollback)).await,
+            None => Ok(conn),
+        }
+    }
+
     async fn timeout<T, F, E>(&self, f: F) -> crate::Result<T>
     where
         F: Future<Output = std::result::Result<T, E>>,
@@ -253,26 +273,210 @@ impl Mysql {
             },
         }
     }
+
+    /// Emulates `RETURNING` by selecting the inserted rows with their
+    /// auto-increment key. The keys are either given in the insert, or
+    /// computed from the last insert id, the number of inserted rows and the
+    /// `auto_increment_increment` of the session. Fails after inserting if
+    /// the database skipped or updated any of the rows, without rolling them
+    /// back.
+    async fn insert_returning<'a>(&'a self, mut insert: Insert<'a>) -> crate::Result<ResultSet> {
+        if insert.on_conflict.is_some() {
+            return Err(unsupported_feature("RETURNING with a conflict resolution"));
+        }
+
+        let returning = insert.returning.take().unwrap_or_default();
+        let table = insert.table.clone();
+        let key = self.auto_increment_column(&table).await?;
+
+        // `DEFAULT VALUES` inserts one row.
+        let inserted_rows = u64::try_from(insert.values.len().max(1))?;
+
+        let given_keys = match insert.columns.iter().position(|c| c.name == key) {
+            Some(i) => given_keys(insert.values.iter().map(|row| row.values[i].clone()).collect())?,
+            None => None,
+        };
+
+        let (sql, params) = visitor::Mysql::build_supported(insert)?;
+
+        let (affected_rows, last_id, increment) = metrics::query("mysql.query_raw", &sql, &params, || async {
+            let conn = self.get_conn().await?;
+            let results = self
+                .timeout(conn.prep_exec(&sql, conversion::conv_params(&params)?))
+                .await?;
+
+            let (affected_rows, last_id) = (results.affected_rows(), results.last_insert_id());
+
+            // The increment is a session variable, read from the connection
+            // of the insert.
+            let conn = self.timeout(results.drop_result()).await?;
+            let (_, increment) = self
+                .timeout(conn.first::<_, u64>("SELECT @@auto_increment_increment"))
+                .await?;
+
+            Ok((affected_rows, last_id, increment.unwrap_or(1)))
+        })
+        .await?;
+
+        if affected_rows != inserted_rows {
+            return Err(unsupported_feature("RETURNING with skipped or updated rows"));
+        }
+
+        let conditions = match given_keys {
+            Some(keys) => Column::from(key.clone()).in_selection(keys),
+            None => {
+                let first = i64::try_from(last_id.unwrap_or(0))?;
+                let increment = i64::try_from(increment)?;
+                let keys: Vec<i64> = (0..i64::try_from(affected_rows)?)
+                    .map(|i| first + i * increment)
+                    .collect();
+
+                Column::from(key.clone()).in_selection(keys)
+            }
+        };
+
+        let select = Select::from_table(table)
+            .columns(returning)
+            .so_that(conditions)
+            .order_by(Column::from(key).ascend());
+
+        let mut result_set = self.query(select.into()).await?;
+
+        if let Some(id) = last_id {
+            result_set.set_last_insert_id(id);
+        }
+
+        Ok(result_set)
+    }
+
+    /// The name of the auto-increment column of the table.
+    async fn auto_increment_column(&self, table: &Table<'_>) -> crate::Result<String> {
+        let name = match table.typ {
+            TableType::Table(ref name) => name,
+            _ => return Err(unsupported_feature("RETURNING from a subquery")),
+        };
+
+        let database = table.database.clone().map(ParameterizedValue::Text);
+        let params = [
+            database.unwrap_or(ParameterizedValue::Null),
+            ParameterizedValue::Text(name.clone()),
+        ];
+
+        let sql = "SELECT COLUMN_NAME FROM information_schema.COLUMNS \
+                   WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_NAME = ? \
+                   AND EXTRA LIKE '%auto_increment%'";
+
+        let rows = self.query_raw(sql, &params).await?;
+
+        match rows.into_single().ok().and_then(|row| row[0].to_string()) {
+            Some(column) => Ok(column),
+            None => Err(unsupported_feature("RETURNING without an auto-increment key")),
+        }
+    }
+}
+
+/// The keys given for the inserted rows, or `None` if MySQL generates all of
+/// them for `NULL` or `0`. Mixing the two, or computing the keys in the
+/// query, leaves no way to find the inserted rows.
+fn given_keys(keys: Vec<DatabaseValue<'_>>) -> crate::Result<Option<Vec<DatabaseValue<'_>>>> {
+    let mut generated = 0;
+
+    for key in keys.iter() {
+        match key {
+            DatabaseValue::Parameterized(ParameterizedValue::Null)
+            | DatabaseValue::Parameterized(ParameterizedValue::Integer(0)) => generated += 1,
+            DatabaseValue::Parameterized(_) => (),
+            _ => return Err(unsupported_feature("RETURNING with computed keys")),
+        }
+    }
+
+    match generated {
+        0 => Ok(Some(keys)),
+        n if n == keys.len() => Ok(None),
+        _ => Err(unsupported_feature("RETURNING with given and generated keys")),
+    }
+}
+
+fn unsupported_feature(feature: &'static str) -> Error {
+    let kind = ErrorKind::UnsupportedFeature {
+        feature,
+        database: "MySQL",
+    };
+
+    Error::builder(kind).build()
+}
+
+/// MySQL has no `RETURNING` clause for `UPDATE` and `DELETE` statements,
+/// no `DISTINCT ON`, no partial indexes and no `IF EXISTS` for indexes.
+fn check_supported(q: &Query) -> crate::Result<()> {
+    let returning = match q {
+        Query::Update(update) => update.returning.is_some(),
+        Query::Delete(delete) => delete.returning.is_some(),
+        _ => false,
+    };
+
+    if returning {
+        return Err(unsupported_feature("RETURNING"));
+    }
+
+    if let Query::Insert(insert) = q {
+        if let Some(OnConflict::DoUpdate(update)) = &insert.on_conflict {
+            if update.columns.is_empty() {
+                return Err(unsupported_feature("ON DUPLICATE KEY UPDATE without assignments"));
+            }
+
+            if update.conditions.is_some() {
+                return Err(unsupported_feature("Conditions in ON DUPLICATE KEY UPDATE"));
+            }
+        }
+    }
+
+    match q {
+        Query::Insert(insert) if insert.with.is_some() => Err(unsupported_feature("WITH in INSERT")),
+        Query::CreateIndex(index) if index.conditions.is_some() => Err(unsupported_feature("Partial indexes")),
+        Query::CreateIndex(index) if index.if_not_exists => Err(unsupported_feature("CREATE INDEX IF NOT EXISTS")),
+        Query::DropIndex(index) if index.if_exists => Err(unsupported_feature("DROP INDEX IF EXISTS")),
+        _ => Ok(()),
+    }
 }
 
 impl TransactionCapable for Mysql {}
 
+impl BulkLoad for Mysql {}
+
+impl Introspect for Mysql {}
+
 impl Queryable for Mysql {
     fn query<'a>(&'a self, q: Query<'a>) -> DBIO<'a, ResultSet> {
-        let (sql, params) = visitor::Mysql::build(q);
-        DBIO::new(async move { self.query_raw(&sql, &params).await })
+        DBIO::new(async move {
+            check_supported(&q)?;
+
+            match q {
+                Query::Insert(insert) if insert.returning.as_ref().map(|r| !r.is_empty()).unwrap_or(false) => {
+                    self.insert_returning(*insert).await
+                }
+                q => {
+                    let (sql, params) = visitor::Mysql::build_supported(q)?;
+                    self.query_raw(&sql, &params).await
+                }
+            }
+        })
     }
 
     fn execute<'a>(&'a self, q: Query<'a>) -> DBIO<'a, u64> {
-        let (sql, params) = visitor::Mysql::build(q);
-        DBIO::new(async move { self.execute_raw(&sql, &params).await })
+        DBIO::new(async move {
+            check_supported(&q)?;
+
+            let (sql, params) = visitor::Mysql::build_supported(q)?;
+            self.execute_raw(&sql, &params).await
+        })
     }
 
     fn query_raw<'a>(&'a self, sql: &'a str, params: &'a [ParameterizedValue]) -> DBIO<'a, ResultSet> {
         metrics::query("mysql.query_raw", sql, params, move || async move {
-            let conn = timeout(self.connect_timeout, self.pool.get_conn()).await??;
+            let conn = self.get_conn().await?;
             let results = self
-                .timeout(conn.prep_exec(sql, conversion::conv_params(params)))
+                .timeout(conn.prep_exec(sql, conversion::conv_params(params)?))
                 .await?;
 
             let columns = results
@@ -300,11 +504,71 @@ impl Queryable for Mysql {
         })
     }
 
+    fn query_stream<'a>(&'a self, sql: &'a str, params: &'a [ParameterizedValue<'a>]) -> DBIO<'a, ResultStream<'a>> {
+        metrics::query("mysql.query_stream", sql, params, move || async move {
+            let conn = self.get_conn().await?;
+            let results = self
+                .timeout(conn.prep_exec(sql, conversion::conv_params(params)?))
+                .await?;
+
+            let columns = results
+                .columns_ref()
+                .iter()
+                .map(|s| s.name_str().into_owned())
+                .collect();
+
+            // mysql_async only hands out the rows to a synchronous callback,
+            // which can't wait for a bounded channel. The stream polls the
+            // reader only when the rows sent so far are consumed, but every
+            // poll reads all rows already received from the server into the
+            // channel, so the memory use is not bounded.
+            let (sender, mut receiver) = mpsc::unbounded();
+            let row_sender = sender.clone();
+
+            let mut reader = Some(
+                async move {
+                    let res = results
+                        .for_each_and_drop(move |mut row| {
+                            let _ = row_sender.unbounded_send(row.take_result_row());
+                        })
+                        .await;
+
+                    if let Err(e) = res {
+                        let _ = sender.unbounded_send(Err(e.into()));
+                    }
+                }
+                .boxed(),
+            );
+
+            let rows = stream::poll_fn(move |cx| loop {
+                match receiver.try_recv() {
+                    Ok(row) => return Poll::Ready(Some(row)),
+                    // The finished reader dropped the senders.
+                    Err(e) if e.is_closed() => return Poll::Ready(None),
+                    Err(_) => (),
+                }
+
+                match reader.as_mut().map(|r| r.poll_unpin(cx)) {
+                    Some(Poll::Ready(())) => reader = None,
+                    Some(Poll::Pending) => {
+                        return match receiver.try_recv() {
+                            Ok(row) => Poll::Ready(Some(row)),
+                            Err(_) => Poll::Pending,
+                        }
+                    }
+                    None => return Poll::Ready(None),
+                }
+            });
+
+            Ok(ResultStream::new(columns, rows))
+        })
+    }
+
     fn execute_raw<'a>(&'a self, sql: &'a str, params: &'a [ParameterizedValue<'a>]) -> DBIO<'a, u64> {
         metrics::query("mysql.execute_raw", sql, params, move || async move {
-            let conn = timeout(self.connect_timeout, self.pool.get_conn()).await??;
+            let conn = self.get_conn().await?;
             let results = self
-                .timeout(conn.prep_exec(sql, conversion::conv_params(params)))
+                .timeout(conn.prep_exec(sql, conversion::conv_params(params)?))
                 .await?;
             Ok(results.affected_rows())
         })
@@ -312,18 +576,27 @@ impl Queryable for Mysql {
 
     fn raw_cmd<'a>(&'a self, cmd: &'a str) -> DBIO<'a, ()> {
         metrics::query("mysql.raw_cmd", cmd, &[], move || async move {
-            let conn = timeout(self.connect_timeout, self.pool.get_conn()).await??;
+            let conn = self.get_conn().await?;
             self.timeout(conn.query(cmd)).await?;
 
             Ok(())
         })
     }
+
+    fn sql_family(&self) -> SqlFamily {
+        SqlFamily::Mysql
+    }
+
+    fn transaction_dropped(&self, _depth: u32, rollback: &str) {
+        self.pending_rollback.set(rollback);
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::MysqlUrl;
-    use crate::{connector::Queryable, error::*, single::Quaint};
+    use super::{Mysql, MysqlUrl};
+    use crate::{ast::*, connector::Queryable, error::*, single::Quaint};
+    use futures::stream::TryStreamExt;
     use lazy_static::lazy_static;
     use std::env;
     use url::Url;
@@ -332,6 +605,16 @@ mod tests {
         static ref CONN_STR: String = env::var("TEST_MYSQL").expect("TEST_MYSQL env var");
     }
 
+    fn assert_unsupported(err: Error, expected: &str) {
+        match err.kind() {
+            ErrorKind::UnsupportedFeature { feature, database } => {
+                assert_eq!(expected, *feature);
+                assert_eq!("MySQL", *database);
+            }
+            e => panic!("Expected `UnsupportedFeature`, got {:?}", e),
+        }
+    }
+
     #[test]
     fn should_parse_socket_url() {
         let url = MysqlUrl::new(Url::parse("mysql://root@localhost/dbname?socket=(/tmp/mysql.sock)").unwrap()).unwrap();
@@ -339,6 +622,168 @@ mod tests {
         assert_eq!(&Some(String::from("/tmp/mysql.sock")), url.socket());
     }
 
+    #[tokio::test]
+    async fn returning_should_return_an_unsupported_feature_error() {
+        let url = MysqlUrl::new(Url::parse("mysql://root@localhost/test").unwrap()).unwrap();
+        let conn = Mysql::new(url).unwrap();
+
+        let update = Update::table("users").set("name", "Musti").returning(vec!["id"]);
+        let err = conn.update_returning(update).await.unwrap_err();
+
+        assert_unsupported(err, "RETURNING");
+
+        let delete = Delete::from_table("users").returning(vec!["id"]);
+        let err = conn.delete_returning(delete).await.unwrap_err();
+
+        assert_unsupported(err, "RETURNING");
+    }
+
+    #[tokio::test]
+    async fn insert_with_should_return_an_unsupported_feature_error() {
+        let url = MysqlUrl::new(Url::parse("mysql://root@localhost/test").unwrap()).unwrap();
+        let conn = Mysql::new(url).unwrap();
+
+        let defaults = Select::default().value(1);
+        let insert = Insert::single_into("users").value("id", 1).build();
+        let err = conn
+            .insert(insert.with(With::new().query("defaults", defaults)))
+            .await
+            .unwrap_err();
+
+        assert_unsupported(err, "WITH in INSERT");
+    }
+
+    #[tokio::test]
+    async fn upsert_conditions_should_return_an_unsupported_feature_error() {
+        let url = MysqlUrl::new(Url::parse("mysql://root@localhost/test").unwrap()).unwrap();
+        let conn = Mysql::new(url).unwrap();
+
+        let update = ConflictUpdate::columns(vec!["id"])
+            .set("visits", 2)
+            .so_that(("users", "locked").equals(false));
+
+        let query = Insert::single_into("users").value("id", 1);
+        let err = conn
+            .insert(Insert::from(query).on_conflict(OnConflict::DoUpdate(update)))
+            .await
+            .unwrap_err();
+
+        assert_unsupported(err, "Conditions in ON DUPLICATE KEY UPDATE");
+    }
+
+    #[tokio::test]
+    async fn upsert_without_assignments_should_return_an_unsupported_feature_error() {
+        let url = MysqlUrl::new(Url::parse("mysql://root@localhost/test").unwrap()).unwrap();
+        let conn = Mysql::new(url).unwrap();
+
+        let query = Insert::single_into("users").value("id", 1);
+        let update = OnConflict::DoUpdate(ConflictUpdate::columns(vec!["id"]));
+        let err = conn.insert(Insert::from(query).on_conflict(update)).await.unwrap_err();
+
+        assert_unsupported(err, "ON DUPLICATE KEY UPDATE without assignments");
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    async fn array_comparisons_should_return_an_unsupported_feature_error() {
+        let url = MysqlUrl::new(Url::parse("mysql://root@localhost/test").unwrap()).unwrap();
+        let connection = Mysql::new(url).unwrap();
+        let comparisons = vec![
+            "tags".array_contains(Column::from("allowed_tags")),
+            "tags".array_contained_by(Column::from("allowed_tags")),
+            "tags".array_overlaps(Column::from("allowed_tags")),
+            DatabaseValue::from("cat").equals_any(Column::from("tags")),
+        ];
+
+        for comparison in comparisons {
+            let query = Select::from_table("posts").so_that(comparison);
+
+            assert_unsupported(connection.query(query.into()).await.unwrap_err(), "Array comparisons");
+        }
+    }
+
+    #[tokio::test]
+    async fn distinct_on_should_return_an_unsupported_feature_error() {
+        let url = MysqlUrl::new(Url::parse("mysql://root@localhost/test").unwrap()).unwrap();
+        let conn = Mysql::new(url).unwrap();
+
+        let query = Select::from_table("posts").distinct_on(vec!["user_id"]);
+        let err = conn.select(query).await.unwrap_err();
+
+        assert_unsupported(err, "DISTINCT ON");
+    }
+
+    #[tokio::test]
+    async fn should_emulate_returning_for_inserts() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        connection
+            .raw_cmd("DROP TABLE IF EXISTS returning_users")
+            .await
+            .unwrap();
+        connection
+            .raw_cmd("CREATE TABLE returning_users (id int auto_increment primary key, name varchar(255))")
+            .await
+            .unwrap();
+
+        let insert = Insert::single_into("returning_users").value("name", "Musti").build();
+        let res = connection.insert(insert.returning(vec!["id", "name"])).await.unwrap();
+        let row = res.get(0).unwrap();
+
+        assert_eq!(1, res.len());
+        assert_eq!(Some(1), row["id"].as_i64());
+        assert_eq!(Some("Musti"), row["name"].as_str());
+        assert_eq!(Some(1), res.last_insert_id());
+
+        let insert = Insert::multi_into("returning_users", vec!["name"])
+            .values(vec!["Naukio"])
+            .values(vec!["Belka"])
+            .build();
+
+        let res = connection.insert(insert.returning(vec!["id", "name"])).await.unwrap();
+        let rows: Vec<_> = res
+            .into_iter()
+            .map(|row| (row["id"].as_i64(), row["name"].to_string()))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (Some(2), Some(String::from("Naukio"))),
+                (Some(3), Some(String::from("Belka")))
+            ],
+            rows
+        );
+
+        let insert = Insert::multi_into("returning_users", vec!["id", "name"])
+            .values((10, "Pantteri"))
+            .values((20, "Kissa"))
+            .build();
+
+        let res = connection.insert(insert.returning(vec!["id"])).await.unwrap();
+        let ids: Vec<_> = res.into_iter().map(|row| row["id"].as_i64()).collect();
+
+        assert_eq!(vec![Some(10), Some(20)], ids);
+
+        let insert = Insert::multi_into("returning_users", vec!["id", "name"])
+            .values((DatabaseValue::from(ParameterizedValue::Null), "Musti"))
+            .values((0, "Naukio"))
+            .build();
+
+        let res = connection.insert(insert.returning(vec!["id"])).await.unwrap();
+        let ids: Vec<_> = res.into_iter().map(|row| row["id"].as_i64()).collect();
+
+        assert_eq!(vec![Some(21), Some(22)], ids);
+
+        let insert = Insert::multi_into("returning_users", vec!["id", "name"])
+            .values((30, "Musti"))
+            .values((0, "Naukio"))
+            .build();
+
+        let err = connection.insert(insert.returning(vec!["id"])).await.unwrap_err();
+
+        assert_unsupported(err, "RETURNING with given and generated keys");
+    }
+
     #[tokio::test]
     async fn should_provide_a_database_connection() {
         let connection = Quaint::new(&CONN_STR).await.unwrap();
@@ -354,6 +799,107 @@ mod tests {
         assert!(res.is_empty());
     }
 
+    #[tokio::test]
+    async fn should_stream_rows() {
+        let connection = Mysql::new(MysqlUrl::new(Url::parse(&CONN_STR).unwrap()).unwrap()).unwrap();
+
+        let stream = connection
+            .query_stream("SELECT 1 AS id UNION ALL SELECT 2 UNION ALL SELECT 3", &[])
+            .await
+            .unwrap();
+
+        assert_eq!(&vec!["id".to_string()], stream.columns());
+
+        let rows: Vec<_> = stream.try_collect().await.unwrap();
+        let ids: Vec<_> = rows.iter().map(|row| row["id"].as_i64().unwrap()).collect();
+
+        assert_eq!(vec![1, 2, 3], ids);
+    }
+
+    #[tokio::test]
+    async fn should_round_trip_blobs() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+        let data: &[u8] = &[0, 159, 146, 150, 255];
+
+        connection.raw_cmd("DROP TABLE IF EXISTS blobs").await.unwrap();
+        connection
+            .raw_cmd("CREATE TABLE blobs (data BLOB, name VARCHAR(255))")
+            .await
+            .unwrap();
+
+        connection
+            .insert(
+                Insert::single_into("blobs")
+                    .value("data", data)
+                    .value("name", "musti")
+                    .into(),
+            )
+            .await
+            .unwrap();
+
+        let res = connection.select(Select::from_table("blobs")).await.unwrap();
+        let row = res.get(0).unwrap();
+
+        assert_eq!(Some(data), row["data"].as_bytes());
+        assert_eq!(Some("musti"), row["name"].as_str());
+    }
+
+    #[tokio::test]
+    async fn unsigned_overflow_should_return_a_conversion_error() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        let err = connection
+            .query_raw("SELECT CAST(18446744073709551615 AS UNSIGNED) AS big", &[])
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    async fn binding_an_array_should_return_a_conversion_error() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+        let array = ParameterizedValue::Array(vec![ParameterizedValue::Integer(1)]);
+
+        let err = connection.query_raw("SELECT ?", &[array]).await.unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "chrono-0_4")]
+    async fn should_map_date_and_time_types() {
+        use chrono::{NaiveDate, NaiveTime};
+
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        let day = NaiveDate::from_ymd_opt(2020, 3, 31).unwrap();
+        let time = NaiveTime::from_hms_opt(13, 37, 0).unwrap();
+
+        connection.raw_cmd("DROP TABLE IF EXISTS events").await.unwrap();
+        connection
+            .raw_cmd("CREATE TABLE events (day DATE, time TIME)")
+            .await
+            .unwrap();
+
+        connection
+            .insert(
+                Insert::single_into("events")
+                    .value("day", day)
+                    .value("time", time)
+                    .into(),
+            )
+            .await
+            .unwrap();
+
+        let res = connection.select(Select::from_table("events")).await.unwrap();
+        let row = res.get(0).unwrap();
+
+        assert_eq!(Some(day), row["day"].as_date());
+        assert_eq!(Some(time), row["time"].as_time());
+    }
+
     const TABLE_DEF: &str = r#"
 CREATE TABLE `user`(
     id       int4    PRIMARY KEY     NOT NULL,
@@ -1,31 +1,65 @@
-use crate::{ast::ParameterizedValue, connector::queryable::TakeRow};
+use crate::{
+    ast::ParameterizedValue,
+    connector::queryable::TakeRow,
+    error::{Error, ErrorKind},
+};
 #[cfg(feature = "chrono-0_4")]
 use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
-use mysql_async as my;
-use mysql_async::Value as MyValue;
+use mysql_async::{self as my, consts::ColumnType, Value as MyValue};
 use rust_decimal::prelude::ToPrimitive;
+use std::convert::TryFrom;
 
-pub fn conv_params<'a>(params: &[ParameterizedValue<'a>]) -> my::Params {
+/// The character set MySQL reports for binary strings.
+const BINARY_CHARSET: u16 = 63;
+
+pub fn conv_params<'a>(params: &[ParameterizedValue<'a>]) -> crate::Result<my::Params> {
     if params.is_empty() {
         // If we don't use explicit 'Empty',
         // mysql crashes with 'internal error: entered unreachable code'
-        my::Params::Empty
+        Ok(my::Params::Empty)
     } else {
-        my::Params::Positional(params.iter().map(|x| x.into()).collect::<Vec<my::Value>>())
+        let values = params
+            .iter()
+            .map(|x| MyValue::try_from(x.clone()))
+            .collect::<crate::Result<Vec<my::Value>>>()?;
+
+        Ok(my::Params::Positional(values))
     }
 }
 
 impl TakeRow for my::Row {
     fn take_result_row<'b>(&'b mut self) -> crate::Result<Vec<ParameterizedValue<'static>>> {
         fn convert(row: &mut my::Row, i: usize) -> crate::Result<ParameterizedValue<'static>> {
+            let column = &row.columns_ref()[i];
+            let column_type = column.column_type();
+
+            let is_binary = column.character_set() == BINARY_CHARSET
+                && matches!(
+                    column_type,
+                    ColumnType::MYSQL_TYPE_TINY_BLOB
+                        | ColumnType::MYSQL_TYPE_MEDIUM_BLOB
+                        | ColumnType::MYSQL_TYPE_LONG_BLOB
+                        | ColumnType::MYSQL_TYPE_BLOB
+                        | ColumnType::MYSQL_TYPE_VARCHAR
+                        | ColumnType::MYSQL_TYPE_VAR_STRING
+                        | ColumnType::MYSQL_TYPE_STRING
+                );
+
             let res = match row.take(i).unwrap_or(my::Value::NULL) {
                 my::Value::NULL => ParameterizedValue::Null,
+                my::Value::Bytes(b) if is_binary => ParameterizedValue::Bytes(b.into()),
                 my::Value::Bytes(b) => ParameterizedValue::Text(String::from_utf8(b.to_vec())?.into()),
                 my::Value::Int(i) => ParameterizedValue::Integer(i),
-                // TOOD: This is unsafe
-                my::Value::UInt(i) => ParameterizedValue::Integer(i as i64),
+                my::Value::UInt(i) => ParameterizedValue::Integer(i64::try_from(i)?),
                 my::Value::Float(f) => ParameterizedValue::from(f),
                 #[cfg(feature = "chrono-0_4")]
+                my::Value::Date(year, month, day, _, _, _, _) if column_type == ColumnType::MYSQL_TYPE_DATE => {
+                    let date = NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+                        .ok_or_else(|| Error::builder(ErrorKind::ConversionError("Invalid MySQL date")).build())?;
+
+                    ParameterizedValue::Date(date)
+                }
+                #[cfg(feature = "chrono-0_4")]
                 my::Value::Date(year, month, day, hour, min, sec, micro) => {
                     let time = NaiveTime::from_hms_micro(hour as u32, min as u32, sec as u32, micro);
 
@@ -35,6 +69,15 @@ impl TakeRow for my::Row {
                     ParameterizedValue::DateTime(DateTime::<Utc>::from_utc(dt, Utc))
                 }
                 #[cfg(feature = "chrono-0_4")]
+                my::Value::Time(false, 0, hours, minutes, seconds, micros) => {
+                    let time = NaiveTime::from_hms_micro_opt(hours as u32, minutes as u32, seconds as u32, micros)
+                        .ok_or_else(|| Error::builder(ErrorKind::ConversionError("Invalid MySQL time")).build())?;
+
+                    ParameterizedValue::Time(time)
+                }
+                // A `TIME` column can hold a duration outside of a single day,
+                // these are returned as seconds.
+                #[cfg(feature = "chrono-0_4")]
                 my::Value::Time(is_neg, days, hours, minutes, seconds, micros) => {
                     let days = Duration::days(i64::from(days));
                     let hours = Duration::hours(i64::from(hours));
@@ -47,19 +90,25 @@ impl TakeRow for my::Row {
                         .and_then(|t| t.checked_add(&minutes))
                         .and_then(|t| t.checked_add(&seconds))
                         .and_then(|t| t.checked_add(&micros))
-                        .unwrap();
+                        .ok_or_else(|| Error::builder(ErrorKind::ConversionError("MySQL time overflow")).build())?;
 
-                    let duration = time.to_std().unwrap();
+                    let duration = time
+                        .to_std()
+                        .map_err(|_| Error::builder(ErrorKind::ConversionError("Invalid MySQL time")).build())?;
                     let f_time = duration.as_secs() as f64 + f64::from(duration.subsec_micros()) * 1e-6;
                     let f_time = if is_neg { -f_time } else { f_time };
 
                     ParameterizedValue::from(f_time)
                 }
                 #[cfg(not(feature = "chrono-0_4"))]
-                typ => panic!(
-                    "Value of type {:?} is not supported with the current configuration",
-                    typ
-                ),
+                _ => {
+                    let kind = ErrorKind::UnsupportedColumnType {
+                        column: row.columns_ref()[i].name_str().into_owned(),
+                        db_type: format!("{:?}", column_type),
+                    };
+
+                    return Err(Error::builder(kind).build());
+                }
             };
 
             Ok(res)
@@ -75,24 +124,38 @@ impl TakeRow for my::Row {
     }
 }
 
-impl<'a> From<ParameterizedValue<'a>> for MyValue {
-    fn from(pv: ParameterizedValue<'a>) -> MyValue {
-        match pv {
+impl<'a> TryFrom<ParameterizedValue<'a>> for MyValue {
+    type Error = Error;
+
+    fn try_from(pv: ParameterizedValue<'a>) -> crate::Result<MyValue> {
+        let value = match pv {
             ParameterizedValue::Null => MyValue::NULL,
             ParameterizedValue::Integer(i) => MyValue::Int(i),
-            ParameterizedValue::Real(f) => MyValue::Float(f.to_f64().expect("Decimal is not a f64.")),
+            ParameterizedValue::Real(f) => {
+                let f = f
+                    .to_f64()
+                    .ok_or_else(|| Error::builder(ErrorKind::ConversionError("Decimal is not a f64.")).build())?;
+
+                MyValue::Float(f)
+            }
             ParameterizedValue::Text(s) => MyValue::Bytes((&*s).as_bytes().to_vec()),
             ParameterizedValue::Enum(s) => MyValue::Bytes((&*s).as_bytes().to_vec()),
             ParameterizedValue::Boolean(b) => MyValue::Int(b as i64),
             ParameterizedValue::Char(c) => MyValue::Bytes(vec![c as u8]),
+            ParameterizedValue::Bytes(bytes) => MyValue::Bytes(bytes.into_owned()),
             #[cfg(feature = "json-1")]
             ParameterizedValue::Json(json) => {
-                let s = serde_json::to_string(&json).expect("Cannot convert JSON to String.");
+                let s = serde_json::to_string(&json).map_err(|_| {
+                    Error::builder(ErrorKind::ConversionError("Cannot convert JSON to String.")).build()
+                })?;
 
                 MyValue::Bytes(s.into_bytes())
             }
             #[cfg(feature = "array")]
-            ParameterizedValue::Array(_) => unimplemented!("Arrays are not supported for mysql."),
+            ParameterizedValue::Array(_) => {
+                let kind = ErrorKind::ConversionError("Arrays are not supported for MySQL.");
+                return Err(Error::builder(kind).build());
+            }
             #[cfg(feature = "uuid-0_8")]
             ParameterizedValue::Uuid(u) => MyValue::Bytes(u.to_hyphenated().to_string().into_bytes()),
             #[cfg(feature = "chrono-0_4")]
@@ -105,6 +168,26 @@ impl<'a> From<ParameterizedValue<'a>> for MyValue {
                 dt.second() as u8,
                 dt.timestamp_subsec_micros(),
             ),
-        }
+            // MySQL doesn't store the offset, the value is converted to UTC.
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::DateTimeTz(dt) => {
+                return MyValue::try_from(ParameterizedValue::DateTime(dt.with_timezone(&Utc)))
+            }
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Date(date) => {
+                MyValue::Date(date.year() as u16, date.month() as u8, date.day() as u8, 0, 0, 0, 0)
+            }
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Time(time) => MyValue::Time(
+                false,
+                0,
+                time.hour() as u8,
+                time.minute() as u8,
+                time.second() as u8,
+                time.nanosecond() / 1000,
+            ),
+        };
+
+        Ok(value)
     }
 }
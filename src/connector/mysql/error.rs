@@ -117,6 +117,19 @@ impl From<my::error::Error> for Error {
 
                 builder.build()
             }
+            my::error::Error::Server(ServerError { ref message, code, .. }) if code == 1213 || code == 1205 => {
+                let kind = match code {
+                    1213 => ErrorKind::Deadlock,
+                    _ => ErrorKind::LockTimeout("MySQL lock wait timeout exceeded".into()),
+                };
+
+                let mut builder = Error::builder(kind);
+
+                builder.set_original_code(format!("{}", code));
+                builder.set_original_message(message);
+
+                builder.build()
+            }
             my::error::Error::Server(ServerError {
                 ref message,
                 code,
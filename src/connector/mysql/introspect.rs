@@ -0,0 +1,143 @@
+use crate::{
+    ast::ParameterizedValue,
+    connector::{
+        introspect::{bool_value, string_value, ColumnDescription, SchemaBuilder, SchemaDescription},
+        Queryable,
+    },
+};
+
+const TABLES: &str = r#"
+    SELECT table_name
+    FROM information_schema.tables
+    WHERE table_schema = ? AND table_type = 'BASE TABLE'
+"#;
+
+const COLUMNS: &str = r#"
+    SELECT table_name, column_name, column_type, is_nullable, column_default, data_type
+    FROM information_schema.columns
+    WHERE table_schema = ?
+    ORDER BY table_name, ordinal_position
+"#;
+
+const INDEXES: &str = r#"
+    SELECT table_name, index_name, column_name, non_unique
+    FROM information_schema.statistics
+    WHERE table_schema = ?
+    ORDER BY table_name, index_name, seq_in_index
+"#;
+
+const FOREIGN_KEYS: &str = r#"
+    SELECT table_name, constraint_name, column_name, referenced_table_name, referenced_column_name
+    FROM information_schema.key_column_usage
+    WHERE table_schema = ? AND referenced_table_name IS NOT NULL
+    ORDER BY table_name, constraint_name, ordinal_position
+"#;
+
+/// Describes a database from `information_schema`.
+pub(crate) async fn describe<Q>(conn: &Q, schema: &str) -> crate::Result<SchemaDescription>
+where
+    Q: Queryable + ?Sized,
+{
+    let params = [ParameterizedValue::from(schema)];
+    let mut builder = SchemaBuilder::default();
+
+    for row in conn.query_raw(TABLES, &params).await? {
+        builder.add_table(string_value(&row[0]).unwrap_or_default());
+    }
+
+    let mut enums = Vec::new();
+
+    for row in conn.query_raw(COLUMNS, &params).await? {
+        let table = string_value(&row[0]).unwrap_or_default();
+        let name = string_value(&row[1]).unwrap_or_default();
+        let data_type = string_value(&row[2]).unwrap_or_default();
+
+        if string_value(&row[5])
+            .map(|t| t.eq_ignore_ascii_case("enum"))
+            .unwrap_or(false)
+        {
+            enums.push((format!("{}_{}", table, name), enum_values(&data_type)));
+        }
+
+        builder.table(&table).columns.push(ColumnDescription {
+            name,
+            data_type,
+            nullable: bool_value(&row[3]),
+            default: string_value(&row[4]),
+        });
+    }
+
+    for row in conn.query_raw(INDEXES, &params).await? {
+        let table = string_value(&row[0]).unwrap_or_default();
+        let index = string_value(&row[1]).unwrap_or_default();
+        let column = string_value(&row[2]).unwrap_or_default();
+
+        if index == "PRIMARY" {
+            builder.table(&table).primary_key.push(column);
+        } else {
+            builder.add_index_column(&table, index, column, !bool_value(&row[3]));
+        }
+    }
+
+    for row in conn.query_raw(FOREIGN_KEYS, &params).await? {
+        builder.add_foreign_key_column(
+            &string_value(&row[0]).unwrap_or_default(),
+            string_value(&row[1]),
+            string_value(&row[2]).unwrap_or_default(),
+            string_value(&row[3]).unwrap_or_default(),
+            string_value(&row[4]).unwrap_or_default(),
+        );
+    }
+
+    for (name, values) in enums {
+        for value in values {
+            builder.add_enum_value(name.clone(), value);
+        }
+    }
+
+    Ok(builder.build())
+}
+
+/// The values of a column type such as `enum('a','it''s')`.
+fn enum_values(column_type: &str) -> Vec<String> {
+    let start = column_type.find('(').map(|i| i + 1).unwrap_or(0);
+    let end = column_type.rfind(')').unwrap_or(column_type.len());
+    let list = &column_type[start..end];
+
+    let mut values = Vec::new();
+    let mut chars = list.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\'' {
+            continue;
+        }
+
+        let mut value = String::new();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' if chars.peek() == Some(&'\'') => {
+                    chars.next();
+                    value.push('\'');
+                }
+                '\'' => break,
+                '\\' => value.extend(chars.next()),
+                c => value.push(c),
+            }
+        }
+
+        values.push(value);
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_enum_values() {
+        assert_eq!(vec!["a", "it's", "b,c"], enum_values("enum('a','it''s','b,c')"));
+    }
+}
@@ -1,13 +1,20 @@
 mod conversion;
 mod error;
+pub(crate) mod introspect;
 
 use crate::{
-    ast::{ParameterizedValue, Query},
-    connector::{metrics, queryable::*, ResultSet, DBIO},
+    ast::{Column, OnConflict, ParameterizedValue, Query, Select, Table},
+    connector::{
+        metrics, queryable::*, BulkLoad, Introspect, PendingRollback, ResultSet, ResultStream, SqlFamily, DBIO,
+    },
     error::{Error, ErrorKind},
     visitor::{self, Visitor},
 };
-use futures::{future::FutureExt, lock::Mutex};
+use futures::{
+    future::FutureExt,
+    lock::{Mutex, MutexGuard},
+    stream::{Stream, StreamExt},
+};
 use native_tls::{Certificate, Identity, TlsConnector};
 use percent_encoding::percent_decode;
 use postgres_native_tls::MakeTlsConnector;
@@ -18,7 +25,11 @@ use std::{
     time::Duration,
 };
 use tokio::time::timeout;
-use tokio_postgres::{config::SslMode, Client, Config};
+use tokio_postgres::{
+    binary_copy::{BinaryCopyInWriter, BinaryCopyOutStream},
+    config::SslMode,
+    Client, Config,
+};
 use url::Url;
 
 pub(crate) const DEFAULT_SCHEMA: &str = "public";
@@ -44,6 +55,7 @@ impl std::fmt::Debug for PostgresClient {
 #[derive(Debug)]
 pub struct PostgreSql {
     client: PostgresClient,
+    pending_rollback: PendingRollback,
     socket_timeout: Option<Duration>,
 }
 
@@ -375,10 +387,119 @@ impl PostgreSql {
 
         Ok(Self {
             client: PostgresClient(Mutex::new(client)),
+            pending_rollback: PendingRollback::default(),
             socket_timeout: url.query_params.socket_timeout,
         })
     }
 
+    /// Loads the rows of the stream into the given columns of the table with
+    /// a binary `COPY FROM STDIN`, returning the number of loaded rows. The
+    /// values are encoded like query parameters, for the types of the table
+    /// columns.
+    ///
+    /// The connection is reserved while the stream is read, so the stream
+    /// must not query the same connection, or the load never finishes.
+    ///
+    /// ```no_run
+    /// # use quaint::{ast::*, connector::*};
+    /// # use futures::stream;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), quaint::error::Error> {
+    /// # let url = PostgresUrl::new(url::Url::parse("postgres://postgres@localhost/postgres").unwrap())?;
+    /// let conn = PostgreSql::new(url).await?;
+    /// let rows = stream::iter((0..100_000).map(|i| vec![ParameterizedValue::from(i), "Musti".into()]));
+    ///
+    /// assert_eq!(100_000, conn.copy_in("users", vec!["id", "name"], rows).await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn copy_in<'a, T, C, S>(&'a self, table: T, columns: Vec<C>, rows: S) -> DBIO<'a, u64>
+    where
+        T: Into<Table<'a>>,
+        C: Into<Column<'a>>,
+        S: Stream<Item = Vec<ParameterizedValue<'a>>> + Send + 'a,
+    {
+        let table: Table<'a> = table.into();
+        let columns: Vec<Column<'a>> = columns.into_iter().map(|c| c.into()).collect();
+
+        // Preparing a select of the columns tells us the types to encode.
+        let types_query = Select::from_table(table.clone()).columns(columns.clone());
+        let (types_sql, _) = visitor::Postgres::build(types_query);
+        let copy_sql = visitor::Postgres::build_copy_in(table, columns);
+
+        DBIO::new(async move {
+            metrics::query("postgres.copy_in", copy_sql.as_str(), &[], || async {
+                let client = self.lock_client().await?;
+                let types_stmt = self.timeout(client.prepare(types_sql.as_str())).await?;
+                let types: Vec<_> = types_stmt.columns().iter().map(|c| c.type_().clone()).collect();
+
+                let sink = self.timeout(client.copy_in(copy_sql.as_str())).await?;
+                let writer = BinaryCopyInWriter::new(sink, &types);
+                futures::pin_mut!(writer);
+
+                let mut rows = Box::pin(rows);
+
+                while let Some(row) = rows.next().await {
+                    let values = conversion::conv_params(&row);
+                    self.timeout(writer.as_mut().write(&values)).await?;
+                }
+
+                let changes = self.timeout(writer.finish()).await?;
+
+                Ok(changes)
+            })
+            .await
+        })
+    }
+
+    /// Streams the rows of the query with a binary `COPY TO STDOUT`. `COPY`
+    /// can't have parameters, so the values are written into the query as
+    /// literals.
+    ///
+    /// The connection stays reserved until the stream is dropped.
+    pub fn copy_out<'a>(&'a self, select: Select<'a>) -> DBIO<'a, ResultStream<'a>> {
+        let select_sql = visitor::Postgres::build_inlined(select);
+
+        DBIO::new(async move {
+            let copy_sql = format!("COPY ({}) TO STDOUT (FORMAT binary)", select_sql);
+
+            metrics::query("postgres.copy_out", copy_sql.as_str(), &[], || async {
+                let client = self.lock_client().await?;
+                let stmt = self.timeout(client.prepare(select_sql.as_str())).await?;
+                let types: Vec<_> = stmt.columns().iter().map(|c| c.type_().clone()).collect();
+
+                let stream = self.timeout(client.copy_out(copy_sql.as_str())).await?;
+                let column_names = stmt.to_column_names();
+
+                // The client stays locked until the stream is dropped.
+                let rows = BinaryCopyOutStream::new(stream, &types).map(move |row| {
+                    let _client = &client;
+
+                    conversion::CopyOutRow {
+                        row: row?,
+                        columns: stmt.columns(),
+                    }
+                    .get_result_row()
+                });
+
+                Ok(ResultStream::new(column_names, rows))
+            })
+            .await
+        })
+    }
+
+    /// Locks the client, first rolling back a transaction dropped since the
+    /// last query.
+    async fn lock_client(&self) -> crate::Result<MutexGuard<'_, Client>> {
+        let client = self.client.0.lock().await;
+
+        if let Some(rollback) = self.pending_rollback.take() {
+            self.timeout(client.simple_query(rollback.as_str())).await?;
+        }
+
+        Ok(client)
+    }
+
     async fn timeout<T, F, E>(&self, f: F) -> crate::Result<T>
     where
         F: Future<Output = std::result::Result<T, E>>,
@@ -398,22 +519,65 @@ impl PostgreSql {
     }
 }
 
+fn unsupported_feature(feature: &'static str) -> Error {
+    let kind = ErrorKind::UnsupportedFeature {
+        feature,
+        database: "PostgreSQL",
+    };
+
+    Error::builder(kind).build()
+}
+
+/// Rejects the queries PostgreSQL can't run.
+fn check_supported(q: &Query) -> crate::Result<()> {
+    if let Query::Insert(insert) = q {
+        if let Some(OnConflict::DoUpdate(update)) = &insert.on_conflict {
+            if update.columns.is_empty() {
+                return Err(unsupported_feature("ON CONFLICT DO UPDATE without assignments"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 impl TransactionCapable for PostgreSql {}
 
+impl Introspect for PostgreSql {}
+
+impl BulkLoad for PostgreSql {
+    fn bulk_load<'a, T, C, S>(&'a self, table: T, columns: Vec<C>, rows: S) -> DBIO<'a, u64>
+    where
+        T: Into<Table<'a>>,
+        C: Into<Column<'a>>,
+        S: Stream<Item = Vec<ParameterizedValue<'a>>> + Send + 'a,
+    {
+        self.copy_in(table, columns, rows)
+    }
+}
+
 impl Queryable for PostgreSql {
     fn query<'a>(&'a self, q: Query<'a>) -> DBIO<'a, ResultSet> {
-        let (sql, params) = visitor::Postgres::build(q);
-        DBIO::new(async move { self.query_raw(sql.as_str(), &params[..]).await })
+        DBIO::new(async move {
+            check_supported(&q)?;
+
+            let (sql, params) = visitor::Postgres::build(q);
+            self.query_raw(sql.as_str(), &params[..]).await
+        })
     }
 
     fn execute<'a>(&'a self, q: Query<'a>) -> DBIO<'a, u64> {
-        let (sql, params) = visitor::Postgres::build(q);
-        DBIO::new(async move { self.execute_raw(sql.as_str(), &params[..]).await })
+        DBIO::new(async move {
+            check_supported(&q)?;
+
+            let (sql, params) = visitor::Postgres::build(q);
+            self.execute_raw(sql.as_str(), &params[..]).await
+        })
     }
 
     fn query_raw<'a>(&'a self, sql: &'a str, params: &'a [ParameterizedValue<'a>]) -> DBIO<'a, ResultSet> {
         metrics::query("postgres.query_raw", sql, params, move || async move {
-            let client = self.client.0.lock().await;
+            let client = self.lock_client().await?;
             let stmt = self.timeout(client.prepare(sql)).await?;
 
             let rows = self
@@ -429,9 +593,30 @@ impl Queryable for PostgreSql {
         })
     }
 
+    fn query_stream<'a>(&'a self, sql: &'a str, params: &'a [ParameterizedValue<'a>]) -> DBIO<'a, ResultStream<'a>> {
+        metrics::query("postgres.query_stream", sql, params, move || async move {
+            let client = self.lock_client().await?;
+            let stmt = self.timeout(client.prepare(sql)).await?;
+
+            let params = conversion::conv_params(params)
+                .into_iter()
+                .map(|p| p as &dyn tokio_postgres::types::ToSql);
+
+            let rows = self.timeout(client.query_raw(&stmt, params)).await?;
+
+            // The client stays locked until the stream is dropped.
+            let rows = rows.map(move |row| {
+                let _client = &client;
+                row?.get_result_row()
+            });
+
+            Ok(ResultStream::new(stmt.to_column_names(), rows))
+        })
+    }
+
     fn execute_raw<'a>(&'a self, sql: &'a str, params: &'a [ParameterizedValue<'a>]) -> DBIO<'a, u64> {
         metrics::query("postgres.execute_raw", sql, params, move || async move {
-            let client = self.client.0.lock().await;
+            let client = self.lock_client().await?;
             let stmt = self.timeout(client.prepare(sql)).await?;
 
             let changes = self
@@ -444,18 +629,32 @@ impl Queryable for PostgreSql {
 
     fn raw_cmd<'a>(&'a self, cmd: &'a str) -> DBIO<'a, ()> {
         metrics::query("postgres.raw_cmd", cmd, &[], move || async move {
-            let client = self.client.0.lock().await;
+            let client = self.lock_client().await?;
             self.timeout(client.simple_query(cmd)).await?;
 
             Ok(())
         })
     }
+
+    fn sql_family(&self) -> SqlFamily {
+        SqlFamily::Postgres
+    }
+
+    fn transaction_dropped(&self, _depth: u32, rollback: &str) {
+        self.pending_rollback.set(rollback);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{connector::Queryable, error::*, single::Quaint};
+    use crate::{
+        ast::*,
+        connector::{EnumDescription, ForeignKeyDescription, IndexDescription, Queryable},
+        error::*,
+        single::Quaint,
+    };
+    use futures::stream::TryStreamExt;
     use lazy_static::lazy_static;
     use std::env;
     use url::Url;
@@ -464,6 +663,16 @@ mod tests {
         static ref CONN_STR: String = env::var("TEST_PSQL").expect("TEST_PSQL env var");
     }
 
+    fn assert_unsupported(err: Error, expected: &str) {
+        match err.kind() {
+            ErrorKind::UnsupportedFeature { feature, database } => {
+                assert_eq!(expected, *feature);
+                assert_eq!("PostgreSQL", *database);
+            }
+            e => panic!("Expected `UnsupportedFeature`, got {:?}", e),
+        }
+    }
+
     #[test]
     fn should_parse_socket_url() {
         let url = PostgresUrl::new(Url::parse("postgresql:///dbname?host=/var/run/psql.sock").unwrap()).unwrap();
@@ -498,6 +707,653 @@ mod tests {
         assert!(res.is_empty());
     }
 
+    #[tokio::test]
+    async fn should_stream_rows() {
+        let url = PostgresUrl::new(Url::parse(&CONN_STR).unwrap()).unwrap();
+        let connection = PostgreSql::new(url).await.unwrap();
+
+        let stream = connection
+            .query_stream("SELECT id FROM generate_series(1, 1000) AS id", &[])
+            .await
+            .unwrap();
+
+        assert_eq!(&vec!["id".to_string()], stream.columns());
+
+        let rows: Vec<_> = stream.try_collect().await.unwrap();
+
+        assert_eq!(1000, rows.len());
+        assert_eq!(Some(1000), rows[999]["id"].as_i64());
+    }
+
+    #[tokio::test]
+    async fn should_stream_rows_through_a_single_connection() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        let stream = connection
+            .query_stream("SELECT id FROM generate_series(1, 10) AS id", &[])
+            .await
+            .unwrap();
+
+        let rows: Vec<_> = stream.try_collect().await.unwrap();
+
+        assert_eq!(10, rows.len());
+        assert_eq!(Some(10), rows[9]["id"].as_i64());
+    }
+
+    #[tokio::test]
+    async fn should_round_trip_bytea() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+        let data: &[u8] = &[0, 159, 146, 150, 255];
+
+        connection.raw_cmd("DROP TABLE IF EXISTS blobs").await.unwrap();
+        connection.raw_cmd("CREATE TABLE blobs (data bytea)").await.unwrap();
+
+        connection
+            .insert(Insert::single_into("blobs").value("data", data).into())
+            .await
+            .unwrap();
+
+        let res = connection.select(Select::from_table("blobs")).await.unwrap();
+        let row = res.get(0).unwrap();
+
+        assert_eq!(Some(data), row["data"].as_bytes());
+    }
+
+    #[tokio::test]
+    async fn should_return_updated_and_deleted_rows() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        connection
+            .raw_cmd("DROP TABLE IF EXISTS returning_users")
+            .await
+            .unwrap();
+        connection
+            .raw_cmd("CREATE TABLE returning_users (id int primary key, name text)")
+            .await
+            .unwrap();
+
+        let insert = Insert::multi_into("returning_users", vec!["id", "name"])
+            .values((1, "Musti"))
+            .values((2, "Naukio"));
+
+        connection.insert(insert.into()).await.unwrap();
+
+        let update = Update::table("returning_users")
+            .set("name", "Belka")
+            .so_that("id".equals(1))
+            .returning(vec!["id", "name"]);
+
+        let res = connection.update_returning(update).await.unwrap();
+        let row = res.get(0).unwrap();
+
+        assert_eq!(1, res.len());
+        assert_eq!(Some(1), row["id"].as_i64());
+        assert_eq!(Some("Belka"), row["name"].as_str());
+
+        let delete = Delete::from_table("returning_users").returning(vec!["name"]);
+        let res = connection.delete_returning(delete).await.unwrap();
+        let mut names: Vec<_> = res.into_iter().map(|row| row["name"].to_string().unwrap()).collect();
+        names.sort();
+
+        assert_eq!(vec!["Belka", "Naukio"], names);
+    }
+
+    #[tokio::test]
+    async fn should_compute_functions() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        connection.raw_cmd("DROP TABLE IF EXISTS function_users").await.unwrap();
+        connection
+            .raw_cmd("CREATE TABLE function_users (name text, code text)")
+            .await
+            .unwrap();
+
+        let insert = Insert::single_into("function_users")
+            .value("name", "Musti")
+            .value("code", "42");
+
+        connection.insert(insert.into()).await.unwrap();
+
+        let query = Select::from_table("function_users")
+            .value(Function::from(length(Column::new("name"))).alias("length"))
+            .value(Function::from(concat(vec![Column::new("name"), Column::new("code")])).alias("concat"))
+            .value(Function::from(cast(Column::new("code"), CastType::Int)).alias("int"))
+            .value(Function::from(now()).alias("now"));
+
+        let row = connection.select(query).await.unwrap().into_single().unwrap();
+
+        assert_eq!(Some(5), row["length"].as_i64());
+        assert_eq!(Some("Musti42"), row["concat"].as_str());
+        assert_eq!(Some(42), row["int"].as_i64());
+
+        #[cfg(feature = "chrono-0_4")]
+        assert!(row["now"].as_datetime().is_some());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "json-1")]
+    async fn should_query_jsonb_documents() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        connection.raw_cmd("DROP TABLE IF EXISTS json_documents").await.unwrap();
+        connection
+            .raw_cmd("CREATE TABLE json_documents (id int, data jsonb)")
+            .await
+            .unwrap();
+
+        let insert = Insert::multi_into("json_documents", vec!["id", "data"])
+            .values((
+                1,
+                serde_json::json!({ "name": "Musti", "tags": ["cat", "black"], "owner": { "name": "Julius" } }),
+            ))
+            .values((
+                2,
+                serde_json::json!({ "name": "Naukio", "tags": ["cat"], "email": "naukio@example.com" }),
+            ));
+
+        connection.insert(insert.into()).await.unwrap();
+
+        let query = Select::from_table("json_documents")
+            .column("data")
+            .value(Column::from("data").json_get_text("name"))
+            .value(Column::from("data").json_get_path(vec!["owner", "name"]))
+            .value(Column::from("data").json_get("tags").json_get(1))
+            .so_that("data".json_contains(serde_json::json!({ "tags": ["black"] })));
+
+        let row = connection.select(query).await.unwrap().into_single().unwrap();
+
+        assert_eq!(Some("Musti"), row[1].as_str());
+        assert_eq!(Some(&serde_json::json!("Julius")), row[2].as_json());
+        assert_eq!(Some(&serde_json::json!("black")), row[3].as_json());
+        assert_eq!(
+            Some(&serde_json::json!(["cat", "black"])),
+            row["data"].as_json().map(|data| &data["tags"])
+        );
+
+        let ids = |condition: Compare<'static>| {
+            let query = Select::from_table("json_documents")
+                .column("id")
+                .so_that(condition)
+                .order_by("id");
+            let connection = &connection;
+
+            async move {
+                let rows = connection.select(query).await.unwrap();
+                rows.into_iter()
+                    .map(|row| row["id"].as_i64().unwrap())
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(vec![2], ids("data".json_has_key("email")).await);
+        assert_eq!(vec![1, 2], ids("data".json_has_any_key(vec!["email", "owner"])).await);
+        assert_eq!(
+            vec![2],
+            ids(Column::from("data").json_get_text("name").equals("Naukio")).await
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "uuid-0_8", feature = "json-1"))]
+    async fn should_query_arrays() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        connection
+            .raw_cmd("DROP TABLE IF EXISTS array_documents")
+            .await
+            .unwrap();
+        connection
+            .raw_cmd("CREATE TABLE array_documents (id int, tags text[], refs uuid[], docs jsonb[])")
+            .await
+            .unwrap();
+
+        let reference = uuid::Uuid::parse_str("0e9f6b3c-7bd4-4b7e-9a5f-1c3e6f0a2d4b").unwrap();
+        let doc = serde_json::json!({ "name": "Musti" });
+
+        let insert = Insert::multi_into("array_documents", vec!["id", "tags", "refs", "docs"])
+            .values((
+                1,
+                ParameterizedValue::array(vec!["cat", "black"]),
+                ParameterizedValue::array(vec![reference]),
+                ParameterizedValue::array(vec![doc.clone()]),
+            ))
+            .values((
+                2,
+                ParameterizedValue::array(vec!["cat"]),
+                ParameterizedValue::array(Vec::<uuid::Uuid>::new()),
+                ParameterizedValue::array(Vec::<serde_json::Value>::new()),
+            ));
+
+        connection.insert(insert.into()).await.unwrap();
+
+        let query = Select::from_table("array_documents")
+            .columns(vec!["refs", "docs"])
+            .value(array_length(Column::from("tags")))
+            .so_that("id".equals(1));
+
+        let row = connection.select(query).await.unwrap().into_single().unwrap();
+
+        assert_eq!(ParameterizedValue::array(vec![reference]), row["refs"]);
+        assert_eq!(ParameterizedValue::array(vec![doc]), row["docs"]);
+        assert_eq!(Some(2), row[2].as_i64());
+
+        let ids = |condition: Compare<'static>| {
+            let query = Select::from_table("array_documents")
+                .column("id")
+                .so_that(condition)
+                .order_by("id");
+            let connection = &connection;
+
+            async move {
+                let rows = connection.select(query).await.unwrap();
+                rows.into_iter()
+                    .map(|row| row["id"].as_i64().unwrap())
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        let cat = || ParameterizedValue::array(vec!["cat"]);
+
+        assert_eq!(vec![1, 2], ids("tags".array_contains(cat())).await);
+        assert_eq!(vec![2], ids("tags".array_contained_by(cat())).await);
+        assert_eq!(
+            vec![1],
+            ids("tags".array_overlaps(ParameterizedValue::array(vec!["black"]))).await
+        );
+        assert_eq!(
+            vec![1],
+            ids(DatabaseValue::from(reference).equals_any(Column::from("refs"))).await
+        );
+        assert_eq!(
+            vec![1, 2],
+            ids("id".in_selection(ParameterizedValue::array(vec![1, 2, 3]))).await
+        );
+        assert_eq!(
+            vec![2],
+            ids("id".not_in_selection(ParameterizedValue::array(vec![1]))).await
+        );
+    }
+
+    #[tokio::test]
+    async fn should_respect_the_parameter_limit() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        connection
+            .raw_cmd("DROP TABLE IF EXISTS batched_numbers")
+            .await
+            .unwrap();
+        connection
+            .raw_cmd("CREATE TABLE batched_numbers (a int, b int)")
+            .await
+            .unwrap();
+
+        let insert = (0..20000).fold(Insert::multi_into("batched_numbers", vec!["a", "b"]), |insert, i| {
+            insert.values((i, i * 2))
+        });
+
+        assert_eq!(20000, connection.insert_batched(insert).await.unwrap());
+
+        let ids: Vec<i64> = (0..40000).collect();
+
+        let query = Select::from_table("batched_numbers")
+            .value(count(asterisk()))
+            .so_that("a".in_selection(ids.clone()).and("b".not_in_selection(ids)));
+
+        let row = connection.select(query).await.unwrap().into_single().unwrap();
+
+        assert_eq!(Some(0), row[0].as_i64());
+
+        let query = Select::from_table("batched_numbers")
+            .value(count(asterisk()))
+            .so_that("b".in_selection((0..40000).collect::<Vec<i64>>()));
+
+        let row = connection.select(query).await.unwrap().into_single().unwrap();
+
+        assert_eq!(Some(20000), row[0].as_i64());
+    }
+
+    #[tokio::test]
+    async fn should_copy_rows_in_and_out() {
+        let url = PostgresUrl::new(Url::parse(&CONN_STR).unwrap()).unwrap();
+        let connection = PostgreSql::new(url).await.unwrap();
+
+        connection.raw_cmd("DROP TABLE IF EXISTS copied_users").await.unwrap();
+        connection
+            .raw_cmd("CREATE TABLE copied_users (id int, name text, score numeric, active bool)")
+            .await
+            .unwrap();
+
+        let rows = futures::stream::iter((0..10000).map(|i| {
+            vec![
+                ParameterizedValue::from(i),
+                format!("user {}", i).into(),
+                (f64::from(i) / 2.0).into(),
+                (i % 2 == 0).into(),
+            ]
+        }));
+
+        let loaded = connection
+            .copy_in("copied_users", vec!["id", "name", "score", "active"], rows)
+            .await
+            .unwrap();
+
+        assert_eq!(10000, loaded);
+
+        let rows = futures::stream::iter(vec![vec![ParameterizedValue::from(10000), ParameterizedValue::Null]]);
+        let loaded = connection
+            .bulk_load("copied_users", vec!["id", "name"], rows)
+            .await
+            .unwrap();
+
+        assert_eq!(1, loaded);
+
+        let query = Select::from_table("copied_users")
+            .columns(vec!["id", "name", "score", "active"])
+            .order_by("id");
+
+        let stream = connection.copy_out(query).await.unwrap();
+        assert_eq!(&vec!["id", "name", "score", "active"], stream.columns());
+
+        let rows: Vec<_> = stream.try_collect().await.unwrap();
+
+        assert_eq!(10001, rows.len());
+        assert_eq!(Some(3), rows[3]["id"].as_i64());
+        assert_eq!(Some("user 3"), rows[3]["name"].as_str());
+        assert_eq!(Some(1.5), rows[3]["score"].as_f64());
+        assert_eq!(Some(false), rows[3]["active"].as_bool());
+        assert!(rows[10000]["name"].is_null());
+
+        let with_values = Select::from_table("copied_users")
+            .column("name")
+            .so_that("id".equals(3).or("name".equals("user's 4")));
+
+        let rows: Vec<_> = connection
+            .copy_out(with_values)
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(1, rows.len());
+        assert_eq!(Some("user 3"), rows[0]["name"].as_str());
+    }
+
+    #[tokio::test]
+    async fn should_change_the_schema() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        connection
+            .raw_cmd("DROP SCHEMA IF EXISTS quaint_ddl CASCADE; CREATE SCHEMA quaint_ddl;")
+            .await
+            .unwrap();
+
+        let authors = CreateTable::new(Table::from("authors").database("quaint_ddl"))
+            .column(
+                ColumnDefinition::new("id", ColumnType::Integer)
+                    .primary_key()
+                    .auto_increment(),
+            )
+            .column(
+                ColumnDefinition::new("name", ColumnType::Text)
+                    .not_null()
+                    .default("it's Musti"),
+            )
+            .column(
+                ColumnDefinition::new("data", ColumnType::Bytes).default(ParameterizedValue::Bytes(vec![1, 2].into())),
+            )
+            .column(ColumnDefinition::new("created_at", ColumnType::DateTime).default_expression("now()"));
+
+        let books = CreateTable::new(Table::from("books").database("quaint_ddl"))
+            .column(ColumnDefinition::new("author_id", ColumnType::Integer).not_null())
+            .column(ColumnDefinition::new("title", ColumnType::Varchar(255)).not_null())
+            .primary_key(vec!["author_id", "title"])
+            .foreign_key(
+                ForeignKey::new(
+                    vec!["author_id"],
+                    Table::from("authors").database("quaint_ddl"),
+                    vec!["id"],
+                )
+                .on_delete(ReferentialAction::Cascade),
+            );
+
+        connection.execute(authors.into()).await.unwrap();
+        connection.execute(books.into()).await.unwrap();
+
+        let add = AlterTable::add_column(
+            Table::from("authors").database("quaint_ddl"),
+            ColumnDefinition::new("active", ColumnType::Boolean)
+                .not_null()
+                .default(true),
+        );
+        connection.execute(add.into()).await.unwrap();
+
+        let index = CreateIndex::new(
+            "authors_name_key",
+            Table::from("authors").database("quaint_ddl"),
+            vec!["name"],
+        )
+        .unique()
+        .so_that("active".equals(true));
+        connection.execute(index.into()).await.unwrap();
+
+        let insert = Insert::single_into(Table::from("authors").database("quaint_ddl")).build();
+        connection.execute(insert.into()).await.unwrap();
+
+        let row = connection
+            .query(Select::from_table(Table::from("authors").database("quaint_ddl")).into())
+            .await
+            .unwrap()
+            .into_single()
+            .unwrap();
+
+        assert_eq!(Some(1), row["id"].as_i64());
+        assert_eq!(Some("it's Musti"), row["name"].as_str());
+        assert_eq!(ParameterizedValue::Bytes(vec![1, 2].into()), row["data"]);
+        assert_eq!(Some(true), row["active"].as_bool());
+
+        let schema = connection.describe_schema("quaint_ddl").await.unwrap();
+        let books = schema.table("books").unwrap();
+
+        assert_eq!(vec!["author_id", "title"], books.primary_key);
+        assert_eq!("character varying(255)", books.column("title").unwrap().data_type);
+        assert_eq!("authors", books.foreign_keys[0].referenced_table);
+
+        let authors = schema.table("authors").unwrap();
+
+        assert!(authors
+            .indexes
+            .iter()
+            .any(|index| index.name == "authors_name_key" && index.unique));
+
+        let drop_column = AlterTable::drop_column(Table::from("authors").database("quaint_ddl"), "active");
+        connection.execute(drop_column.into()).await.unwrap();
+
+        let drop_index = DropIndex::new("authors_name_key", Table::from("authors").database("quaint_ddl")).if_exists();
+        connection.execute(drop_index.into()).await.unwrap();
+
+        let drop_table = DropTable::new(Table::from("books").database("quaint_ddl"));
+        connection.execute(drop_table.into()).await.unwrap();
+
+        let schema = connection.describe_schema("quaint_ddl").await.unwrap();
+        let authors = schema.table("authors").unwrap();
+
+        assert!(schema.table("books").is_none());
+        assert!(authors.column("active").is_none());
+        assert!(authors.indexes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_describe_the_schema() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        connection
+            .raw_cmd(
+                "DROP SCHEMA IF EXISTS quaint_introspection CASCADE; \
+                 CREATE SCHEMA quaint_introspection; \
+                 CREATE TYPE quaint_introspection.mood AS ENUM ('happy', 'sleepy'); \
+                 CREATE TABLE quaint_introspection.owners (id serial PRIMARY KEY, email varchar(255) NOT NULL UNIQUE); \
+                 CREATE TABLE quaint_introspection.cats ( \
+                     owner_id int NOT NULL REFERENCES quaint_introspection.owners (id), \
+                     number int NOT NULL, \
+                     mood quaint_introspection.mood DEFAULT 'happy', \
+                     tags text[], \
+                     PRIMARY KEY (owner_id, number)); \
+                 CREATE INDEX cats_mood ON quaint_introspection.cats (mood, number);",
+            )
+            .await
+            .unwrap();
+
+        let schema = connection.describe_schema("quaint_introspection").await.unwrap();
+
+        assert_eq!(
+            vec![EnumDescription {
+                name: "mood".into(),
+                values: vec!["happy".into(), "sleepy".into()],
+            }],
+            schema.enums
+        );
+
+        let cats = schema.table("cats").unwrap();
+
+        assert_eq!(vec!["owner_id", "number"], cats.primary_key);
+        assert_eq!("text[]", cats.column("tags").unwrap().data_type);
+
+        let mood = cats.column("mood").unwrap();
+        assert!(mood.nullable);
+        assert_eq!(Some("'happy'::quaint_introspection.mood"), mood.default.as_deref());
+
+        assert_eq!(
+            vec![IndexDescription {
+                name: "cats_mood".into(),
+                columns: vec!["mood".into(), "number".into()],
+                unique: false,
+            }],
+            cats.indexes
+        );
+
+        assert_eq!(
+            vec![ForeignKeyDescription {
+                name: Some("cats_owner_id_fkey".into()),
+                columns: vec!["owner_id".into()],
+                referenced_table: "owners".into(),
+                referenced_columns: vec!["id".into()],
+            }],
+            cats.foreign_keys
+        );
+
+        let owners = schema.table("owners").unwrap();
+        let email = owners.column("email").unwrap();
+
+        assert_eq!("character varying(255)", email.data_type);
+        assert!(!email.nullable);
+        assert!(owners.column("id").unwrap().default.is_some());
+        assert_eq!(
+            vec!["owners_email_key"],
+            owners.indexes.iter().map(|i| &i.name).collect::<Vec<_>>()
+        );
+        assert!(owners.indexes[0].unique);
+
+        let public = connection.describe().await.unwrap();
+        assert!(public.table("cats").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_match_patterns() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        connection
+            .raw_cmd("DROP TABLE IF EXISTS pattern_products")
+            .await
+            .unwrap();
+        connection
+            .raw_cmd("CREATE TABLE pattern_products (id int, name text)")
+            .await
+            .unwrap();
+
+        let insert = Insert::multi_into("pattern_products", vec!["id", "name"])
+            .values((1, "50% OFF Shoes"))
+            .values((2, "500 shoes"))
+            .values((3, "Hats_2020"));
+
+        connection.insert(insert.into()).await.unwrap();
+
+        let ids = |condition: Compare<'static>| {
+            let query = Select::from_table("pattern_products")
+                .column("id")
+                .so_that(condition)
+                .order_by("id");
+
+            let connection = &connection;
+
+            async move {
+                let rows = connection.select(query).await.unwrap();
+                rows.into_iter()
+                    .map(|row| row["id"].as_i64().unwrap())
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(vec![1], ids("name".like("50%")).await);
+        assert_eq!(vec![3], ids("name".ends_into("_2020")).await);
+        assert_eq!(vec![1, 2], ids("name".ilike("SHOES")).await);
+        assert_eq!(vec![3], ids("name".not_ilike("shoes")).await);
+        assert_eq!(vec![2, 3], ids("name".matches_regex("^[0-9]+ |_[0-9]+$")).await);
+    }
+
+    #[tokio::test]
+    async fn unsupported_column_types_should_return_an_error() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        let err = connection
+            .query_raw("SELECT '(1,2)'::point AS location", &[])
+            .await
+            .unwrap_err();
+
+        match err.kind() {
+            ErrorKind::UnsupportedColumnType { column, db_type } => {
+                assert_eq!("location", column);
+                assert_eq!("point", db_type);
+            }
+            e => panic!("Expected `UnsupportedColumnType`, got {:?}", e),
+        }
+
+        let err = connection
+            .query_raw("SELECT 'NaN'::float8 AS number", &[])
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "chrono-0_4")]
+    async fn should_map_date_and_time_types() {
+        use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        let day = NaiveDate::from_ymd_opt(2020, 3, 31).unwrap();
+        let time = NaiveTime::from_hms_micro_opt(13, 37, 0, 500).unwrap();
+        let at = DateTime::parse_from_rfc3339("2020-03-31T15:37:00+02:00").unwrap();
+
+        let res = connection
+            .query_raw(
+                "SELECT $1::date AS day, $2::time AS time, $3::timestamptz AS at, \
+                 INTERVAL '1 year 2 months 3 days 04:05:06.5' AS span",
+                &[day.into(), time.into(), at.into()],
+            )
+            .await
+            .unwrap();
+
+        let row = res.get(0).unwrap();
+
+        assert_eq!(Some(day), row["day"].as_date());
+        assert_eq!(Some(time), row["time"].as_time());
+        assert_eq!(Some(at.with_timezone(&Utc)), row["at"].as_datetime());
+        assert_eq!(Some("P1Y2M3DT4H5M6.5S"), row["span"].as_str());
+    }
+
     #[allow(unused)]
     const TABLE_DEF: &str = r#"
     CREATE TABLE "user"(
@@ -624,6 +1480,25 @@ mod tests {
         assert_eq!(Some("\"musti-test\""), row[0].as_str());
     }
 
+    #[tokio::test]
+    async fn should_roll_back_a_dropped_transaction_before_the_next_query() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        connection.raw_cmd("DROP TABLE IF EXISTS dropped_tx").await.unwrap();
+        connection.raw_cmd("CREATE TABLE dropped_tx (id int)").await.unwrap();
+
+        {
+            let tx = connection.start_transaction().await.unwrap();
+            tx.raw_cmd("INSERT INTO dropped_tx VALUES (1)").await.unwrap();
+        }
+
+        let res = connection.select(Select::from_table("dropped_tx")).await.unwrap();
+        assert!(res.is_empty());
+
+        let tx = connection.start_transaction().await.unwrap();
+        tx.commit().await.unwrap();
+    }
+
     #[tokio::test]
     async fn should_map_nonexisting_database_error() {
         let mut url = Url::parse(&CONN_STR).unwrap();
@@ -732,4 +1607,18 @@ mod tests {
             other => panic!("{:?}", other),
         }
     }
+
+    #[tokio::test]
+    async fn upsert_without_assignments_should_return_an_unsupported_feature_error() {
+        let connection = Quaint::new(&CONN_STR).await.unwrap();
+
+        let query = Insert::single_into("users").value("id", 1);
+        let update = OnConflict::DoUpdate(ConflictUpdate::columns(vec!["id"]));
+        let err = connection
+            .insert(Insert::from(query).on_conflict(update))
+            .await
+            .unwrap_err();
+
+        assert_unsupported(err, "ON CONFLICT DO UPDATE without assignments");
+    }
 }
@@ -1,15 +1,17 @@
 use crate::{
     ast::ParameterizedValue,
     connector::queryable::{GetRow, ToColumnNames},
+    error::{self, ErrorKind},
 };
 use bytes::BytesMut;
 #[cfg(feature = "chrono-0_4")]
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use rust_decimal::{prelude::FromPrimitive, Decimal};
-use std::{error::Error, str::FromStr};
+use std::{convert::TryInto, error::Error, fmt, str::FromStr};
 use tokio_postgres::{
+    binary_copy::BinaryCopyOutRow,
     types::{self, FromSql, IsNull, Kind, ToSql, Type as PostgresType},
-    Row as PostgresRow, Statement as PostgresStatement,
+    Column, Row as PostgresRow, Statement as PostgresStatement,
 };
 
 #[cfg(feature = "uuid-0_8")]
@@ -26,7 +28,7 @@ struct EnumString {
 impl<'a> FromSql<'a> for EnumString {
     fn from_sql(_ty: &PostgresType, raw: &'a [u8]) -> Result<EnumString, Box<dyn std::error::Error + Sync + Send>> {
         Ok(EnumString {
-            value: String::from_utf8(raw.to_owned()).unwrap().into(),
+            value: String::from_utf8(raw.to_owned())?,
         })
     }
 
@@ -35,243 +37,435 @@ impl<'a> FromSql<'a> for EnumString {
     }
 }
 
+/// A PostgreSQL `INTERVAL`, returned as an ISO-8601 duration such as
+/// `P1Y2M3DT4H5M6.5S`.
+struct Interval {
+    microseconds: i64,
+    days: i32,
+    months: i32,
+}
+
+impl<'a> FromSql<'a> for Interval {
+    fn from_sql(_ty: &PostgresType, raw: &'a [u8]) -> Result<Interval, Box<dyn std::error::Error + Sync + Send>> {
+        if raw.len() != 16 {
+            return Err("invalid message length: interval size mismatch".into());
+        }
+
+        Ok(Interval {
+            microseconds: i64::from_be_bytes(raw[0..8].try_into()?),
+            days: i32::from_be_bytes(raw[8..12].try_into()?),
+            months: i32::from_be_bytes(raw[12..16].try_into()?),
+        })
+    }
+
+    fn accepts(ty: &PostgresType) -> bool {
+        *ty == PostgresType::INTERVAL
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.microseconds == 0 && self.days == 0 && self.months == 0 {
+            return write!(f, "PT0S");
+        }
+
+        write!(f, "P")?;
+
+        let (years, months) = (self.months / 12, self.months % 12);
+
+        if years != 0 {
+            write!(f, "{}Y", years)?;
+        }
+
+        if months != 0 {
+            write!(f, "{}M", months)?;
+        }
+
+        if self.days != 0 {
+            write!(f, "{}D", self.days)?;
+        }
+
+        if self.microseconds != 0 {
+            let hours = self.microseconds / 3_600_000_000;
+            let minutes = self.microseconds % 3_600_000_000 / 60_000_000;
+            let seconds = self.microseconds % 60_000_000;
+
+            write!(f, "T")?;
+
+            if hours != 0 {
+                write!(f, "{}H", hours)?;
+            }
+
+            if minutes != 0 {
+                write!(f, "{}M", minutes)?;
+            }
+
+            if seconds != 0 {
+                write!(f, "{}S", seconds as f64 / 1_000_000.0)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The values of a row read from the database, either as a query result or
+/// from a binary `COPY`.
+trait PostgresValues {
+    fn columns(&self) -> &[Column];
+
+    fn try_get<'a, T>(&'a self, idx: usize) -> Result<T, tokio_postgres::Error>
+    where
+        T: FromSql<'a>;
+}
+
+impl PostgresValues for PostgresRow {
+    fn columns(&self) -> &[Column] {
+        PostgresRow::columns(self)
+    }
+
+    fn try_get<'a, T>(&'a self, idx: usize) -> Result<T, tokio_postgres::Error>
+    where
+        T: FromSql<'a>,
+    {
+        PostgresRow::try_get(self, idx)
+    }
+}
+
+/// A row of a binary `COPY TO STDOUT` with the columns of the copied query.
+pub(crate) struct CopyOutRow<'a> {
+    pub(crate) row: BinaryCopyOutRow,
+    pub(crate) columns: &'a [Column],
+}
+
+impl<'b> PostgresValues for CopyOutRow<'b> {
+    fn columns(&self) -> &[Column] {
+        self.columns
+    }
+
+    fn try_get<'a, T>(&'a self, idx: usize) -> Result<T, tokio_postgres::Error>
+    where
+        T: FromSql<'a>,
+    {
+        self.row.try_get(idx)
+    }
+}
+
 impl GetRow for PostgresRow {
-    fn get_result_row<'b>(&'b self) -> crate::Result<Vec<ParameterizedValue<'static>>> {
-        fn convert(row: &PostgresRow, i: usize) -> crate::Result<ParameterizedValue<'static>> {
-            let result = match *row.columns()[i].type_() {
-                PostgresType::BOOL => match row.try_get(i)? {
-                    Some(val) => ParameterizedValue::Boolean(val),
-                    None => ParameterizedValue::Null,
-                },
-                PostgresType::INT2 => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: i16 = val;
-                        ParameterizedValue::Integer(i64::from(val))
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                PostgresType::INT4 => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: i32 = val;
-                        ParameterizedValue::Integer(i64::from(val))
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                PostgresType::INT8 => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: i64 = val;
-                        ParameterizedValue::Integer(val)
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                PostgresType::NUMERIC => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: Decimal = val;
-                        ParameterizedValue::Real(val)
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                PostgresType::FLOAT4 => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: Decimal = Decimal::from_f32(val).expect("f32 is not a Decimal");
-                        ParameterizedValue::Real(val)
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                PostgresType::FLOAT8 => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: Decimal = Decimal::from_f64(val).expect("f64 is not a Decimal");
-                        ParameterizedValue::Real(val)
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                #[cfg(feature = "chrono-0_4")]
-                PostgresType::TIMESTAMP => match row.try_get(i)? {
-                    Some(val) => {
-                        let ts: NaiveDateTime = val;
-                        let dt = DateTime::<Utc>::from_utc(ts, Utc);
-                        ParameterizedValue::DateTime(dt)
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                #[cfg(feature = "uuid-0_8")]
-                PostgresType::UUID => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: Uuid = val;
-                        ParameterizedValue::Uuid(val)
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                #[cfg(feature = "json-1")]
-                PostgresType::JSON => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: serde_json::Value = val;
-                        ParameterizedValue::Json(val)
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                #[cfg(feature = "array")]
-                PostgresType::INT2_ARRAY => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: Vec<i16> = val;
-                        ParameterizedValue::Array(
-                            val.into_iter()
-                                .map(|x| ParameterizedValue::Integer(i64::from(x)))
-                                .collect(),
-                        )
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                #[cfg(feature = "array")]
-                PostgresType::INT4_ARRAY => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: Vec<i32> = val;
-                        ParameterizedValue::Array(
-                            val.into_iter()
-                                .map(|x| ParameterizedValue::Integer(i64::from(x)))
-                                .collect(),
-                        )
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                #[cfg(feature = "array")]
-                PostgresType::INT8_ARRAY => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: Vec<i64> = val;
-                        ParameterizedValue::Array(
-                            val.into_iter().map(|x| ParameterizedValue::Integer(x as i64)).collect(),
-                        )
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                #[cfg(feature = "array")]
-                PostgresType::FLOAT4_ARRAY => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: Vec<f32> = val;
-                        ParameterizedValue::Array(val.into_iter().map(ParameterizedValue::from).collect())
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                #[cfg(feature = "array")]
-                PostgresType::FLOAT8_ARRAY => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: Vec<f64> = val;
-                        ParameterizedValue::Array(val.into_iter().map(ParameterizedValue::from).collect())
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                #[cfg(feature = "array")]
-                PostgresType::BOOL_ARRAY => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: Vec<bool> = val;
-                        ParameterizedValue::Array(val.into_iter().map(ParameterizedValue::Boolean).collect())
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                #[cfg(all(feature = "array", feature = "chrono-0_4"))]
-                PostgresType::TIMESTAMP_ARRAY => match row.try_get(i)? {
+    fn get_result_row(&self) -> crate::Result<Vec<ParameterizedValue<'static>>> {
+        result_row(self)
+    }
+}
+
+impl<'b> GetRow for CopyOutRow<'b> {
+    fn get_result_row(&self) -> crate::Result<Vec<ParameterizedValue<'static>>> {
+        result_row(self)
+    }
+}
+
+fn result_row<R>(row: &R) -> crate::Result<Vec<ParameterizedValue<'static>>>
+where
+    R: PostgresValues,
+{
+    fn unsupported_column_type<R: PostgresValues>(row: &R, i: usize) -> error::Error {
+        let column = &row.columns()[i];
+
+        let kind = ErrorKind::UnsupportedColumnType {
+            column: column.name().into(),
+            db_type: column.type_().name().into(),
+        };
+
+        error::Error::builder(kind).build()
+    }
+
+    fn convert<R: PostgresValues>(row: &R, i: usize) -> crate::Result<ParameterizedValue<'static>> {
+        let result = match *row.columns()[i].type_() {
+            PostgresType::BOOL => match row.try_get(i)? {
+                Some(val) => ParameterizedValue::Boolean(val),
+                None => ParameterizedValue::Null,
+            },
+            PostgresType::INT2 => match row.try_get(i)? {
+                Some(val) => {
+                    let val: i16 = val;
+                    ParameterizedValue::Integer(i64::from(val))
+                }
+                None => ParameterizedValue::Null,
+            },
+            PostgresType::INT4 => match row.try_get(i)? {
+                Some(val) => {
+                    let val: i32 = val;
+                    ParameterizedValue::Integer(i64::from(val))
+                }
+                None => ParameterizedValue::Null,
+            },
+            PostgresType::INT8 => match row.try_get(i)? {
+                Some(val) => {
+                    let val: i64 = val;
+                    ParameterizedValue::Integer(val)
+                }
+                None => ParameterizedValue::Null,
+            },
+            PostgresType::NUMERIC => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Decimal = val;
+                    ParameterizedValue::Real(val)
+                }
+                None => ParameterizedValue::Null,
+            },
+            PostgresType::FLOAT4 => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Decimal = Decimal::from_f32(val).ok_or_else(|| {
+                        error::Error::builder(ErrorKind::ConversionError("f32 is not a Decimal")).build()
+                    })?;
+                    ParameterizedValue::Real(val)
+                }
+                None => ParameterizedValue::Null,
+            },
+            PostgresType::FLOAT8 => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Decimal = Decimal::from_f64(val).ok_or_else(|| {
+                        error::Error::builder(ErrorKind::ConversionError("f64 is not a Decimal")).build()
+                    })?;
+                    ParameterizedValue::Real(val)
+                }
+                None => ParameterizedValue::Null,
+            },
+            PostgresType::BYTEA => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Vec<u8> = val;
+                    ParameterizedValue::Bytes(val.into())
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "chrono-0_4")]
+            PostgresType::TIMESTAMPTZ => match row.try_get(i)? {
+                Some(val) => {
+                    let dt: DateTime<Utc> = val;
+                    ParameterizedValue::DateTime(dt)
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "chrono-0_4")]
+            PostgresType::DATE => match row.try_get(i)? {
+                Some(val) => {
+                    let date: NaiveDate = val;
+                    ParameterizedValue::Date(date)
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "chrono-0_4")]
+            PostgresType::TIME => match row.try_get(i)? {
+                Some(val) => {
+                    let time: NaiveTime = val;
+                    ParameterizedValue::Time(time)
+                }
+                None => ParameterizedValue::Null,
+            },
+            PostgresType::INTERVAL => match row.try_get(i)? {
+                Some(val) => {
+                    let interval: Interval = val;
+                    ParameterizedValue::Text(interval.to_string().into())
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "chrono-0_4")]
+            PostgresType::TIMESTAMP => match row.try_get(i)? {
+                Some(val) => {
+                    let ts: NaiveDateTime = val;
+                    let dt = DateTime::<Utc>::from_utc(ts, Utc);
+                    ParameterizedValue::DateTime(dt)
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "uuid-0_8")]
+            PostgresType::UUID => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Uuid = val;
+                    ParameterizedValue::Uuid(val)
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "json-1")]
+            PostgresType::JSON | PostgresType::JSONB => match row.try_get(i)? {
+                Some(val) => {
+                    let val: serde_json::Value = val;
+                    ParameterizedValue::Json(val)
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "array")]
+            PostgresType::INT2_ARRAY => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Vec<i16> = val;
+                    ParameterizedValue::Array(
+                        val.into_iter()
+                            .map(|x| ParameterizedValue::Integer(i64::from(x)))
+                            .collect(),
+                    )
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "array")]
+            PostgresType::INT4_ARRAY => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Vec<i32> = val;
+                    ParameterizedValue::Array(
+                        val.into_iter()
+                            .map(|x| ParameterizedValue::Integer(i64::from(x)))
+                            .collect(),
+                    )
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "array")]
+            PostgresType::INT8_ARRAY => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Vec<i64> = val;
+                    ParameterizedValue::Array(val.into_iter().map(|x| ParameterizedValue::Integer(x as i64)).collect())
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "array")]
+            PostgresType::FLOAT4_ARRAY => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Vec<f32> = val;
+                    ParameterizedValue::Array(val.into_iter().map(ParameterizedValue::from).collect())
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "array")]
+            PostgresType::FLOAT8_ARRAY => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Vec<f64> = val;
+                    ParameterizedValue::Array(val.into_iter().map(ParameterizedValue::from).collect())
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "array")]
+            PostgresType::BOOL_ARRAY => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Vec<bool> = val;
+                    ParameterizedValue::Array(val.into_iter().map(ParameterizedValue::Boolean).collect())
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(all(feature = "array", feature = "chrono-0_4"))]
+            PostgresType::TIMESTAMP_ARRAY => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Vec<NaiveDateTime> = val;
+                    ParameterizedValue::Array(
+                        val.into_iter()
+                            .map(|x| ParameterizedValue::DateTime(DateTime::<Utc>::from_utc(x, Utc)))
+                            .collect(),
+                    )
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "array")]
+            PostgresType::NUMERIC_ARRAY => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Vec<Decimal> = val;
+                    ParameterizedValue::Array(val.into_iter().map(ParameterizedValue::Real).collect())
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(feature = "array")]
+            PostgresType::TEXT_ARRAY | PostgresType::NAME_ARRAY | PostgresType::VARCHAR_ARRAY => {
+                match row.try_get(i)? {
                     Some(val) => {
-                        let val: Vec<NaiveDateTime> = val;
+                        let val: Vec<&str> = val;
                         ParameterizedValue::Array(
                             val.into_iter()
-                                .map(|x| ParameterizedValue::DateTime(DateTime::<Utc>::from_utc(x, Utc)))
+                                .map(|x| ParameterizedValue::Text(String::from(x).into()))
                                 .collect(),
                         )
                     }
                     None => ParameterizedValue::Null,
-                },
-                #[cfg(feature = "array")]
-                PostgresType::NUMERIC_ARRAY => match row.try_get(i)? {
+                }
+            }
+            #[cfg(all(feature = "array", feature = "uuid-0_8"))]
+            PostgresType::UUID_ARRAY => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Vec<Uuid> = val;
+                    ParameterizedValue::Array(val.into_iter().map(ParameterizedValue::Uuid).collect())
+                }
+                None => ParameterizedValue::Null,
+            },
+            #[cfg(all(feature = "array", feature = "json-1"))]
+            PostgresType::JSON_ARRAY | PostgresType::JSONB_ARRAY => match row.try_get(i)? {
+                Some(val) => {
+                    let val: Vec<serde_json::Value> = val;
+                    ParameterizedValue::Array(val.into_iter().map(ParameterizedValue::Json).collect())
+                }
+                None => ParameterizedValue::Null,
+            },
+            PostgresType::OID => match row.try_get(i)? {
+                Some(val) => {
+                    let val: u32 = val;
+                    ParameterizedValue::Integer(i64::from(val))
+                }
+                None => ParameterizedValue::Null,
+            },
+            PostgresType::CHAR => match row.try_get(i)? {
+                Some(val) => {
+                    let val: i8 = val;
+                    ParameterizedValue::Char((val as u8) as char)
+                }
+                None => ParameterizedValue::Null,
+            },
+            ref x => match x.kind() {
+                Kind::Enum(_) => match row.try_get(i)? {
                     Some(val) => {
-                        let val: Vec<Decimal> = val;
-                        ParameterizedValue::Array(
-                            val.into_iter()
-                                .map(|x| ParameterizedValue::Real(x.to_string().parse().unwrap()))
-                                .collect(),
-                        )
+                        let val: EnumString = val;
+                        ParameterizedValue::Enum(val.value.into())
                     }
                     None => ParameterizedValue::Null,
                 },
-                #[cfg(feature = "array")]
-                PostgresType::TEXT_ARRAY | PostgresType::NAME_ARRAY | PostgresType::VARCHAR_ARRAY => {
-                    match row.try_get(i)? {
+                Kind::Array(inner) => match inner.kind() {
+                    Kind::Enum(_) => match row.try_get(i)? {
                         Some(val) => {
-                            let val: Vec<&str> = val;
+                            let val: Vec<EnumString> = val;
                             ParameterizedValue::Array(
                                 val.into_iter()
-                                    .map(|x| ParameterizedValue::Text(String::from(x).into()))
+                                    .map(|x| ParameterizedValue::Enum(x.value.into()))
                                     .collect(),
                             )
                         }
                         None => ParameterizedValue::Null,
-                    }
-                }
-                PostgresType::OID => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: u32 = val;
-                        ParameterizedValue::Integer(i64::from(val))
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                PostgresType::CHAR => match row.try_get(i)? {
-                    Some(val) => {
-                        let val: i8 = val;
-                        ParameterizedValue::Char((val as u8) as char)
-                    }
-                    None => ParameterizedValue::Null,
-                },
-                ref x => match x.kind() {
-                    Kind::Enum(_) => match row.try_get(i)? {
-                        Some(val) => {
-                            let val: EnumString = val;
-                            ParameterizedValue::Enum(val.value.into())
-                        }
-                        None => ParameterizedValue::Null,
-                    },
-                    Kind::Array(inner) => match inner.kind() {
-                        Kind::Enum(_) => match row.try_get(i)? {
-                            Some(val) => {
-                                let val: Vec<EnumString> = val;
-                                ParameterizedValue::Array(
-                                    val.into_iter()
-                                        .map(|x| ParameterizedValue::Enum(x.value.into()))
-                                        .collect(),
-                                )
-                            }
-                            None => ParameterizedValue::Null,
-                        },
-                        _ => match row.try_get(i)? {
-                            Some(val) => {
-                                let val: Vec<String> = val;
-                                ParameterizedValue::Array(
-                                    val.into_iter().map(|x| ParameterizedValue::Text(x.into())).collect(),
-                                )
-                            }
-                            None => ParameterizedValue::Null,
-                        },
                     },
-                    _ => match row.try_get(i)? {
+                    _ if <Vec<String> as FromSql>::accepts(x) => match row.try_get(i)? {
                         Some(val) => {
-                            let val: String = val;
-                            ParameterizedValue::Text(val.into())
+                            let val: Vec<String> = val;
+                            ParameterizedValue::Array(
+                                val.into_iter().map(|x| ParameterizedValue::Text(x.into())).collect(),
+                            )
                         }
                         None => ParameterizedValue::Null,
                     },
+                    _ => return Err(unsupported_column_type(row, i)),
                 },
-            };
-
-            Ok(result)
-        }
+                _ if <String as FromSql>::accepts(x) => match row.try_get(i)? {
+                    Some(val) => {
+                        let val: String = val;
+                        ParameterizedValue::Text(val.into())
+                    }
+                    None => ParameterizedValue::Null,
+                },
+                _ => return Err(unsupported_column_type(row, i)),
+            },
+        };
 
-        let num_columns = self.columns().len();
-        let mut row = Vec::with_capacity(num_columns);
+        Ok(result)
+    }
 
-        for i in 0..num_columns {
-            row.push(convert(self, i)?);
-        }
+    let num_columns = row.columns().len();
+    let mut values = Vec::with_capacity(num_columns);
 
-        Ok(row)
+    for i in 0..num_columns {
+        values.push(convert(row, i)?);
     }
+
+    Ok(values)
 }
 
 impl ToColumnNames for PostgresStatement {
@@ -293,7 +487,7 @@ impl<'a> ToSql for ParameterizedValue<'a> {
             ParameterizedValue::Real(float) => match *ty {
                 PostgresType::NUMERIC => {
                     let s = float.to_string();
-                    Decimal::from_str(&s).unwrap().to_sql(ty, out)
+                    Decimal::from_str(&s)?.to_sql(ty, out)
                 }
                 _ => float.to_sql(ty, out),
             },
@@ -304,6 +498,7 @@ impl<'a> ToSql for ParameterizedValue<'a> {
             }
             ParameterizedValue::Boolean(boo) => boo.to_sql(ty, out),
             ParameterizedValue::Char(c) => (*c as i8).to_sql(ty, out),
+            ParameterizedValue::Bytes(bytes) => (&**bytes).to_sql(ty, out),
             #[cfg(feature = "array")]
             ParameterizedValue::Array(vec) => vec.to_sql(ty, out),
             #[cfg(feature = "json-1")]
@@ -311,7 +506,19 @@ impl<'a> ToSql for ParameterizedValue<'a> {
             #[cfg(feature = "uuid-0_8")]
             ParameterizedValue::Uuid(value) => value.to_sql(ty, out),
             #[cfg(feature = "chrono-0_4")]
-            ParameterizedValue::DateTime(value) => value.naive_utc().to_sql(ty, out),
+            ParameterizedValue::DateTime(value) => match *ty {
+                PostgresType::TIMESTAMPTZ => value.to_sql(ty, out),
+                _ => value.naive_utc().to_sql(ty, out),
+            },
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::DateTimeTz(value) => match *ty {
+                PostgresType::TIMESTAMPTZ => value.to_sql(ty, out),
+                _ => value.naive_utc().to_sql(ty, out),
+            },
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Date(value) => value.to_sql(ty, out),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Time(value) => value.to_sql(ty, out),
         }
     }
 
@@ -335,7 +542,7 @@ impl<'a> ToSql for ParameterizedValue<'a> {
             ParameterizedValue::Real(float) => match *ty {
                 PostgresType::NUMERIC => {
                     let s = float.to_string();
-                    Decimal::from_str(&s).unwrap().to_sql(ty, out)
+                    Decimal::from_str(&s)?.to_sql(ty, out)
                 }
                 _ => float.to_sql(ty, out),
             },
@@ -346,6 +553,7 @@ impl<'a> ToSql for ParameterizedValue<'a> {
             }
             ParameterizedValue::Boolean(boo) => boo.to_sql_checked(ty, out),
             ParameterizedValue::Char(c) => (*c as i8).to_sql_checked(ty, out),
+            ParameterizedValue::Bytes(bytes) => (&**bytes).to_sql_checked(ty, out),
             #[cfg(feature = "array")]
             ParameterizedValue::Array(vec) => vec.to_sql_checked(ty, out),
             #[cfg(feature = "json-1")]
@@ -353,7 +561,19 @@ impl<'a> ToSql for ParameterizedValue<'a> {
             #[cfg(feature = "uuid-0_8")]
             ParameterizedValue::Uuid(value) => value.to_sql_checked(ty, out),
             #[cfg(feature = "chrono-0_4")]
-            ParameterizedValue::DateTime(value) => value.naive_utc().to_sql_checked(ty, out),
+            ParameterizedValue::DateTime(value) => match *ty {
+                PostgresType::TIMESTAMPTZ => value.to_sql_checked(ty, out),
+                _ => value.naive_utc().to_sql_checked(ty, out),
+            },
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::DateTimeTz(value) => match *ty {
+                PostgresType::TIMESTAMPTZ => value.to_sql_checked(ty, out),
+                _ => value.naive_utc().to_sql_checked(ty, out),
+            },
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Date(value) => value.to_sql_checked(ty, out),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Time(value) => value.to_sql_checked(ty, out),
         }
     }
 }
@@ -117,6 +117,23 @@ impl From<tokio_postgres::error::Error> for Error {
 
                 builder.build()
             }
+            Some(code) if code == "40001" || code == "40P01" => {
+                let kind = match code {
+                    "40001" => ErrorKind::SerializationFailure,
+                    _ => ErrorKind::Deadlock,
+                };
+
+                let code = code.to_string();
+                let error = e.into_source().unwrap(); // boom
+                let db_error = error.downcast_ref::<DbError>().unwrap(); // BOOM
+
+                let mut builder = Error::builder(kind);
+
+                builder.set_original_code(code);
+                builder.set_original_message(db_error.message());
+
+                builder.build()
+            }
             code => {
                 // This is necessary, on top of the other conversions, for the cases where a
                 // native_tls error comes wrapped in a tokio_postgres error.
@@ -0,0 +1,114 @@
+use crate::{
+    ast::ParameterizedValue,
+    connector::{
+        introspect::{bool_value, string_value, ColumnDescription, SchemaBuilder, SchemaDescription},
+        Queryable,
+    },
+};
+
+const TABLES: &str = r#"
+    SELECT c.relname::text
+    FROM pg_class c
+    JOIN pg_namespace n ON n.oid = c.relnamespace
+    WHERE n.nspname = $1 AND c.relkind IN ('r', 'p')
+"#;
+
+const COLUMNS: &str = r#"
+    SELECT c.relname::text, a.attname::text, format_type(a.atttypid, a.atttypmod),
+           NOT a.attnotnull, pg_get_expr(d.adbin, d.adrelid)
+    FROM pg_attribute a
+    JOIN pg_class c ON c.oid = a.attrelid
+    JOIN pg_namespace n ON n.oid = c.relnamespace
+    LEFT JOIN pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+    WHERE n.nspname = $1 AND c.relkind IN ('r', 'p') AND a.attnum > 0 AND NOT a.attisdropped
+    ORDER BY c.relname, a.attnum
+"#;
+
+const INDEXES: &str = r#"
+    SELECT t.relname::text, i.relname::text, a.attname::text, ix.indisunique, ix.indisprimary
+    FROM pg_index ix
+    JOIN pg_class t ON t.oid = ix.indrelid
+    JOIN pg_class i ON i.oid = ix.indexrelid
+    JOIN pg_namespace n ON n.oid = t.relnamespace
+    JOIN LATERAL unnest(ix.indkey::int2[]) WITH ORDINALITY AS k(attnum, ord) ON true
+    JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum
+    WHERE n.nspname = $1
+    ORDER BY t.relname, i.relname, k.ord
+"#;
+
+const FOREIGN_KEYS: &str = r#"
+    SELECT t.relname::text, con.conname::text, a.attname::text, r.relname::text, ra.attname::text
+    FROM pg_constraint con
+    JOIN pg_class t ON t.oid = con.conrelid
+    JOIN pg_class r ON r.oid = con.confrelid
+    JOIN pg_namespace n ON n.oid = t.relnamespace
+    JOIN LATERAL unnest(con.conkey, con.confkey) WITH ORDINALITY AS k(attnum, refnum, ord) ON true
+    JOIN pg_attribute a ON a.attrelid = con.conrelid AND a.attnum = k.attnum
+    JOIN pg_attribute ra ON ra.attrelid = con.confrelid AND ra.attnum = k.refnum
+    WHERE con.contype = 'f' AND n.nspname = $1
+    ORDER BY t.relname, con.conname, k.ord
+"#;
+
+const ENUMS: &str = r#"
+    SELECT t.typname::text, e.enumlabel::text
+    FROM pg_type t
+    JOIN pg_enum e ON e.enumtypid = t.oid
+    JOIN pg_namespace n ON n.oid = t.typnamespace
+    WHERE n.nspname = $1
+    ORDER BY t.typname, e.enumsortorder
+"#;
+
+/// Describes a schema from `pg_catalog`.
+pub(crate) async fn describe<Q>(conn: &Q, schema: &str) -> crate::Result<SchemaDescription>
+where
+    Q: Queryable + ?Sized,
+{
+    let params = [ParameterizedValue::from(schema)];
+    let mut builder = SchemaBuilder::default();
+
+    for row in conn.query_raw(TABLES, &params).await? {
+        builder.add_table(string_value(&row[0]).unwrap_or_default());
+    }
+
+    for row in conn.query_raw(COLUMNS, &params).await? {
+        let table = string_value(&row[0]).unwrap_or_default();
+
+        builder.table(&table).columns.push(ColumnDescription {
+            name: string_value(&row[1]).unwrap_or_default(),
+            data_type: string_value(&row[2]).unwrap_or_default(),
+            nullable: bool_value(&row[3]),
+            default: string_value(&row[4]),
+        });
+    }
+
+    for row in conn.query_raw(INDEXES, &params).await? {
+        let table = string_value(&row[0]).unwrap_or_default();
+        let index = string_value(&row[1]).unwrap_or_default();
+        let column = string_value(&row[2]).unwrap_or_default();
+
+        if bool_value(&row[4]) {
+            builder.table(&table).primary_key.push(column);
+        } else {
+            builder.add_index_column(&table, index, column, bool_value(&row[3]));
+        }
+    }
+
+    for row in conn.query_raw(FOREIGN_KEYS, &params).await? {
+        builder.add_foreign_key_column(
+            &string_value(&row[0]).unwrap_or_default(),
+            string_value(&row[1]),
+            string_value(&row[2]).unwrap_or_default(),
+            string_value(&row[3]).unwrap_or_default(),
+            string_value(&row[4]).unwrap_or_default(),
+        );
+    }
+
+    for row in conn.query_raw(ENUMS, &params).await? {
+        builder.add_enum_value(
+            string_value(&row[0]).unwrap_or_default(),
+            string_value(&row[1]).unwrap_or_default(),
+        );
+    }
+
+    Ok(builder.build())
+}
@@ -1,4 +1,4 @@
-use super::{ResultSet, Transaction, DBIO};
+use super::{ResultSet, ResultStream, SqlFamily, Transaction, TransactionOptions, DBIO};
 use crate::ast::*;
 
 pub trait GetRow {
@@ -24,6 +24,19 @@ where
     /// Execute a query given as SQL, interpolating the given parameters.
     fn query_raw<'a>(&'a self, sql: &'a str, params: &'a [ParameterizedValue<'a>]) -> DBIO<'a, ResultSet>;
 
+    /// Execute a query given as SQL, interpolating the given parameters, and
+    /// stream the resulting rows instead of reading them all into memory.
+    ///
+    /// The connection stays reserved until the stream is dropped. PostgreSQL
+    /// and SQLite read the rows a few at a time as the stream is polled. MySQL
+    /// reads every row already received from the server whenever the stream
+    /// runs out of rows, so its memory use is not bounded.
+    ///
+    /// By default the rows are read into a `ResultSet` before streaming them.
+    fn query_stream<'a>(&'a self, sql: &'a str, params: &'a [ParameterizedValue<'a>]) -> DBIO<'a, ResultStream<'a>> {
+        DBIO::new(async move { Ok(ResultStream::from(self.query_raw(sql, params).await?)) })
+    }
+
     /// Execute the given query, returning the number of affected rows.
     fn execute<'a>(&'a self, q: Query<'a>) -> DBIO<'a, u64>;
 
@@ -35,6 +48,16 @@ where
     /// prepared statements.
     fn raw_cmd<'a>(&'a self, cmd: &'a str) -> DBIO<'a, ()>;
 
+    /// The SQL dialect of the connection.
+    fn sql_family(&self) -> SqlFamily;
+
+    /// Called when a transaction is dropped without being committed or
+    /// rolled back, with its nesting depth and the command that would roll it
+    /// back. Connections that can run the command without blocking on I/O
+    /// should do so, others should run it before their next query.
+    #[doc(hidden)]
+    fn transaction_dropped(&self, _depth: u32, _rollback: &str) {}
+
     /// Execute a `SELECT` query.
     fn select<'a>(&'a self, q: Select<'a>) -> DBIO<'a, ResultSet> {
         self.query(q.into())
@@ -45,17 +68,61 @@ where
         self.query(q.into())
     }
 
+    /// Execute a multi-row `INSERT` in as many queries as needed to stay under
+    /// the parameter limit of the database, returning the total number of
+    /// inserted rows. See
+    /// [max_bind_values](enum.SqlFamily.html#method.max_bind_values).
+    ///
+    /// The batches are not atomic, run this in a transaction to insert all
+    /// or none of the rows.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, connector::*};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), quaint::error::Error> {
+    /// let conn = Sqlite::new("db/test.db")?;
+    /// conn.raw_cmd("CREATE TABLE numbers (a INTEGER, b INTEGER)").await?;
+    ///
+    /// let insert = (0..1000).fold(Insert::multi_into("numbers", vec!["a", "b"]), |insert, i| {
+    ///     insert.values((i, i * 2))
+    /// });
+    ///
+    /// assert_eq!(1000, conn.insert_batched(insert).await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn insert_batched<'a>(&'a self, q: MultiRowInsert<'a>) -> DBIO<'a, u64> {
+        DBIO::new(async move {
+            let mut changes = 0;
+
+            for batch in q.into_batches(self.sql_family().max_bind_values()) {
+                changes += self.execute(batch.build().into()).await?;
+            }
+
+            Ok(changes)
+        })
+    }
+
     /// Execute an `UPDATE` query, returning the number of affected rows.
     fn update<'a>(&'a self, q: Update<'a>) -> DBIO<'a, u64> {
         self.execute(q.into())
     }
 
+    /// Execute an `UPDATE` query, returning the rows of its
+    /// [returning](../ast/struct.Update.html#method.returning) columns.
+    fn update_returning<'a>(&'a self, q: Update<'a>) -> DBIO<'a, ResultSet> {
+        self.query(q.into())
+    }
+
     /// Execute a `DELETE` query, returning the number of affected rows.
-    fn delete<'a>(&'a self, q: Delete<'a>) -> DBIO<'a, ()> {
-        DBIO::new(async move {
-            self.query(q.into()).await?;
-            Ok(())
-        })
+    fn delete<'a>(&'a self, q: Delete<'a>) -> DBIO<'a, u64> {
+        self.execute(q.into())
+    }
+
+    /// Execute a `DELETE` query, returning the rows of its
+    /// [returning](../ast/struct.Delete.html#method.returning) columns.
+    fn delete_returning<'a>(&'a self, q: Delete<'a>) -> DBIO<'a, ResultSet> {
+        self.query(q.into())
     }
 }
 
@@ -66,6 +133,83 @@ where
 {
     /// Starts a new transaction
     fn start_transaction(&self) -> DBIO<Transaction> {
-        DBIO::new(async move { Transaction::new(self).await })
+        self.start_transaction_with(TransactionOptions::default())
+    }
+
+    /// Starts a new transaction with the given isolation level and access
+    /// mode.
+    fn start_transaction_with(&self, options: TransactionOptions) -> DBIO<Transaction> {
+        DBIO::new(async move { Transaction::new(self, options).await })
+    }
+
+    /// Runs the closure in a new transaction, committing it if the closure
+    /// returns `Ok` and rolling it back on errors.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, connector::*};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), quaint::error::Error> {
+    /// let conn = Sqlite::new("db/test.db")?;
+    /// conn.raw_cmd("CREATE TABLE users (id INTEGER)").await?;
+    ///
+    /// let changes = conn
+    ///     .transaction(|tx| DBIO::new(async move {
+    ///         tx.execute(Insert::single_into("users").value("id", 1).into()).await
+    ///     }))
+    ///     .await?;
+    ///
+    /// assert_eq!(1, changes);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transaction<'a, F, T>(&'a self, f: F) -> DBIO<'a, T>
+    where
+        F: for<'t> Fn(&'t Transaction<'a>) -> DBIO<'t, T> + Send + Sync + 'a,
+        T: Send + 'a,
+    {
+        self.transaction_with(TransactionOptions::default(), f)
+    }
+
+    /// Runs the closure in a new transaction started with the given options,
+    /// committing it if the closure returns `Ok` and rolling it back on errors.
+    ///
+    /// If the transaction fails due to a serialization failure, a deadlock or
+    /// a lock timeout, it is rolled back and the closure is run again in a new
+    /// transaction, at most [max_retries](struct.TransactionOptions.html#method.max_retries)
+    /// times. A nested transaction is never retried, the error is returned to
+    /// let the outermost transaction retry.
+    fn transaction_with<'a, F, T>(&'a self, options: TransactionOptions, f: F) -> DBIO<'a, T>
+    where
+        F: for<'t> Fn(&'t Transaction<'a>) -> DBIO<'t, T> + Send + Sync + 'a,
+        T: Send + 'a,
+    {
+        DBIO::new(async move {
+            let mut attempt = 0;
+
+            loop {
+                let tx = self.start_transaction_with(options.clone()).await?;
+                let depth = tx.depth();
+
+                let error = match f(&tx).await {
+                    Ok(value) => match tx.commit().await {
+                        Ok(()) => return Ok(value),
+                        Err(e) => e,
+                    },
+                    Err(e) => {
+                        let _ = tx.rollback().await;
+                        e
+                    }
+                };
+
+                // A conflict aborts the whole transaction on the server, only
+                // the outermost transaction can run again.
+                if depth > 0 || !error.is_retryable() || attempt >= options.max_retries {
+                    return Err(error);
+                }
+
+                tokio::time::delay_for(options.retry_backoff * 2u32.pow(attempt.min(10))).await;
+                attempt += 1;
+            }
+        })
     }
 }
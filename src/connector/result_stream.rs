@@ -0,0 +1,90 @@
+use super::{ResultRow, ResultSet};
+use crate::ast::ParameterizedValue;
+use futures::{
+    stream::{self, BoxStream, Stream, StreamExt},
+    task::{Context, Poll},
+};
+use std::{pin::Pin, sync::Arc};
+
+/// A stream of result rows, read from the database when polled. The column
+/// names are available before reading the first row.
+///
+/// ```rust
+/// # use quaint::connector::*;
+/// # use futures::stream::TryStreamExt;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), quaint::error::Error> {
+/// let conn = Sqlite::new("db/test.db")?;
+/// let mut stream = conn.query_stream("SELECT 1 AS one", &[]).await?;
+///
+/// assert_eq!(&vec!["one".to_string()], stream.columns());
+///
+/// while let Some(row) = stream.try_next().await? {
+///     assert_eq!(Some(1), row["one"].as_i64());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ResultStream<'a> {
+    columns: Arc<Vec<String>>,
+    rows: BoxStream<'a, crate::Result<Vec<ParameterizedValue<'static>>>>,
+}
+
+impl<'a> ResultStream<'a> {
+    pub(crate) fn new<S>(columns: Vec<String>, rows: S) -> Self
+    where
+        S: Stream<Item = crate::Result<Vec<ParameterizedValue<'static>>>> + Send + 'a,
+    {
+        Self {
+            columns: Arc::new(columns),
+            rows: rows.boxed(),
+        }
+    }
+
+    /// Keeps the value alive until the stream is dropped.
+    pub(crate) fn holding<T>(self, value: T) -> Self
+    where
+        T: Send + 'a,
+    {
+        let rows = self.rows.map(move |row| {
+            let _ = &value;
+            row
+        });
+
+        Self {
+            columns: self.columns,
+            rows: rows.boxed(),
+        }
+    }
+
+    /// The column names of the rows in the stream.
+    pub fn columns(&self) -> &Vec<String> {
+        &self.columns
+    }
+}
+
+impl<'a> From<ResultSet> for ResultStream<'a> {
+    fn from(result_set: ResultSet) -> Self {
+        Self {
+            columns: result_set.columns,
+            rows: stream::iter(result_set.rows.into_iter().map(Ok)).boxed(),
+        }
+    }
+}
+
+impl<'a> Stream for ResultStream<'a> {
+    type Item = crate::Result<ResultRow>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let columns = Arc::clone(&self.columns);
+
+        self.rows.as_mut().poll_next(ctx).map(|row| {
+            row.map(|row| {
+                row.map(|values| ResultRow {
+                    columns: columns.clone(),
+                    values,
+                })
+            })
+        })
+    }
+}
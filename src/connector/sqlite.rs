@@ -1,24 +1,62 @@
 mod conversion;
 mod error;
+pub(crate) mod introspect;
 
 use crate::{
-    ast::{ParameterizedValue, Query},
-    connector::{metrics, queryable::*, ResultSet, DBIO},
+    ast::{AlterTableChange, ConflictTarget, OnConflict, ParameterizedValue, Query},
+    connector::{
+        metrics, queryable::*, BulkLoad, Introspect, PendingRollback, ResultSet, ResultStream, SqlFamily, DBIO,
+    },
     error::{Error, ErrorKind},
-    visitor::{self, Visitor},
+    visitor,
 };
+use futures::{
+    channel::{mpsc, oneshot},
+    SinkExt,
+};
+use regex::Regex;
 use rusqlite::NO_PARAMS;
-use std::{collections::HashSet, convert::TryFrom, path::Path, time::Duration};
-use tokio::sync::Mutex;
+use std::{collections::HashSet, convert::TryFrom, path::Path, sync::Arc, time::Duration};
+use tokio::sync::{Mutex, OwnedMutexGuard};
 
 const DEFAULT_SCHEMA_NAME: &str = "quaint";
 
+/// How many rows a stream reads ahead of its consumer.
+const STREAM_BUFFER_SIZE: usize = 64;
+
 /// A connector interface for the SQLite database
 pub struct Sqlite {
-    pub(crate) client: Mutex<rusqlite::Connection>,
+    pub(crate) client: Arc<Mutex<rusqlite::Connection>>,
+    pending_rollback: PendingRollback,
     /// This is not a `PathBuf` because we need to `ATTACH` the database to the path, and this can
     /// only be done with UTF-8 paths.
     pub(crate) file_path: String,
+    pub(crate) datetime_format: DateTimeFormat,
+}
+
+/// How datetime values are written to SQLite, which has no type for them.
+/// Set with the `datetime_format` connection string parameter.
+///
+/// Both formats are read back as datetimes from columns declared as
+/// `DATETIME` or `TIMESTAMP`. Dates and times without a date are always
+/// stored as ISO-8601 text, such as `2020-03-31` and `13:37:00`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateTimeFormat {
+    /// Milliseconds since the Unix epoch, stored as an integer (`millis`).
+    /// The default.
+    Millis,
+    /// RFC 3339 text in UTC with milliseconds, such as
+    /// `2020-03-31T13:37:00.000Z` (`iso8601`). Values with another offset
+    /// are converted to UTC, so the stored text sorts chronologically.
+    Iso8601,
+}
+
+// Deriving needs `#[default]` on the variant, which older compilers lack.
+#[allow(clippy::derivable_impls)]
+impl Default for DateTimeFormat {
+    fn default() -> Self {
+        Self::Millis
+    }
 }
 
 pub struct SqliteParams {
@@ -28,6 +66,7 @@ pub struct SqliteParams {
     pub file_path: String,
     pub db_name: String,
     pub socket_timeout: Option<Duration>,
+    pub datetime_format: DateTimeFormat,
 }
 
 type ConnectionParams = (Vec<(String, String)>, Vec<(String, String)>);
@@ -53,6 +92,7 @@ impl TryFrom<&str> for SqliteParams {
             let mut connection_limit = num_cpus::get_physical() * 2 + 1;
             let mut db_name = None;
             let mut socket_timeout = None;
+            let mut datetime_format = DateTimeFormat::default();
 
             if path_parts.len() > 1 {
                 let (_, unsupported): ConnectionParams = path_parts
@@ -86,6 +126,13 @@ impl TryFrom<&str> for SqliteParams {
 
                             socket_timeout = Some(Duration::from_secs(as_int));
                         }
+                        "datetime_format" => {
+                            datetime_format = match v.as_str() {
+                                "millis" => DateTimeFormat::Millis,
+                                "iso8601" => DateTimeFormat::Iso8601,
+                                _ => return Err(Error::builder(ErrorKind::InvalidConnectionArguments).build()),
+                            };
+                        }
                         _ => {
                             #[cfg(not(feature = "tracing-log"))]
                             trace!("Discarding connection string param: {}", k);
@@ -101,6 +148,7 @@ impl TryFrom<&str> for SqliteParams {
                 file_path: path_str.to_owned(),
                 db_name: db_name.unwrap_or_else(|| DEFAULT_SCHEMA_NAME.to_owned()),
                 socket_timeout,
+                datetime_format,
             })
         }
     }
@@ -118,10 +166,21 @@ impl TryFrom<&str> for Sqlite {
             conn.busy_timeout(timeout)?;
         };
 
-        let client = Mutex::new(conn);
+        register_regexp(&conn)?;
+
+        #[cfg(feature = "json-1")]
+        register_json_contains(&conn)?;
+
+        let client = Arc::new(Mutex::new(conn));
         let file_path = params.file_path;
+        let datetime_format = params.datetime_format;
 
-        Ok(Sqlite { client, file_path })
+        Ok(Sqlite {
+            client,
+            pending_rollback: PendingRollback::default(),
+            file_path,
+            datetime_format,
+        })
     }
 }
 
@@ -130,8 +189,20 @@ impl Sqlite {
         Self::try_from(file_path)
     }
 
+    /// Locks the client, first rolling back a transaction dropped while the
+    /// client was in use.
+    async fn lock_client(&self) -> crate::Result<OwnedMutexGuard<rusqlite::Connection>> {
+        let client = self.client.clone().lock_owned().await;
+
+        if let Some(rollback) = self.pending_rollback.take() {
+            client.execute_batch(&rollback)?;
+        }
+
+        Ok(client)
+    }
+
     pub async fn attach_database(&mut self, db_name: &str) -> crate::Result<()> {
-        let client = self.client.lock().await;
+        let client = self.lock_client().await?;
         let mut stmt = client.prepare("PRAGMA database_list")?;
 
         let databases: HashSet<String> = stmt
@@ -153,24 +224,154 @@ impl Sqlite {
     }
 }
 
+/// Adds the `regexp` function used by the `REGEXP` operator, which SQLite
+/// does not define. The compiled expression is cached for the statement.
+fn register_regexp(conn: &rusqlite::Connection) -> rusqlite::Result<()> {
+    conn.create_scalar_function("regexp", 2, true, |ctx| {
+        let cached: Option<&Regex> = ctx.get_aux(0)?;
+
+        let compiled = match cached {
+            Some(_) => None,
+            None => {
+                let pattern: String = ctx.get(0)?;
+                let regex = Regex::new(&pattern).map_err(|e| rusqlite::Error::UserFunctionError(Box::new(e)))?;
+
+                Some(regex)
+            }
+        };
+
+        let regex = cached.or(compiled.as_ref()).unwrap();
+        let is_match = ctx.get::<Option<String>>(1)?.map(|text| regex.is_match(&text));
+
+        if let Some(regex) = compiled {
+            ctx.set_aux(0, regex);
+        }
+
+        Ok(is_match)
+    })
+}
+
+/// Adds the `json_contains` function, testing if the first JSON document
+/// contains the second one with the rules of the PostgreSQL `@>` operator.
+#[cfg(feature = "json-1")]
+fn register_json_contains(conn: &rusqlite::Connection) -> rusqlite::Result<()> {
+    fn parse(ctx: &rusqlite::functions::Context, idx: usize) -> rusqlite::Result<Option<serde_json::Value>> {
+        match ctx.get::<Option<String>>(idx)? {
+            Some(text) => {
+                let value = serde_json::from_str(&text).map_err(|e| rusqlite::Error::UserFunctionError(Box::new(e)))?;
+                Ok(Some(value))
+            }
+            None => Ok(None),
+        }
+    }
+
+    conn.create_scalar_function("json_contains", 2, true, |ctx| match (parse(ctx, 0)?, parse(ctx, 1)?) {
+        (Some(target), Some(candidate)) => Ok(Some(json_contains(&target, &candidate))),
+        _ => Ok(None),
+    })
+}
+
+#[cfg(feature = "json-1")]
+fn json_contains(target: &serde_json::Value, candidate: &serde_json::Value) -> bool {
+    use serde_json::Value;
+
+    match (target, candidate) {
+        (Value::Object(target), Value::Object(candidate)) => candidate
+            .iter()
+            .all(|(key, value)| target.get(key).map(|t| json_contains(t, value)).unwrap_or(false)),
+        (Value::Array(target), Value::Array(candidate)) => candidate
+            .iter()
+            .all(|value| target.iter().any(|t| json_contains(t, value))),
+        // An array contains its scalar elements.
+        (Value::Array(target), candidate) if !candidate.is_object() => target.contains(candidate),
+        (target, candidate) => target == candidate,
+    }
+}
+
+fn unsupported_feature(feature: &'static str) -> Error {
+    let kind = ErrorKind::UnsupportedFeature {
+        feature,
+        database: "SQLite",
+    };
+
+    Error::builder(kind).build()
+}
+
+/// The bundled SQLite has no `RETURNING` clause for `UPDATE` and `DELETE`
+/// statements, no named constraints in upserts, no `DROP COLUMN` and no
+/// `DISTINCT ON`.
+fn check_supported(q: &Query) -> crate::Result<()> {
+    let returning = match q {
+        Query::Update(update) => update.returning.is_some(),
+        Query::Delete(delete) => delete.returning.is_some(),
+        _ => false,
+    };
+
+    if returning {
+        return Err(unsupported_feature("RETURNING"));
+    }
+
+    if let Query::Insert(insert) = q {
+        if let Some(OnConflict::DoUpdate(update)) = &insert.on_conflict {
+            if let ConflictTarget::Constraint(_) = update.target {
+                return Err(unsupported_feature("ON CONFLICT ON CONSTRAINT"));
+            }
+
+            if update.columns.is_empty() {
+                return Err(unsupported_feature("ON CONFLICT DO UPDATE without assignments"));
+            }
+        }
+    }
+
+    if let Query::AlterTable(alter_table) = q {
+        if let AlterTableChange::Drop(_) = alter_table.change {
+            return Err(unsupported_feature("DROP COLUMN"));
+        }
+    }
+
+    Ok(())
+}
+
 impl TransactionCapable for Sqlite {}
 
+impl BulkLoad for Sqlite {}
+
+impl Introspect for Sqlite {}
+
 impl Queryable for Sqlite {
     fn query<'a>(&'a self, q: Query<'a>) -> DBIO<'a, ResultSet> {
-        let (sql, params) = visitor::Sqlite::build(q);
-        DBIO::new(async move { self.query_raw(&sql, &params).await })
+        DBIO::new(async move {
+            check_supported(&q)?;
+
+            let (sql, params) = visitor::Sqlite::build_supported(q, self.datetime_format)?;
+            self.query_raw(&sql, &params).await
+        })
     }
 
     fn execute<'a>(&'a self, q: Query<'a>) -> DBIO<'a, u64> {
-        let (sql, params) = visitor::Sqlite::build(q);
-        DBIO::new(async move { self.execute_raw(&sql, &params).await })
+        DBIO::new(async move {
+            check_supported(&q)?;
+
+            let is_ddl = q.is_ddl();
+            let (sql, params) = visitor::Sqlite::build_supported(q, self.datetime_format)?;
+
+            // Schema changes have no parameters, and older SQLite versions
+            // step `ALTER TABLE` with rows, failing a plain execute.
+            if is_ddl {
+                self.raw_cmd(&sql).await?;
+                return Ok(0);
+            }
+
+            self.execute_raw(&sql, &params).await
+        })
     }
 
     fn query_raw<'a>(&'a self, sql: &'a str, params: &'a [ParameterizedValue]) -> DBIO<'a, ResultSet> {
         metrics::query("sqlite.query_raw", sql, params, move || async move {
-            let client = self.client.lock().await;
+            let client = self.lock_client().await?;
 
             let mut stmt = client.prepare_cached(sql)?;
+            let params = conversion::conv_params(params, self.datetime_format)?;
 
             let mut rows = stmt.query(params)?;
             let mut result = ResultSet::new(rows.to_column_names(), Vec::new());
@@ -185,10 +386,68 @@ impl Queryable for Sqlite {
         })
     }
 
+    /// Reads the rows with a cursor on a blocking thread, a few rows ahead of
+    /// the stream. The connection stays locked until the stream is dropped.
+    fn query_stream<'a>(&'a self, sql: &'a str, params: &'a [ParameterizedValue<'a>]) -> DBIO<'a, ResultStream<'a>> {
+        metrics::query("sqlite.query_stream", sql, params, move || async move {
+            let client = self.lock_client().await?;
+            let params = conversion::conv_params_owned(params, self.datetime_format)?;
+            let sql = sql.to_string();
+
+            let (columns_sender, columns) = oneshot::channel::<crate::Result<Vec<String>>>();
+            let (mut sender, rows) = mpsc::channel(STREAM_BUFFER_SIZE);
+
+            tokio::task::spawn_blocking(move || {
+                let mut stmt = match client.prepare(&sql) {
+                    Ok(stmt) => stmt,
+                    Err(e) => {
+                        let _ = columns_sender.send(Err(e.into()));
+                        return;
+                    }
+                };
+
+                let mut rows = match stmt.query(&params) {
+                    Ok(rows) => rows,
+                    Err(e) => {
+                        let _ = columns_sender.send(Err(e.into()));
+                        return;
+                    }
+                };
+
+                if columns_sender.send(Ok(rows.to_column_names())).is_err() {
+                    return;
+                }
+
+                loop {
+                    let row = match rows.next() {
+                        Ok(Some(row)) => row.get_result_row(),
+                        Ok(None) => break,
+                        Err(e) => Err(e.into()),
+                    };
+
+                    let failed = row.is_err();
+
+                    // Waits while the channel is full, stopping when the
+                    // stream is dropped.
+                    if futures::executor::block_on(sender.send(row)).is_err() || failed {
+                        break;
+                    }
+                }
+            });
+
+            let columns = columns
+                .await
+                .map_err(|_| Error::builder(ErrorKind::QueryError("The SQLite cursor stopped".into())).build())??;
+
+            Ok(ResultStream::new(columns, rows))
+        })
+    }
+
     fn execute_raw<'a>(&'a self, sql: &'a str, params: &'a [ParameterizedValue<'a>]) -> DBIO<'a, u64> {
         metrics::query("sqlite.query_raw", sql, params, move || async move {
-            let client = self.client.lock().await;
+            let client = self.lock_client().await?;
             let mut stmt = client.prepare_cached(sql)?;
+            let params = conversion::conv_params(params, self.datetime_format)?;
             let res = u64::try_from(stmt.execute(params)?)?;
 
             Ok(res)
@@ -197,11 +456,24 @@ impl Queryable for Sqlite {
 
     fn raw_cmd<'a>(&'a self, cmd: &'a str) -> DBIO<'a, ()> {
         metrics::query("sqlite.raw_cmd", cmd, &[], move || async move {
-            let client = self.client.lock().await;
+            let client = self.lock_client().await?;
             client.execute_batch(cmd)?;
             Ok(())
         })
     }
+
+    fn transaction_dropped(&self, _depth: u32, rollback: &str) {
+        match self.client.try_lock() {
+            Ok(client) => {
+                let _ = client.execute_batch(rollback);
+            }
+            Err(_) => self.pending_rollback.set(rollback),
+        }
+    }
+
+    fn sql_family(&self) -> SqlFamily {
+        SqlFamily::Sqlite
+    }
 }
 
 #[cfg(test)]
@@ -209,11 +481,38 @@ mod tests {
     use super::*;
     use crate::{
         ast::*,
-        connector::{Queryable, TransactionCapable},
+        col,
+        connector::{
+            ColumnDescription, ForeignKeyDescription, IndexDescription, LockingMode, Queryable, TransactionCapable,
+            TransactionOptions,
+        },
         error::{DatabaseConstraint, ErrorKind},
         val,
     };
 
+    fn assert_unsupported(err: Error, expected: &str) {
+        match err.kind() {
+            ErrorKind::UnsupportedFeature { feature, database } => {
+                assert_eq!(expected, *feature);
+                assert_eq!("SQLite", *database);
+            }
+            e => panic!("Expected `UnsupportedFeature`, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn busy_errors_should_be_retryable_lock_timeouts() {
+        let busy = libsqlite3_sys::Error::new(libsqlite3_sys::SQLITE_BUSY);
+        let err = Error::from(rusqlite::Error::SqliteFailure(busy, None));
+
+        match err.kind() {
+            ErrorKind::LockTimeout(_) => (),
+            e => panic!("Expected `LockTimeout`, got {:?}", e),
+        }
+
+        assert!(err.is_retryable());
+    }
+
     #[test]
     fn sqlite_params_from_str_should_resolve_path_correctly_with_file_scheme() {
         let path = "file:dev.db";
@@ -228,6 +527,17 @@ mod tests {
         assert_eq!(params.file_path, "dev.db");
     }
 
+    #[test]
+    fn sqlite_params_from_str_should_parse_the_datetime_format() {
+        let params = SqliteParams::try_from("file:dev.db?datetime_format=iso8601").unwrap();
+        assert_eq!(DateTimeFormat::Iso8601, params.datetime_format);
+
+        let params = SqliteParams::try_from("file:dev.db").unwrap();
+        assert_eq!(DateTimeFormat::Millis, params.datetime_format);
+
+        assert!(SqliteParams::try_from("file:dev.db?datetime_format=julian").is_err());
+    }
+
     #[test]
     fn sqlite_params_from_str_should_resolve_path_correctly_with_no_scheme() {
         let path = "dev.db";
@@ -252,6 +562,403 @@ mod tests {
         assert!(res.is_empty());
     }
 
+    #[tokio::test]
+    async fn should_start_a_transaction_with_a_locking_mode() {
+        let connection = Sqlite::new("db/test.db").unwrap();
+        let options = TransactionOptions::new().locking_mode(LockingMode::Exclusive);
+
+        let tx = connection.start_transaction_with(options).await.unwrap();
+        let res = tx.query_raw("SELECT * FROM sqlite_master", &[]).await.unwrap();
+        tx.commit().await.unwrap();
+
+        assert!(res.is_empty());
+    }
+
+    #[tokio::test]
+    async fn transaction_closure_should_commit_on_ok_and_roll_back_on_error() {
+        let connection = Sqlite::new("db/test.db").unwrap();
+        connection.raw_cmd("CREATE TABLE closure (id INTEGER)").await.unwrap();
+
+        connection
+            .transaction(|tx| DBIO::new(tx.execute(Insert::single_into("closure").value("id", 1).into())))
+            .await
+            .unwrap();
+
+        let res: crate::Result<()> = connection
+            .transaction(|tx| {
+                DBIO::new(async move {
+                    tx.execute(Insert::single_into("closure").value("id", 2).into()).await?;
+                    Err(Error::builder(ErrorKind::NotFound).build())
+                })
+            })
+            .await;
+
+        assert!(res.is_err());
+
+        let res = connection.select(Select::from_table("closure")).await.unwrap();
+
+        assert_eq!(1, res.len());
+        assert_eq!(Some(1), res.get(0).unwrap()["id"].as_i64());
+    }
+
+    #[tokio::test]
+    async fn transaction_closure_should_retry_on_deadlocks() {
+        use std::sync::atomic::{AtomicI64, Ordering};
+
+        let connection = Sqlite::new("db/test.db").unwrap();
+        connection.raw_cmd("CREATE TABLE retried (id INTEGER)").await.unwrap();
+
+        let attempts = AtomicI64::new(0);
+        let options = TransactionOptions::new()
+            .max_retries(2)
+            .retry_backoff(Duration::from_millis(1));
+
+        let res = connection
+            .transaction_with(options, |tx| {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+
+                DBIO::new(async move {
+                    tx.execute(Insert::single_into("retried").value("id", attempt).into())
+                        .await?;
+
+                    match attempt {
+                        0 => Err(Error::builder(ErrorKind::Deadlock).build()),
+                        _ => Ok(attempt),
+                    }
+                })
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(1, res);
+        assert_eq!(2, attempts.load(Ordering::SeqCst));
+
+        let res = connection.select(Select::from_table("retried")).await.unwrap();
+
+        assert_eq!(1, res.len());
+        assert_eq!(Some(1), res.get(0).unwrap()["id"].as_i64());
+    }
+
+    #[tokio::test]
+    async fn nested_transaction_closure_should_not_retry() {
+        use std::sync::atomic::{AtomicI64, Ordering};
+
+        let connection = Sqlite::new("db/test.db").unwrap();
+        let tx = connection.start_transaction().await.unwrap();
+
+        let attempts = AtomicI64::new(0);
+        let options = TransactionOptions::new()
+            .max_retries(2)
+            .retry_backoff(Duration::from_millis(1));
+
+        let res: crate::Result<()> = tx
+            .transaction_with(options, |_| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                DBIO::new(async move { Err(Error::builder(ErrorKind::Deadlock).build()) })
+            })
+            .await;
+
+        match res.unwrap_err().kind() {
+            ErrorKind::Deadlock => (),
+            e => panic!("Expected `Deadlock`, got {:?}", e),
+        }
+
+        assert_eq!(1, attempts.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn nested_transaction_rollback_should_only_revert_the_inner_scope() {
+        let connection = Sqlite::new("db/test.db").unwrap();
+        connection.raw_cmd("CREATE TABLE nested (id INTEGER)").await.unwrap();
+
+        let tx = connection.start_transaction().await.unwrap();
+        tx.insert(Insert::single_into("nested").value("id", 1).into())
+            .await
+            .unwrap();
+
+        let inner = tx.start_transaction().await.unwrap();
+        assert_eq!(1, inner.depth());
+
+        inner
+            .insert(Insert::single_into("nested").value("id", 2).into())
+            .await
+            .unwrap();
+        inner.rollback().await.unwrap();
+
+        // The savepoint is released after rolling back.
+        assert!(tx.raw_cmd("RELEASE SAVEPOINT quaint_savepoint_1").await.is_err());
+
+        tx.commit().await.unwrap();
+
+        let res = connection.select(Select::from_table("nested")).await.unwrap();
+
+        assert_eq!(1, res.len());
+        assert_eq!(Some(1), res.get(0).unwrap()["id"].as_i64());
+    }
+
+    #[tokio::test]
+    async fn nested_transaction_commit_should_keep_the_changes() {
+        let connection = Sqlite::new("db/test.db").unwrap();
+        connection.raw_cmd("CREATE TABLE nested (id INTEGER)").await.unwrap();
+
+        let tx = connection.start_transaction().await.unwrap();
+
+        let inner = tx.start_transaction().await.unwrap();
+        inner
+            .insert(Insert::single_into("nested").value("id", 1).into())
+            .await
+            .unwrap();
+
+        let innermost = inner.start_transaction().await.unwrap();
+        assert_eq!(2, innermost.depth());
+
+        innermost
+            .insert(Insert::single_into("nested").value("id", 2).into())
+            .await
+            .unwrap();
+        innermost.commit().await.unwrap();
+        inner.commit().await.unwrap();
+
+        tx.commit().await.unwrap();
+
+        let res = connection.select(Select::from_table("nested")).await.unwrap();
+        assert_eq!(2, res.len());
+    }
+
+    #[tokio::test]
+    async fn dropping_an_open_transaction_should_roll_it_back() {
+        let connection = Sqlite::new("db/test.db").unwrap();
+        connection.raw_cmd("CREATE TABLE dropped (id INTEGER)").await.unwrap();
+
+        {
+            let tx = connection.start_transaction().await.unwrap();
+            tx.insert(Insert::single_into("dropped").value("id", 1).into())
+                .await
+                .unwrap();
+        }
+
+        let res = connection.select(Select::from_table("dropped")).await.unwrap();
+        assert!(res.is_empty());
+
+        let tx = connection.start_transaction().await.unwrap();
+        tx.insert(Insert::single_into("dropped").value("id", 1).into())
+            .await
+            .unwrap();
+
+        {
+            let inner = tx.start_transaction().await.unwrap();
+            inner
+                .insert(Insert::single_into("dropped").value("id", 2).into())
+                .await
+                .unwrap();
+        }
+
+        tx.commit().await.unwrap();
+
+        let res = connection.select(Select::from_table("dropped")).await.unwrap();
+        assert_eq!(1, res.len());
+    }
+
+    #[tokio::test]
+    async fn should_stream_rows() {
+        use futures::stream::TryStreamExt;
+
+        let connection = Sqlite::new("db/test.db").unwrap();
+
+        let stream = connection
+            .query_stream("SELECT 1 AS id UNION ALL SELECT 2", &[])
+            .await
+            .unwrap();
+
+        assert_eq!(&vec!["id".to_string()], stream.columns());
+
+        let rows: Vec<_> = stream.try_collect().await.unwrap();
+        let ids: Vec<_> = rows.iter().map(|row| row["id"].as_i64().unwrap()).collect();
+
+        assert_eq!(vec![1, 2], ids);
+    }
+
+    #[tokio::test]
+    async fn should_stream_more_rows_than_the_buffer_holds() {
+        use futures::stream::TryStreamExt;
+
+        let connection = Sqlite::new("db/test.db").unwrap();
+        let count = STREAM_BUFFER_SIZE * 10;
+
+        let sql =
+            "WITH RECURSIVE nums (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM nums WHERE n < ?) SELECT n FROM nums";
+        let params = [ParameterizedValue::from(count as i64)];
+        let stream = connection.query_stream(sql, &params).await.unwrap();
+
+        let rows: Vec<_> = stream.try_collect().await.unwrap();
+
+        assert_eq!(count, rows.len());
+        assert_eq!(Some(count as i64), rows[count - 1]["n"].as_i64());
+
+        // A stream dropped before its end releases the connection.
+        let mut stream = connection.query_stream(sql, &params).await.unwrap();
+        assert!(stream.try_next().await.unwrap().is_some());
+        drop(stream);
+
+        connection.raw_cmd("SELECT 1").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn queries_should_fail_while_a_single_connection_streams() {
+        use crate::single::Quaint;
+        use futures::stream::TryStreamExt;
+
+        let connection = Quaint::new("file:db/test.db").await.unwrap();
+        let mut stream = connection.query_stream("SELECT 1 AS id", &[]).await.unwrap();
+
+        match connection.clone().raw_cmd("SELECT 1").await.unwrap_err().kind() {
+            ErrorKind::QueryError(_) => (),
+            e => panic!("Expected `QueryError`, got {:?}", e),
+        }
+
+        assert!(stream.try_next().await.unwrap().is_some());
+        drop(stream);
+
+        connection.raw_cmd("SELECT 1").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn stream_errors_should_be_returned() {
+        let connection = Sqlite::new("db/test.db").unwrap();
+
+        assert!(connection.query_stream("SELECT * FROM nonexisting", &[]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_round_trip_blobs() {
+        let connection = Sqlite::new("db/test.db").unwrap();
+        connection.raw_cmd("CREATE TABLE blobs (data BLOB)").await.unwrap();
+
+        let data: &[u8] = &[0, 159, 146, 150, 255];
+
+        connection
+            .insert(Insert::single_into("blobs").value("data", data).into())
+            .await
+            .unwrap();
+
+        let res = connection.select(Select::from_table("blobs")).await.unwrap();
+        let row = res.get(0).unwrap();
+
+        assert_eq!(Some(data), row["data"].as_bytes());
+    }
+
+    #[tokio::test]
+    async fn invalid_utf8_text_should_return_a_conversion_error() {
+        let connection = Sqlite::new("db/test.db").unwrap();
+
+        let err = connection
+            .query_raw("SELECT CAST(x'ff' AS TEXT) AS broken", &[])
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    async fn binding_an_array_should_return_a_conversion_error() {
+        let connection = Sqlite::new("db/test.db").unwrap();
+        let array = ParameterizedValue::Array(vec![ParameterizedValue::Integer(1)]);
+
+        let err = connection.query_raw("SELECT ?", &[array]).await.unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "chrono-0_4")]
+    async fn should_store_datetimes_as_millis_by_default() {
+        use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+        let connection = Sqlite::new("db/test.db").unwrap();
+        connection
+            .raw_cmd("CREATE TABLE events (at DATETIME, day DATE, time TIME)")
+            .await
+            .unwrap();
+
+        let at: DateTime<Utc> = "2020-03-31T13:37:00.123Z".parse().unwrap();
+        let day = NaiveDate::from_ymd_opt(2020, 3, 31).unwrap();
+        let time = NaiveTime::from_hms_opt(13, 37, 0).unwrap();
+
+        let insert = Insert::single_into("events")
+            .value("at", at)
+            .value("day", day)
+            .value("time", time);
+
+        connection.insert(insert.into()).await.unwrap();
+
+        let res = connection
+            .query_raw("SELECT at, typeof(at) AS storage, day, time FROM events", &[])
+            .await
+            .unwrap();
+
+        let row = res.get(0).unwrap();
+
+        assert_eq!(Some("integer"), row["storage"].as_str());
+        assert_eq!(Some(at), row["at"].as_datetime());
+        assert_eq!(Some(day), row["day"].as_date());
+        assert_eq!(Some(time), row["time"].as_time());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "chrono-0_4")]
+    async fn should_store_datetimes_as_iso8601_text() {
+        use chrono::{DateTime, Utc};
+
+        let connection = Sqlite::new("db/test.db?datetime_format=iso8601").unwrap();
+        connection.raw_cmd("CREATE TABLE events (at DATETIME)").await.unwrap();
+
+        let at = DateTime::parse_from_rfc3339("2020-03-31T15:37:00+02:00").unwrap();
+
+        connection
+            .insert(Insert::single_into("events").value("at", at).into())
+            .await
+            .unwrap();
+
+        let res = connection
+            .query_raw("SELECT at, CAST(at AS TEXT) AS stored FROM events", &[])
+            .await
+            .unwrap();
+
+        let row = res.get(0).unwrap();
+
+        assert_eq!(Some("2020-03-31T13:37:00.000Z"), row["stored"].as_str());
+        assert_eq!(Some(at.with_timezone(&Utc)), row["at"].as_datetime());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "chrono-0_4")]
+    async fn now_should_compare_with_stored_datetimes() {
+        use chrono::{Duration, Utc};
+
+        for url in &["db/test.db", "db/test.db?datetime_format=iso8601"] {
+            let connection = Sqlite::new(url).unwrap();
+            connection.raw_cmd("CREATE TABLE events (at DATETIME)").await.unwrap();
+
+            let past = Utc::now() - Duration::hours(1);
+            let future = Utc::now() + Duration::hours(1);
+
+            let insert = Insert::multi_into("events", vec!["at"])
+                .values((past,))
+                .values((future,));
+            connection.insert(insert.into()).await.unwrap();
+
+            let query = Select::from_table("events").so_that("at".less_than(now()));
+            let rows = connection.select(query).await.unwrap();
+
+            assert_eq!(1, rows.len(), "{}", url);
+            assert_eq!(
+                Some(past.timestamp_millis()),
+                rows.get(0).unwrap()["at"].as_datetime().map(|at| at.timestamp_millis())
+            );
+        }
+    }
+
     #[allow(unused)]
     const TABLE_DEF: &str = r#"
     CREATE TABLE USER (
@@ -287,6 +994,641 @@ mod tests {
         assert_eq!(row["SALARY"].as_f64(), Some(20000.0));
     }
 
+    #[tokio::test]
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    async fn array_comparisons_should_return_an_unsupported_feature_error() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+        let comparisons = vec![
+            "tags".array_contains(Column::from("allowed_tags")),
+            "tags".array_contained_by(Column::from("allowed_tags")),
+            "tags".array_overlaps(Column::from("allowed_tags")),
+            DatabaseValue::from("cat").equals_any(Column::from("tags")),
+        ];
+
+        for comparison in comparisons {
+            let query = Select::from_table("posts").so_that(comparison);
+
+            assert_unsupported(connection.query(query.into()).await.unwrap_err(), "Array comparisons");
+        }
+    }
+
+    #[tokio::test]
+    async fn distinct_on_should_return_an_unsupported_feature_error() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+        let query = Select::default().value(1).distinct_on(vec!["id"]);
+        let err = connection.select(query).await.unwrap_err();
+
+        assert_unsupported(err, "DISTINCT ON");
+    }
+
+    #[tokio::test]
+    async fn nested_distinct_on_should_return_an_unsupported_feature_error() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+        let distinct = || Select::from_table("users").column("id").distinct_on(vec!["id"]);
+
+        let cte = Select::from_table("ids").with(With::new().query("ids", distinct()));
+        let subquery = Select::from_table("users").so_that("id".in_selection(distinct()));
+        let derived = Select::from_table(Table::from(distinct()).alias("ids"));
+        let union = Union::new(Select::default().value(1)).all(distinct());
+
+        let queries: Vec<Query> = vec![cte.into(), subquery.into(), derived.into(), union.into()];
+
+        for query in queries {
+            assert_unsupported(connection.query(query).await.unwrap_err(), "DISTINCT ON");
+        }
+    }
+
+    #[tokio::test]
+    async fn returning_should_return_an_unsupported_feature_error() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+        let query = Update::table("users").set("name", "Musti").returning(vec!["id"]);
+        let err = connection.update_returning(query).await.unwrap_err();
+
+        assert_unsupported(err, "RETURNING");
+    }
+
+    #[tokio::test]
+    async fn drop_column_should_return_an_unsupported_feature_error() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+        let err = connection
+            .execute(AlterTable::drop_column("users", "name").into())
+            .await
+            .unwrap_err();
+
+        assert_unsupported(err, "DROP COLUMN");
+    }
+
+    #[tokio::test]
+    async fn upsert_on_constraint_should_return_an_unsupported_feature_error() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+        let update = ConflictUpdate::constraint("users_pkey").set("name", "Musti");
+        let query = Insert::single_into("users").value("id", 1);
+        let err = connection
+            .insert(Insert::from(query).on_conflict(OnConflict::DoUpdate(update)))
+            .await
+            .unwrap_err();
+
+        assert_unsupported(err, "ON CONFLICT ON CONSTRAINT");
+    }
+
+    #[tokio::test]
+    async fn upsert_without_assignments_should_return_an_unsupported_feature_error() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+        let query = Insert::single_into("users").value("id", 1);
+        let update = OnConflict::DoUpdate(ConflictUpdate::columns(vec!["id"]));
+        let err = connection
+            .insert(Insert::from(query).on_conflict(update))
+            .await
+            .unwrap_err();
+
+        assert_unsupported(err, "ON CONFLICT DO UPDATE without assignments");
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "json-1")]
+    async fn should_query_in_lists_longer_than_the_parameter_limit() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+
+        connection
+            .raw_cmd("CREATE TABLE numbers (n INTEGER, name TEXT)")
+            .await
+            .unwrap();
+
+        let insert = (0..2000).fold(Insert::multi_into("numbers", vec!["n", "name"]), |insert, n| {
+            insert.values((n, format!("n{}", n)))
+        });
+        connection.insert_batched(insert).await.unwrap();
+
+        let ids: Vec<i64> = (0..1000).map(|n| n * 2).collect();
+        let matching = |conditions| {
+            let query = Select::from_table("numbers")
+                .value(count(asterisk()))
+                .so_that(conditions);
+            let connection = &connection;
+
+            async move { connection.select(query).await.unwrap().into_single().unwrap()[0].as_i64() }
+        };
+
+        assert_eq!(Some(1000), matching("n".in_selection(ids.clone())).await);
+        assert_eq!(Some(1000), matching("n".not_in_selection(ids)).await);
+
+        let names: Vec<String> = (0..1000).map(|n| format!("n{}", n)).collect();
+        assert_eq!(Some(1000), matching("name".in_selection(names)).await);
+    }
+
+    #[tokio::test]
+    async fn should_query_unions_of_limited_selects() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+        connection.raw_cmd("CREATE TABLE nums (n INTEGER)").await.unwrap();
+
+        let insert = Insert::multi_into("nums", vec!["n"])
+            .values((1,))
+            .values((2,))
+            .values((3,));
+        connection.insert(insert.into()).await.unwrap();
+
+        let largest = Select::from_table("nums").column("n").order_by("n".descend()).limit(1);
+        let smallest = Select::from_table("nums").column("n").order_by("n".ascend()).limit(1);
+
+        let rows = connection
+            .query(Union::new(largest).all(smallest).into())
+            .await
+            .unwrap();
+        let nums: Vec<_> = rows.into_iter().map(|row| row["n"].as_i64()).collect();
+
+        assert_eq!(vec![Some(3), Some(1)], nums);
+    }
+
+    #[tokio::test]
+    async fn should_query_a_recursive_cte() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+
+        let start = Select::default().value(1);
+        let step = Select::from_table("nums")
+            .value(col!("n") + val!(1))
+            .so_that("n".less_than(3));
+
+        let nums = CommonTableExpression::new("nums", Union::new(start).all(step)).columns(vec!["n"]);
+        let query = Select::from_table("nums").with(With::new().recursive().cte(nums));
+
+        let rows = connection.select(query).await.unwrap();
+        let values: Vec<_> = rows.into_iter().map(|row| row["n"].as_i64()).collect();
+
+        assert_eq!(vec![Some(1), Some(2), Some(3)], values);
+    }
+
+    #[tokio::test]
+    async fn should_compute_functions() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+
+        connection
+            .raw_cmd("CREATE TABLE scores (name TEXT, nickname TEXT, score INTEGER)")
+            .await
+            .unwrap();
+
+        let insert = Insert::multi_into("scores", vec!["name", "nickname", "score"])
+            .values(("Musti", "Mus", -2))
+            .values(("Naukio", DatabaseValue::from(ParameterizedValue::Null), 6));
+
+        connection.insert(insert.into()).await.unwrap();
+
+        let aggregates = Select::from_table("scores")
+            .value(Function::from(sum(Column::new("score"))).alias("sum"))
+            .value(Function::from(avg(Column::new("score"))).alias("avg"))
+            .value(Function::from(min(Column::new("score"))).alias("min"))
+            .value(Function::from(max(Column::new("score"))).alias("max"))
+            .value(Function::from(count(Column::new("nickname")).distinct()).alias("nicknames"));
+
+        let row = connection.select(aggregates).await.unwrap().into_single().unwrap();
+
+        assert_eq!(Some(4), row["sum"].as_i64());
+        assert_eq!(Some(2.0), row["avg"].as_f64());
+        assert_eq!(Some(-2), row["min"].as_i64());
+        assert_eq!(Some(6), row["max"].as_i64());
+        assert_eq!(Some(1), row["nicknames"].as_i64());
+
+        let names = vec![DatabaseValue::from(Column::new("nickname")), Column::new("name").into()];
+
+        let scalars = Select::from_table("scores")
+            .value(Function::from(coalesce(names)).alias("nickname"))
+            .value(Function::from(lower(Column::new("name"))).alias("lower"))
+            .value(Function::from(upper(Column::new("name"))).alias("upper"))
+            .value(Function::from(length(Column::new("name"))).alias("length"))
+            .value(Function::from(abs(Column::new("score"))).alias("abs"))
+            .value(Function::from(concat(vec![Column::new("name"), Column::new("score")])).alias("concat"))
+            .value(Function::from(cast(Column::new("score"), CastType::Text)).alias("text"))
+            .order_by("name");
+
+        let row = connection.select(scalars).await.unwrap().into_iter().next().unwrap();
+
+        assert_eq!(Some("Mus"), row["nickname"].as_str());
+        assert_eq!(Some("musti"), row["lower"].as_str());
+        assert_eq!(Some("MUSTI"), row["upper"].as_str());
+        assert_eq!(Some(5), row["length"].as_i64());
+        assert_eq!(Some(2), row["abs"].as_i64());
+        assert_eq!(Some("Musti-2"), row["concat"].as_str());
+        assert_eq!(Some("-2"), row["text"].as_str());
+    }
+
+    #[tokio::test]
+    async fn should_compute_window_functions() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+
+        connection
+            .raw_cmd("CREATE TABLE games (player TEXT, round INTEGER, points INTEGER)")
+            .await
+            .unwrap();
+
+        let insert = Insert::multi_into("games", vec!["player", "round", "points"])
+            .values(("Musti", 1, 10))
+            .values(("Musti", 2, 30))
+            .values(("Musti", 3, 20))
+            .values(("Naukio", 1, 30));
+
+        connection.insert(insert.into()).await.unwrap();
+
+        let running = Over::window("player_rounds").rows(FrameBound::UnboundedPreceding, FrameBound::CurrentRow);
+
+        let query = Select::from_table("games")
+            .columns(vec!["player", "round"])
+            .value(sum(Column::new("points")).over(running).alias("total"))
+            .value(
+                Function::from(
+                    lag(Column::new("points"))
+                        .default(0)
+                        .over(Over::window("player_rounds")),
+                )
+                .alias("previous"),
+            )
+            .value(Function::from(dense_rank().order_by("points".descend())).alias("rank"))
+            .window("player_rounds", Over::new().partition_by("player").order_by("round"))
+            .order_by("player")
+            .order_by("round");
+
+        let rows = connection.select(query).await.unwrap();
+
+        let values: Vec<_> = rows
+            .into_iter()
+            .map(|row| (row["total"].as_i64(), row["previous"].as_i64(), row["rank"].as_i64()))
+            .collect();
+
+        let expected = vec![
+            (Some(10), Some(0), Some(3)),
+            (Some(40), Some(10), Some(1)),
+            (Some(60), Some(30), Some(2)),
+            (Some(30), Some(0), Some(1)),
+        ];
+
+        assert_eq!(expected, values);
+    }
+
+    #[tokio::test]
+    async fn should_update_and_order_with_case_expressions() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+
+        connection
+            .raw_cmd("CREATE TABLE tickets (id INTEGER, status TEXT, priority INTEGER)")
+            .await
+            .unwrap();
+
+        let insert = Insert::multi_into("tickets", vec!["id", "status", "priority"])
+            .values((1, "normal", 0))
+            .values((2, "urgent", 0))
+            .values((3, "closed", 0));
+
+        connection.insert(insert.into()).await.unwrap();
+
+        let priority = case()
+            .when("status".equals("urgent"), 2)
+            .when("status".equals("normal"), 1)
+            .otherwise(Column::from("priority"));
+
+        let update = Update::table("tickets").set("priority", priority);
+        connection.update(update).await.unwrap();
+
+        let open_first = case().when("status".equals("closed"), 1).otherwise(0);
+
+        let query = Select::from_table("tickets")
+            .columns(vec!["id", "priority"])
+            .order_by(open_first.ascend())
+            .order_by("priority".descend());
+
+        let rows = connection.select(query).await.unwrap();
+
+        let values: Vec<_> = rows
+            .into_iter()
+            .map(|row| (row["id"].as_i64(), row["priority"].as_i64()))
+            .collect();
+
+        assert_eq!(vec![(Some(2), Some(2)), (Some(1), Some(1)), (Some(3), Some(0))], values);
+    }
+
+    #[tokio::test]
+    async fn should_emulate_quantified_comparisons() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+
+        connection
+            .raw_cmd("CREATE TABLE prices (price INTEGER); INSERT INTO prices (price) VALUES (10), (20), (NULL)")
+            .await
+            .unwrap();
+
+        let check = |condition: Compare<'static>| {
+            let query = Select::default().value(case().when(condition, true).otherwise(false));
+            let connection = &connection;
+
+            async move {
+                let row = connection.select(query).await.unwrap().into_single().unwrap();
+                row[0].as_bool().unwrap()
+            }
+        };
+
+        let prices = || Select::from_table("prices").column("price");
+
+        assert!(check(DatabaseValue::from(15).any(ComparisonOperator::GreaterThan, prices())).await);
+        assert!(!check(DatabaseValue::from(5).any(ComparisonOperator::GreaterThan, prices())).await);
+        assert!(check(DatabaseValue::from(20).any(ComparisonOperator::Equals, prices())).await);
+        assert!(!check(DatabaseValue::from(30).all(ComparisonOperator::GreaterThan, prices())).await);
+
+        let known = || prices().so_that("price".is_not_null());
+
+        assert!(check(DatabaseValue::from(30).all(ComparisonOperator::GreaterThan, known())).await);
+        assert!(!check(DatabaseValue::from(15).all(ComparisonOperator::GreaterThan, known())).await);
+        assert!(check(exists(known())).await);
+        assert!(check(not_exists(prices().so_that("price".greater_than(100)))).await);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "json-1")]
+    async fn should_query_json_documents() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+
+        connection
+            .raw_cmd("CREATE TABLE documents (id INTEGER, data TEXT)")
+            .await
+            .unwrap();
+
+        let insert = Insert::multi_into("documents", vec!["id", "data"])
+            .values((
+                1,
+                serde_json::json!({ "name": "Musti", "tags": ["cat", "black"], "owner": { "name": "Julius" } }),
+            ))
+            .values((
+                2,
+                serde_json::json!({ "name": "Naukio", "tags": ["cat"], "email": null }),
+            ));
+
+        connection.insert(insert.into()).await.unwrap();
+
+        let query = Select::from_table("documents")
+            .value(Column::from("data").json_get_text("name"))
+            .value(Column::from("data").json_get_path(vec!["owner", "name"]))
+            .value(Column::from("data").json_get("tags"))
+            .so_that("data".json_contains(serde_json::json!({ "tags": ["black"] })));
+
+        let row = connection.select(query).await.unwrap().into_single().unwrap();
+
+        assert_eq!(Some("Musti"), row[0].as_str());
+        assert_eq!(Some("Julius"), row[1].as_str());
+        assert_eq!(Some(r#"["cat","black"]"#), row[2].as_str());
+
+        let ids = |condition: Compare<'static>| {
+            let query = Select::from_table("documents")
+                .column("id")
+                .so_that(condition)
+                .order_by("id");
+            let connection = &connection;
+
+            async move {
+                let rows = connection.select(query).await.unwrap();
+                rows.into_iter()
+                    .map(|row| row["id"].as_i64().unwrap())
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(vec![2], ids("data".json_has_key("email")).await);
+        assert_eq!(vec![1, 2], ids("data".json_has_any_key(vec!["email", "owner"])).await);
+        assert_eq!(
+            vec![1, 2],
+            ids("data".json_contains(serde_json::json!({ "tags": "cat" }))).await
+        );
+        assert!(ids("data".json_contains(serde_json::json!({ "owner": {} }))).await == vec![1]);
+    }
+
+    #[tokio::test]
+    async fn should_insert_in_batches() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+
+        connection
+            .raw_cmd("CREATE TABLE batched_numbers (a INTEGER, b INTEGER)")
+            .await
+            .unwrap();
+
+        let insert = (0..1200).fold(Insert::multi_into("batched_numbers", vec!["a", "b"]), |insert, i| {
+            insert.values((i, i * 2))
+        });
+
+        let too_many_parameters = connection.insert(insert.build()).await;
+        assert!(too_many_parameters.is_err());
+
+        let insert = (0..1200).fold(Insert::multi_into("batched_numbers", vec!["a", "b"]), |insert, i| {
+            insert.values((i, i * 2))
+        });
+
+        assert_eq!(1200, connection.insert_batched(insert).await.unwrap());
+
+        let query = Select::from_table("batched_numbers")
+            .value(count(asterisk()))
+            .value(sum(Column::from("b")));
+
+        let row = connection.select(query).await.unwrap().into_single().unwrap();
+
+        assert_eq!(Some(1200), row[0].as_i64());
+        assert_eq!(Some(1199 * 1200), row[1].as_i64());
+    }
+
+    #[tokio::test]
+    async fn should_bulk_load_rows() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+
+        connection
+            .raw_cmd("CREATE TABLE loaded_numbers (a INTEGER, b TEXT)")
+            .await
+            .unwrap();
+
+        let rows = futures::stream::iter((0..1500).map(|i| vec![ParameterizedValue::from(i), format!("{}", i).into()]));
+        let loaded = connection
+            .bulk_load("loaded_numbers", vec!["a", "b"], rows)
+            .await
+            .unwrap();
+
+        assert_eq!(1500, loaded);
+
+        let query = Select::from_table("loaded_numbers")
+            .value(count(asterisk()))
+            .value(sum(Column::from("a")));
+
+        let row = connection.select(query).await.unwrap().into_single().unwrap();
+
+        assert_eq!(Some(1500), row[0].as_i64());
+        assert_eq!(Some(1499 * 1500 / 2), row[1].as_i64());
+    }
+
+    #[tokio::test]
+    async fn should_describe_the_schema() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+
+        connection
+            .raw_cmd(
+                "CREATE TABLE owners (id INTEGER PRIMARY KEY, email TEXT NOT NULL UNIQUE); \
+                 CREATE TABLE cats (owner_id INTEGER NOT NULL REFERENCES owners, number INTEGER NOT NULL, \
+                     name TEXT DEFAULT 'Musti', PRIMARY KEY (owner_id, number)); \
+                 CREATE INDEX cats_name ON cats (name, number);",
+            )
+            .await
+            .unwrap();
+
+        let schema = connection.describe_schema("main").await.unwrap();
+        let cats = schema.table("cats").unwrap();
+
+        assert_eq!(vec!["owner_id", "number"], cats.primary_key);
+
+        assert_eq!(
+            &ColumnDescription {
+                name: "name".into(),
+                data_type: "TEXT".into(),
+                nullable: true,
+                default: Some("'Musti'".into()),
+            },
+            cats.column("name").unwrap()
+        );
+
+        assert!(!cats.column("number").unwrap().nullable);
+
+        assert_eq!(
+            vec![IndexDescription {
+                name: "cats_name".into(),
+                columns: vec!["name".into(), "number".into()],
+                unique: false,
+            }],
+            cats.indexes
+        );
+
+        assert_eq!(
+            vec![ForeignKeyDescription {
+                name: None,
+                columns: vec!["owner_id".into()],
+                referenced_table: "owners".into(),
+                referenced_columns: vec!["id".into()],
+            }],
+            cats.foreign_keys
+        );
+
+        let owners = schema.table("owners").unwrap();
+
+        assert_eq!(vec!["id"], owners.primary_key);
+        assert_eq!(1, owners.indexes.len());
+        assert!(owners.indexes[0].unique);
+        assert_eq!(vec!["email"], owners.indexes[0].columns);
+        assert!(schema.enums.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_change_the_schema() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+
+        let authors = CreateTable::new("ddl_authors")
+            .column(
+                ColumnDefinition::new("id", ColumnType::Integer)
+                    .primary_key()
+                    .auto_increment(),
+            )
+            .column(
+                ColumnDefinition::new("name", ColumnType::Text)
+                    .not_null()
+                    .default("Musti"),
+            );
+
+        let books = CreateTable::new("ddl_books")
+            .column(ColumnDefinition::new("author_id", ColumnType::Integer).not_null())
+            .column(ColumnDefinition::new("title", ColumnType::Text).not_null())
+            .primary_key(vec!["author_id", "title"])
+            .foreign_key(ForeignKey::new(vec!["author_id"], "ddl_authors", vec!["id"]));
+
+        connection.execute(authors.into()).await.unwrap();
+        connection.execute(books.into()).await.unwrap();
+
+        let rename = AlterTable::rename_column("ddl_authors", "name", "full_name");
+        connection.execute(rename.into()).await.unwrap();
+
+        let index = CreateIndex::new("ddl_authors_full_name", "ddl_authors", vec!["full_name"])
+            .unique()
+            .so_that("full_name".not_equals("Musti"));
+        connection.execute(index.into()).await.unwrap();
+
+        let insert = Insert::single_into("ddl_authors").build();
+        connection.execute(insert.clone().into()).await.unwrap();
+        connection.execute(insert.into()).await.unwrap();
+
+        let rows = connection
+            .query(Select::from_table("ddl_authors").order_by("id").into())
+            .await
+            .unwrap();
+
+        let row = rows.get(1).unwrap();
+
+        assert_eq!(Some(2), row["id"].as_i64());
+        assert_eq!(Some("Musti"), row["full_name"].as_str());
+
+        let schema = connection.describe_schema("main").await.unwrap();
+        let books = schema.table("ddl_books").unwrap();
+
+        assert_eq!(vec!["author_id", "title"], books.primary_key);
+        assert_eq!("ddl_authors", books.foreign_keys[0].referenced_table);
+
+        let authors = schema.table("ddl_authors").unwrap();
+
+        assert_eq!(Some("'Musti'"), authors.column("full_name").unwrap().default.as_deref());
+        assert!(authors
+            .indexes
+            .iter()
+            .any(|index| index.name == "ddl_authors_full_name"));
+
+        let drop_index = DropIndex::new("ddl_authors_full_name", "ddl_authors");
+        connection.execute(drop_index.into()).await.unwrap();
+
+        connection.execute(DropTable::new("ddl_books").into()).await.unwrap();
+        connection
+            .execute(DropTable::new("ddl_books").if_exists().into())
+            .await
+            .unwrap();
+
+        let schema = connection.describe_schema("main").await.unwrap();
+
+        assert!(schema.table("ddl_books").is_none());
+        assert!(schema.table("ddl_authors").unwrap().indexes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_match_patterns() {
+        let connection = Sqlite::try_from("file:db/test.db").unwrap();
+
+        connection
+            .raw_cmd("CREATE TABLE products (id INTEGER, name TEXT)")
+            .await
+            .unwrap();
+
+        let insert = Insert::multi_into("products", vec!["id", "name"])
+            .values((1, "50% OFF Shoes"))
+            .values((2, "500 shoes"))
+            .values((3, "Hats_2020"))
+            .values((4, DatabaseValue::from(ParameterizedValue::Null)));
+
+        connection.insert(insert.into()).await.unwrap();
+
+        let ids = |condition: Compare<'static>| {
+            let query = Select::from_table("products")
+                .column("id")
+                .so_that(condition)
+                .order_by("id");
+
+            let connection = &connection;
+
+            async move {
+                let rows = connection.select(query).await.unwrap();
+                rows.into_iter()
+                    .map(|row| row["id"].as_i64().unwrap())
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(vec![1], ids("name".like("50%")).await);
+        assert_eq!(vec![3], ids("name".ends_into("_2020")).await);
+        assert_eq!(vec![1, 2], ids("name".ilike("SHOES")).await);
+        assert_eq!(vec![3], ids("name".not_ilike("shoes")).await);
+        assert_eq!(vec![2, 3], ids("name".matches_regex("^[0-9]+ |_[0-9]+$")).await);
+    }
+
     #[tokio::test]
     async fn op_test_add_one_level() {
         let connection = Sqlite::try_from("file:db/test.db").unwrap();
@@ -1,21 +1,87 @@
+use super::DateTimeFormat;
 use crate::{
     ast::ParameterizedValue,
     connector::queryable::{GetRow, ToColumnNames},
+    error::{Error, ErrorKind},
 };
+#[cfg(feature = "chrono-0_4")]
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, TimeZone, Utc};
 use rusqlite::{
-    types::{Null, ToSql, ToSqlOutput, ValueRef},
+    types::{Null, ToSql, ToSqlOutput, Value, ValueRef},
     Error as RusqlError, Row as SqliteRow, Rows as SqliteRows,
 };
 use rust_decimal::prelude::ToPrimitive;
 
+#[cfg(feature = "chrono-0_4")]
+const DATE_FORMAT: &str = "%Y-%m-%d";
+#[cfg(feature = "chrono-0_4")]
+const TIME_FORMAT: &str = "%H:%M:%S%.f";
+
+/// Converts the parameters for binding, writing datetimes in the given format.
+pub fn conv_params<'a>(
+    params: &'a [ParameterizedValue<'a>],
+    datetime_format: DateTimeFormat,
+) -> Result<Vec<ToSqlOutput<'a>>, RusqlError> {
+    params
+        .iter()
+        .map(|param| match (datetime_format, param) {
+            #[cfg(feature = "chrono-0_4")]
+            (DateTimeFormat::Iso8601, ParameterizedValue::DateTime(value)) => Ok(ToSqlOutput::from(iso8601(value))),
+            #[cfg(feature = "chrono-0_4")]
+            (DateTimeFormat::Iso8601, ParameterizedValue::DateTimeTz(value)) => {
+                Ok(ToSqlOutput::from(iso8601(&value.with_timezone(&Utc))))
+            }
+            _ => param.to_sql(),
+        })
+        .collect()
+}
+
+/// Converts the parameters into owned values, for binding on another thread.
+pub fn conv_params_owned(
+    params: &[ParameterizedValue<'_>],
+    datetime_format: DateTimeFormat,
+) -> Result<Vec<Value>, RusqlError> {
+    conv_params(params, datetime_format)?
+        .into_iter()
+        .map(|param| match param {
+            ToSqlOutput::Borrowed(value) => Ok(Value::from(value)),
+            ToSqlOutput::Owned(value) => Ok(value),
+        })
+        .collect()
+}
+
+/// A binding error carrying our own error, unwrapped again when the rusqlite
+/// error is converted.
+fn conversion_failure(message: &'static str) -> RusqlError {
+    let error = Error::builder(ErrorKind::ConversionError(message)).build();
+    RusqlError::ToSqlConversionFailure(Box::new(error))
+}
+
+/// UTC with a fixed number of fractional digits, so the text sorts in the
+/// same order as the datetimes.
+#[cfg(feature = "chrono-0_4")]
+fn iso8601(value: &DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+#[cfg(feature = "chrono-0_4")]
+fn parse_datetime(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").map(|dt| Utc.from_utc_datetime(&dt)))
+        .ok()
+}
+
 impl<'a> GetRow for SqliteRow<'a> {
     fn get_result_row<'b>(&'b self) -> crate::Result<Vec<ParameterizedValue<'static>>> {
         let mut row = Vec::with_capacity(self.columns().len());
 
         for (i, column) in self.columns().iter().enumerate() {
+            let decl_type = column.decl_type().map(|t| t.to_uppercase());
+
             let pv = match self.get_raw(i) {
                 ValueRef::Null => ParameterizedValue::Null,
-                ValueRef::Integer(i) => match column.decl_type() {
+                ValueRef::Integer(i) => match decl_type.as_deref() {
                     Some("BOOLEAN") => {
                         if i == 0 {
                             ParameterizedValue::Boolean(false)
@@ -23,11 +89,37 @@ impl<'a> GetRow for SqliteRow<'a> {
                             ParameterizedValue::Boolean(true)
                         }
                     }
+                    #[cfg(feature = "chrono-0_4")]
+                    Some("DATETIME") | Some("TIMESTAMP") => match Utc.timestamp_millis_opt(i).single() {
+                        Some(dt) => ParameterizedValue::DateTime(dt),
+                        None => ParameterizedValue::Integer(i),
+                    },
                     _ => ParameterizedValue::Integer(i),
                 },
                 ValueRef::Real(f) => ParameterizedValue::from(f),
-                ValueRef::Text(bytes) => ParameterizedValue::Text(String::from_utf8(bytes.to_vec())?.into()),
-                ValueRef::Blob(_) => panic!("Blobs not supported, yet"),
+                ValueRef::Text(bytes) => {
+                    let s = String::from_utf8(bytes.to_vec())?;
+
+                    match decl_type.as_deref() {
+                        #[cfg(feature = "chrono-0_4")]
+                        Some("DATETIME") | Some("TIMESTAMP") => match parse_datetime(&s) {
+                            Some(dt) => ParameterizedValue::DateTime(dt),
+                            None => ParameterizedValue::Text(s.into()),
+                        },
+                        #[cfg(feature = "chrono-0_4")]
+                        Some("DATE") => match NaiveDate::parse_from_str(&s, DATE_FORMAT) {
+                            Ok(date) => ParameterizedValue::Date(date),
+                            Err(_) => ParameterizedValue::Text(s.into()),
+                        },
+                        #[cfg(feature = "chrono-0_4")]
+                        Some("TIME") => match NaiveTime::parse_from_str(&s, TIME_FORMAT) {
+                            Ok(time) => ParameterizedValue::Time(time),
+                            Err(_) => ParameterizedValue::Text(s.into()),
+                        },
+                        _ => ParameterizedValue::Text(s.into()),
+                    }
+                }
+                ValueRef::Blob(bytes) => ParameterizedValue::Bytes(bytes.to_vec().into()),
             };
 
             row.push(pv);
@@ -51,13 +143,17 @@ impl<'a> ToSql for ParameterizedValue<'a> {
         let value = match self {
             ParameterizedValue::Null => ToSqlOutput::from(Null),
             ParameterizedValue::Integer(integer) => ToSqlOutput::from(*integer),
-            ParameterizedValue::Real(d) => ToSqlOutput::from((*d).to_f64().expect("Decimal is not a f64.")),
+            ParameterizedValue::Real(d) => {
+                let f = d.to_f64().ok_or_else(|| conversion_failure("Decimal is not a f64."))?;
+                ToSqlOutput::from(f)
+            }
             ParameterizedValue::Text(cow) => ToSqlOutput::from(&**cow),
             ParameterizedValue::Enum(cow) => ToSqlOutput::from(&**cow),
             ParameterizedValue::Boolean(boo) => ToSqlOutput::from(*boo),
             ParameterizedValue::Char(c) => ToSqlOutput::from(*c as u8),
+            ParameterizedValue::Bytes(bytes) => ToSqlOutput::from(&**bytes),
             #[cfg(feature = "array")]
-            ParameterizedValue::Array(_) => unimplemented!("Arrays are not supported for sqlite."),
+            ParameterizedValue::Array(_) => return Err(conversion_failure("Arrays are not supported for SQLite.")),
             #[cfg(feature = "json-1")]
             ParameterizedValue::Json(value) => {
                 let stringified =
@@ -68,6 +164,12 @@ impl<'a> ToSql for ParameterizedValue<'a> {
             ParameterizedValue::Uuid(value) => ToSqlOutput::from(value.to_hyphenated().to_string()),
             #[cfg(feature = "chrono-0_4")]
             ParameterizedValue::DateTime(value) => ToSqlOutput::from(value.timestamp_millis()),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::DateTimeTz(value) => ToSqlOutput::from(value.timestamp_millis()),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Date(value) => ToSqlOutput::from(value.format(DATE_FORMAT).to_string()),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Time(value) => ToSqlOutput::from(value.format(TIME_FORMAT).to_string()),
         };
 
         Ok(value)
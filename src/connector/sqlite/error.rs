@@ -106,7 +106,7 @@ impl From<rusqlite::Error> for Error {
                 },
                 description,
             ) => {
-                let mut builder = Error::builder(ErrorKind::Timeout("SQLite database is busy".into()));
+                let mut builder = Error::builder(ErrorKind::LockTimeout("SQLite database is busy".into()));
                 builder.set_original_code(format!("{}", extended_code));
 
                 if let Some(description) = description {
@@ -127,6 +127,11 @@ impl From<rusqlite::Error> for Error {
 
                 builder.build()
             }
+            rusqlite::Error::ToSqlConversionFailure(e) => match e.downcast::<Error>() {
+                Ok(error) => *error,
+                Err(e) => Error::builder(ErrorKind::QueryError(e)).build(),
+            },
+
             e => Error::builder(ErrorKind::QueryError(e.into())).build(),
         }
     }
@@ -0,0 +1,131 @@
+use crate::{
+    ast::ParameterizedValue,
+    connector::{
+        introspect::{
+            bool_value, string_value, ColumnDescription, ForeignKeyDescription, SchemaBuilder, SchemaDescription,
+        },
+        Queryable,
+    },
+};
+
+const COLUMNS: &str = r#"SELECT name, type, "notnull", dflt_value, pk FROM pragma_table_info(?, ?) ORDER BY cid"#;
+
+const INDEXES: &str = r#"SELECT name, "unique", origin FROM pragma_index_list(?, ?) ORDER BY name"#;
+
+const INDEX_COLUMNS: &str = r#"SELECT name FROM pragma_index_info(?, ?) ORDER BY seqno"#;
+
+const FOREIGN_KEYS: &str = r#"SELECT id, "table", "from", "to" FROM pragma_foreign_key_list(?, ?) ORDER BY id, seq"#;
+
+/// Describes an attached database with the table-valued pragma functions.
+pub(crate) async fn describe<Q>(conn: &Q, schema: &str) -> crate::Result<SchemaDescription>
+where
+    Q: Queryable + ?Sized,
+{
+    let tables_sql = format!(
+        "SELECT name FROM \"{}\".sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+        schema.replace('"', "\"\"")
+    );
+
+    let mut builder = SchemaBuilder::default();
+    let mut tables = Vec::new();
+
+    for row in conn.query_raw(&tables_sql, &[]).await? {
+        tables.push(string_value(&row[0]).unwrap_or_default());
+    }
+
+    for name in tables {
+        builder.add_table(name.clone());
+
+        let params = [
+            ParameterizedValue::from(name.as_str()),
+            ParameterizedValue::from(schema),
+        ];
+        let mut primary_key = Vec::new();
+
+        for row in conn.query_raw(COLUMNS, &params).await? {
+            let column = string_value(&row[0]).unwrap_or_default();
+
+            if let Some(position) = row[4].as_i64().filter(|pk| *pk > 0) {
+                primary_key.push((position, column.clone()));
+            }
+
+            builder.table(&name).columns.push(ColumnDescription {
+                name: column,
+                data_type: string_value(&row[1]).unwrap_or_default(),
+                nullable: !bool_value(&row[2]),
+                default: string_value(&row[3]),
+            });
+        }
+
+        primary_key.sort();
+        builder.table(&name).primary_key = primary_key.into_iter().map(|(_, column)| column).collect();
+
+        for row in conn.query_raw(INDEXES, &params).await? {
+            let index = string_value(&row[0]).unwrap_or_default();
+
+            if string_value(&row[2]).as_deref() == Some("pk") {
+                continue;
+            }
+
+            let index_params = [
+                ParameterizedValue::from(index.as_str()),
+                ParameterizedValue::from(schema),
+            ];
+            let unique = bool_value(&row[1]);
+
+            for column in conn.query_raw(INDEX_COLUMNS, &index_params).await? {
+                // Expressions have no column name.
+                if let Some(column) = string_value(&column[0]) {
+                    builder.add_index_column(&name, index.clone(), column, unique);
+                }
+            }
+        }
+
+        let mut last_id = None;
+
+        for row in conn.query_raw(FOREIGN_KEYS, &params).await? {
+            let foreign_keys = &mut builder.table(&name).foreign_keys;
+            let id = row[0].as_i64();
+
+            if last_id != Some(id) {
+                last_id = Some(id);
+
+                foreign_keys.push(ForeignKeyDescription {
+                    name: None,
+                    referenced_table: string_value(&row[1]).unwrap_or_default(),
+                    ..Default::default()
+                });
+            }
+
+            if let Some(foreign_key) = foreign_keys.last_mut() {
+                foreign_key.columns.push(string_value(&row[2]).unwrap_or_default());
+
+                // Without the referenced columns the key refers to the
+                // primary key, filled in when all tables are read.
+                if let Some(referenced_column) = string_value(&row[3]) {
+                    foreign_key.referenced_columns.push(referenced_column);
+                }
+            }
+        }
+    }
+
+    let mut description = builder.build();
+
+    let primary_keys: Vec<_> = description
+        .tables
+        .iter()
+        .map(|table| (table.name.clone(), table.primary_key.clone()))
+        .collect();
+
+    for table in description.tables.iter_mut() {
+        for foreign_key in table.foreign_keys.iter_mut() {
+            if foreign_key.referenced_columns.is_empty() {
+                if let Some((_, primary_key)) = primary_keys.iter().find(|(t, _)| *t == foreign_key.referenced_table) {
+                    foreign_key.referenced_columns = primary_key.clone();
+                }
+            }
+        }
+    }
+
+    Ok(description)
+}
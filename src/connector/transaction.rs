@@ -1,41 +1,141 @@
+mod options;
+
+pub use options::*;
+
 use super::*;
 use crate::ast::*;
 
 /// A representation of an SQL database transaction. If not commited, a
 /// transaction will be rolled back by default when dropped.
 ///
-/// Currently does not support nesting, so starting a new transaction using the
-/// transaction object will panic.
+/// Rolling back on drop can't block, so the connection rolls back before
+/// running its next query, and a pooled connection dropping its outermost
+/// transaction is closed when returned to the pool. The transaction should
+/// still be finished explicitly with [commit](#method.commit) or
+/// [rollback](#method.rollback) to see the errors.
+///
+/// Starting a new transaction using the transaction object creates a nested
+/// transaction using savepoints. Committing the nested transaction releases
+/// the savepoint and rolling it back only reverts the changes done after the
+/// nested transaction was started. The [options](struct.TransactionOptions.html)
+/// of a nested transaction are ignored, it always shares the settings of the
+/// outermost transaction.
 pub struct Transaction<'a> {
     pub(crate) inner: &'a dyn Queryable,
+    depth: u32,
+    open: bool,
 }
 
 impl<'a> Transaction<'a> {
-    pub(crate) async fn new(inner: &'a dyn Queryable) -> crate::Result<Transaction<'a>> {
-        inner.raw_cmd("BEGIN").await?;
-        Ok(Self { inner })
+    pub(crate) async fn new(inner: &'a dyn Queryable, options: TransactionOptions) -> crate::Result<Transaction<'a>> {
+        for statement in options.begin_statements(inner.sql_family()) {
+            inner.raw_cmd(&statement).await?;
+        }
+
+        Ok(Self {
+            inner,
+            depth: 0,
+            open: true,
+        })
+    }
+
+    pub(crate) async fn savepoint(inner: &'a dyn Queryable, depth: u32) -> crate::Result<Transaction<'a>> {
+        inner
+            .raw_cmd(&format!("SAVEPOINT {}", Self::savepoint_name(depth)))
+            .await?;
+
+        Ok(Self {
+            inner,
+            depth,
+            open: true,
+        })
+    }
+
+    fn savepoint_name(depth: u32) -> String {
+        format!("quaint_savepoint_{}", depth)
+    }
+
+    fn rollback_cmd(&self) -> String {
+        match self.depth {
+            0 => String::from("ROLLBACK"),
+            // Rolling back to a savepoint keeps it, releasing it ends the
+            // nested transaction.
+            depth => format!(
+                "ROLLBACK TO SAVEPOINT {0}; RELEASE SAVEPOINT {0}",
+                Self::savepoint_name(depth)
+            ),
+        }
+    }
+
+    /// The nesting level of the transaction, `0` being the outermost
+    /// transaction.
+    pub fn depth(&self) -> u32 {
+        self.depth
     }
 
     /// Commit the changes to the database and consume the transaction.
-    pub async fn commit(&self) -> crate::Result<()> {
-        self.inner.raw_cmd("COMMIT").await?;
+    pub async fn commit(mut self) -> crate::Result<()> {
+        let cmd = match self.depth {
+            0 => String::from("COMMIT"),
+            depth => format!("RELEASE SAVEPOINT {}", Self::savepoint_name(depth)),
+        };
+
+        self.inner.raw_cmd(&cmd).await?;
+        self.open = false;
 
         Ok(())
     }
 
-    /// Rolls back the changes to the database.
-    pub async fn rollback(&self) -> crate::Result<()> {
-        self.inner.raw_cmd("ROLLBACK").await?;
+    /// Rolls back the changes to the database and consume the transaction.
+    pub async fn rollback(mut self) -> crate::Result<()> {
+        self.inner.raw_cmd(&self.rollback_cmd()).await?;
+        self.open = false;
 
         Ok(())
     }
 }
 
+impl<'a> Drop for Transaction<'a> {
+    fn drop(&mut self) {
+        if self.open {
+            self.inner.transaction_dropped(self.depth, &self.rollback_cmd());
+        }
+    }
+}
+
+/// The rollback of a transaction dropped without blocking, run by the
+/// connection before its next query.
+#[derive(Debug, Default)]
+pub(crate) struct PendingRollback(std::sync::Mutex<Option<String>>);
+
+impl PendingRollback {
+    pub(crate) fn set(&self, rollback: &str) {
+        *self.0.lock().unwrap() = Some(rollback.to_string());
+    }
+
+    pub(crate) fn take(&self) -> Option<String> {
+        self.0.lock().unwrap().take()
+    }
+}
+
+impl<'a> TransactionCapable for Transaction<'a> {
+    /// Starts a nested transaction by creating a new savepoint.
+    fn start_transaction_with(&self, _: TransactionOptions) -> DBIO<Transaction> {
+        DBIO::new(async move { Transaction::savepoint(self.inner, self.depth + 1).await })
+    }
+}
+
+impl<'a> Introspect for Transaction<'a> {}
+
 impl<'a> Queryable for Transaction<'a> {
     fn query<'b>(&'b self, q: Query<'b>) -> DBIO<'b, ResultSet> {
         self.inner.query(q)
     }
 
+    fn query_stream<'b>(&'b self, sql: &'b str, params: &'b [ParameterizedValue<'b>]) -> DBIO<'b, ResultStream<'b>> {
+        self.inner.query_stream(sql, params)
+    }
+
     fn execute<'b>(&'b self, q: Query<'b>) -> DBIO<'b, u64> {
         self.inner.execute(q)
     }
@@ -51,4 +151,12 @@ impl<'a> Queryable for Transaction<'a> {
     fn raw_cmd<'b>(&'b self, cmd: &'b str) -> DBIO<'b, ()> {
         self.inner.raw_cmd(cmd)
     }
+
+    fn sql_family(&self) -> SqlFamily {
+        self.inner.sql_family()
+    }
+
+    fn transaction_dropped(&self, depth: u32, rollback: &str) {
+        self.inner.transaction_dropped(depth, rollback)
+    }
 }
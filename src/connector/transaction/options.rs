@@ -0,0 +1,266 @@
+use crate::connector::SqlFamily;
+use std::{fmt, time::Duration};
+
+/// The isolation level of a transaction, from the weakest to the strongest
+/// guarantees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl fmt::Display for IsolationLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReadUncommitted => write!(f, "READ UNCOMMITTED"),
+            Self::ReadCommitted => write!(f, "READ COMMITTED"),
+            Self::RepeatableRead => write!(f, "REPEATABLE READ"),
+            Self::Serializable => write!(f, "SERIALIZABLE"),
+        }
+    }
+}
+
+/// Defines if a transaction can modify data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccessMode {
+    ReadOnly,
+    ReadWrite,
+}
+
+impl fmt::Display for AccessMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReadOnly => write!(f, "READ ONLY"),
+            Self::ReadWrite => write!(f, "READ WRITE"),
+        }
+    }
+}
+
+/// When an SQLite transaction acquires its database locks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LockingMode {
+    /// Locks are acquired on first read or write.
+    Deferred,
+    /// A write lock is acquired when the transaction starts.
+    Immediate,
+    /// An exclusive lock is acquired when the transaction starts.
+    Exclusive,
+}
+
+impl fmt::Display for LockingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Deferred => write!(f, "DEFERRED"),
+            Self::Immediate => write!(f, "IMMEDIATE"),
+            Self::Exclusive => write!(f, "EXCLUSIVE"),
+        }
+    }
+}
+
+/// Options for starting a new transaction. By default the database settings
+/// are used.
+///
+/// - PostgreSQL starts the transaction with `BEGIN ISOLATION LEVEL ...`.
+/// - MySQL sets the characteristics with `SET TRANSACTION` before `BEGIN`.
+/// - SQLite transactions are always serializable, only the
+///   [locking mode](#method.locking_mode) is used.
+///
+/// ```rust
+/// # use quaint::connector::*;
+/// let options = TransactionOptions::new()
+///     .isolation_level(IsolationLevel::Serializable)
+///     .access_mode(AccessMode::ReadOnly)
+///     .deferrable();
+///
+/// #[cfg(feature = "postgresql")]
+/// assert_eq!(
+///     vec!["BEGIN ISOLATION LEVEL SERIALIZABLE, READ ONLY, DEFERRABLE"],
+///     options.begin_statements(SqlFamily::Postgres),
+/// );
+///
+/// #[cfg(feature = "mysql")]
+/// assert_eq!(
+///     vec!["SET TRANSACTION ISOLATION LEVEL SERIALIZABLE, READ ONLY", "BEGIN"],
+///     options.begin_statements(SqlFamily::Mysql),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionOptions {
+    isolation_level: Option<IsolationLevel>,
+    access_mode: Option<AccessMode>,
+    deferrable: bool,
+    locking_mode: Option<LockingMode>,
+    pub(crate) max_retries: u32,
+    pub(crate) retry_backoff: Duration,
+}
+
+impl Default for TransactionOptions {
+    fn default() -> Self {
+        Self {
+            isolation_level: None,
+            access_mode: None,
+            deferrable: false,
+            locking_mode: None,
+            max_retries: 0,
+            retry_backoff: Duration::from_millis(10),
+        }
+    }
+}
+
+impl TransactionOptions {
+    /// Options using the database defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the isolation level of the transaction. Not used on SQLite.
+    pub fn isolation_level(mut self, level: IsolationLevel) -> Self {
+        self.isolation_level = Some(level);
+        self
+    }
+
+    /// Sets the transaction to be read-only or read-write. Not used on
+    /// SQLite.
+    pub fn access_mode(mut self, mode: AccessMode) -> Self {
+        self.access_mode = Some(mode);
+        self
+    }
+
+    /// A serializable, read-only transaction that waits for a snapshot free
+    /// of serialization failures before starting. Only used on PostgreSQL.
+    pub fn deferrable(mut self) -> Self {
+        self.deferrable = true;
+        self
+    }
+
+    /// When the transaction acquires its locks. Only used on SQLite.
+    pub fn locking_mode(mut self, mode: LockingMode) -> Self {
+        self.locking_mode = Some(mode);
+        self
+    }
+
+    /// How many times [transaction](trait.TransactionCapable.html#method.transaction_with)
+    /// runs the closure again after a serialization failure, deadlock or a
+    /// lock timeout. Defaults to zero.
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// The time to wait before the first retry, doubled after every attempt.
+    /// Defaults to 10 milliseconds.
+    pub fn retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// The statements starting a transaction with these options in the given
+    /// SQL dialect.
+    pub fn begin_statements(&self, family: SqlFamily) -> Vec<String> {
+        match family {
+            #[cfg(feature = "postgresql")]
+            SqlFamily::Postgres => {
+                let mut modes = Vec::new();
+
+                if let Some(level) = self.isolation_level {
+                    modes.push(format!("ISOLATION LEVEL {}", level));
+                }
+
+                if let Some(mode) = self.access_mode {
+                    modes.push(mode.to_string());
+                }
+
+                if self.deferrable {
+                    modes.push(String::from("DEFERRABLE"));
+                }
+
+                if modes.is_empty() {
+                    vec![String::from("BEGIN")]
+                } else {
+                    vec![format!("BEGIN {}", modes.join(", "))]
+                }
+            }
+            #[cfg(feature = "mysql")]
+            SqlFamily::Mysql => {
+                let mut characteristics = Vec::new();
+
+                if let Some(level) = self.isolation_level {
+                    characteristics.push(format!("ISOLATION LEVEL {}", level));
+                }
+
+                if let Some(mode) = self.access_mode {
+                    characteristics.push(mode.to_string());
+                }
+
+                let mut statements = Vec::new();
+
+                if !characteristics.is_empty() {
+                    statements.push(format!("SET TRANSACTION {}", characteristics.join(", ")));
+                }
+
+                statements.push(String::from("BEGIN"));
+                statements
+            }
+            #[cfg(feature = "sqlite")]
+            SqlFamily::Sqlite => match self.locking_mode {
+                Some(mode) => vec![format!("BEGIN {}", mode)],
+                None => vec![String::from("BEGIN")],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "postgresql")]
+    fn postgres_default_options() {
+        let options = TransactionOptions::new();
+        assert_eq!(vec!["BEGIN"], options.begin_statements(SqlFamily::Postgres));
+    }
+
+    #[test]
+    #[cfg(feature = "postgresql")]
+    fn postgres_isolation_level() {
+        let options = TransactionOptions::new().isolation_level(IsolationLevel::RepeatableRead);
+
+        assert_eq!(
+            vec!["BEGIN ISOLATION LEVEL REPEATABLE READ"],
+            options.begin_statements(SqlFamily::Postgres)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "mysql")]
+    fn mysql_default_options() {
+        let options = TransactionOptions::new();
+        assert_eq!(vec!["BEGIN"], options.begin_statements(SqlFamily::Mysql));
+    }
+
+    #[test]
+    #[cfg(feature = "mysql")]
+    fn mysql_access_mode() {
+        let options = TransactionOptions::new()
+            .access_mode(AccessMode::ReadWrite)
+            .deferrable();
+
+        assert_eq!(
+            vec!["SET TRANSACTION READ WRITE", "BEGIN"],
+            options.begin_statements(SqlFamily::Mysql)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sqlite")]
+    fn sqlite_locking_mode() {
+        let options = TransactionOptions::new()
+            .isolation_level(IsolationLevel::Serializable)
+            .locking_mode(LockingMode::Immediate);
+
+        assert_eq!(vec!["BEGIN IMMEDIATE"], options.begin_statements(SqlFamily::Sqlite));
+    }
+}
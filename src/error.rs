@@ -77,6 +77,15 @@ impl Error {
     pub fn kind(&self) -> &ErrorKind {
         &self.kind
     }
+
+    /// True if the transaction failed due to concurrent transactions, and
+    /// running it again might succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::SerializationFailure | ErrorKind::Deadlock | ErrorKind::LockTimeout(_)
+        )
+    }
 }
 
 impl fmt::Display for Error {
@@ -135,6 +144,15 @@ pub enum ErrorKind {
     #[error("Conversion failed: {}", _0)]
     ConversionError(&'static str),
 
+    #[error("Column `{}` has an unsupported type `{}`", column, db_type)]
+    UnsupportedColumnType { column: String, db_type: String },
+
+    #[error("{} is not supported on {}", feature, database)]
+    UnsupportedFeature {
+        feature: &'static str,
+        database: &'static str,
+    },
+
     #[error("The provided arguments are not supported")]
     InvalidConnectionArguments,
 
@@ -147,6 +165,15 @@ pub enum ErrorKind {
     #[error("Operation timed out ({0})")]
     Timeout(String),
 
+    #[error("Transaction failed due to a concurrent update")]
+    SerializationFailure,
+
+    #[error("Transaction failed due to a deadlock")]
+    Deadlock,
+
+    #[error("Timed out waiting for a lock ({0})")]
+    LockTimeout(String),
+
     #[error("Error opening a TLS connection. {}", message)]
     TlsError { message: String },
 
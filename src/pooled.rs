@@ -6,7 +6,6 @@ pub use manager::{PooledConnection, QuaintManager};
 use crate::connector::{ConnectionInfo, SqlFamily};
 use mobc::Pool;
 use std::sync::Arc;
-use url::Url;
 
 #[cfg(feature = "sqlite")]
 use std::convert::TryFrom;
@@ -48,7 +47,9 @@ impl Quaint {
     ///   query namespacing.
     /// - `socket_timeout` defined in seconds. Acts as the busy timeout in
     ///   SQLite. When set, queries that are waiting for a lock to be released
-    ///   will return the `Timeout` error after the defined value.
+    ///   will return the `LockTimeout` error after the defined value.
+    /// - `datetime_format` either `millis` (default) or `iso8601`. How
+    ///   datetimes are stored, see [DateTimeFormat](../connector/enum.DateTimeFormat.html).
     ///
     /// PostgreSQL:
     ///
@@ -88,40 +89,38 @@ impl Quaint {
     ///   database will return a `ConnectTimeout` error if taking more than the
     ///   defined value.
     pub async fn new(url_str: &str) -> crate::Result<Self> {
-        let url = Url::parse(url_str)?;
+        let connection_info = ConnectionInfo::from_url(url_str)?;
 
-        let (manager, connection_limit) = match url.scheme() {
+        let (manager, connection_limit) = match &connection_info {
             #[cfg(feature = "sqlite")]
-            "file" | "sqlite" => {
+            ConnectionInfo::Sqlite { .. } => {
                 let params = crate::connector::SqliteParams::try_from(url_str)?;
 
                 let manager = QuaintManager::Sqlite {
                     file_path: params.file_path,
                     db_name: params.db_name,
+                    datetime_format: params.datetime_format,
                 };
 
                 (manager, params.connection_limit)
             }
             #[cfg(feature = "mysql")]
-            "mysql" => {
-                let url = crate::connector::MysqlUrl::new(url)?;
+            ConnectionInfo::Mysql(url) => {
                 let connection_limit = url.connection_limit();
-                let manager = QuaintManager::Mysql(url);
+                let manager = QuaintManager::Mysql(url.clone());
 
                 (manager, connection_limit as u32)
             }
             #[cfg(feature = "postgresql")]
-            "postgres" | "postgresql" => {
-                let url = crate::connector::PostgresUrl::new(url)?;
+            ConnectionInfo::Postgres(url) => {
                 let connection_limit = url.connection_limit();
-                let manager = QuaintManager::Postgres(url);
+                let manager = QuaintManager::Postgres(url.clone());
 
                 (manager, connection_limit as u32)
             }
-            _ => unimplemented!("Supported url schemes: file or sqlite, mysql, postgres or postgresql."),
         };
 
-        let connection_info = Arc::new(ConnectionInfo::from_url(url_str)?);
+        let connection_info = Arc::new(connection_info);
         Self::log_start(connection_info.sql_family(), connection_limit);
 
         let inner = Pool::builder()
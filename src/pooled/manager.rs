@@ -7,10 +7,11 @@ use crate::connector::PostgresUrl;
 
 use crate::{
     ast,
-    connector::{self, Queryable, TransactionCapable, DBIO},
+    connector::{self, Introspect, Queryable, TransactionCapable, DBIO},
     error::Error,
 };
 use mobc::{Connection as MobcPooled, Manager};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// A connection from the pool. Implements
 /// [Queryable](connector/trait.Queryable.html).
@@ -18,27 +19,76 @@ pub struct PooledConnection {
     pub(crate) inner: MobcPooled<QuaintManager>,
 }
 
+impl PooledConnection {
+    /// True if the connection will be closed instead of being returned to
+    /// the pool.
+    pub fn is_broken(&self) -> bool {
+        self.inner.broken.load(Ordering::SeqCst)
+    }
+}
+
 impl TransactionCapable for PooledConnection {}
 
+impl Introspect for PooledConnection {}
+
 impl Queryable for PooledConnection {
     fn query<'a>(&'a self, q: ast::Query<'a>) -> DBIO<'a, connector::ResultSet> {
-        self.inner.query(q)
+        self.inner.conn.query(q)
     }
 
     fn execute<'a>(&'a self, q: ast::Query<'a>) -> DBIO<'a, u64> {
-        self.inner.execute(q)
+        self.inner.conn.execute(q)
     }
 
     fn query_raw<'a>(&'a self, sql: &'a str, params: &'a [ast::ParameterizedValue]) -> DBIO<'a, connector::ResultSet> {
-        self.inner.query_raw(sql, params)
+        self.inner.conn.query_raw(sql, params)
+    }
+
+    fn query_stream<'a>(
+        &'a self,
+        sql: &'a str,
+        params: &'a [ast::ParameterizedValue<'a>],
+    ) -> DBIO<'a, connector::ResultStream<'a>> {
+        self.inner.conn.query_stream(sql, params)
     }
 
     fn execute_raw<'a>(&'a self, sql: &'a str, params: &'a [ast::ParameterizedValue]) -> DBIO<'a, u64> {
-        self.inner.execute_raw(sql, params)
+        self.inner.conn.execute_raw(sql, params)
     }
 
     fn raw_cmd<'a>(&'a self, cmd: &'a str) -> DBIO<'a, ()> {
-        self.inner.raw_cmd(cmd)
+        self.inner.conn.raw_cmd(cmd)
+    }
+
+    fn sql_family(&self) -> connector::SqlFamily {
+        self.inner.conn.sql_family()
+    }
+
+    fn transaction_dropped(&self, depth: u32, rollback: &str) {
+        self.inner.conn.transaction_dropped(depth, rollback);
+
+        // Rolling back a savepoint leaves the outer transaction usable.
+        if depth == 0 {
+            self.inner.broken.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+/// A database connection managed by the pool.
+#[doc(hidden)]
+pub struct ManagedConnection {
+    pub(crate) conn: Box<dyn Queryable + Send + Sync>,
+    /// A connection left in an unknown state, e.g. in the middle of a
+    /// transaction, is closed when returned to the pool.
+    pub(crate) broken: AtomicBool,
+}
+
+impl ManagedConnection {
+    fn new(conn: Box<dyn Queryable + Send + Sync>) -> Self {
+        Self {
+            conn,
+            broken: AtomicBool::new(false),
+        }
     }
 }
 
@@ -51,49 +101,70 @@ pub enum QuaintManager {
     Postgres(PostgresUrl),
 
     #[cfg(feature = "sqlite")]
-    Sqlite { file_path: String, db_name: String },
+    Sqlite {
+        file_path: String,
+        db_name: String,
+        datetime_format: crate::connector::DateTimeFormat,
+    },
 }
 
 #[async_trait]
 impl Manager for QuaintManager {
-    type Connection = Box<dyn Queryable + Send + Sync>;
+    type Connection = ManagedConnection;
     type Error = Error;
 
     async fn connect(&self) -> crate::Result<Self::Connection> {
         match self {
             #[cfg(feature = "sqlite")]
-            QuaintManager::Sqlite { file_path, db_name } => {
+            QuaintManager::Sqlite {
+                file_path,
+                db_name,
+                datetime_format,
+            } => {
                 use crate::connector::Sqlite;
 
                 let mut conn = Sqlite::new(&file_path)?;
+                conn.datetime_format = *datetime_format;
                 conn.attach_database(db_name).await?;
 
-                Ok(Box::new(conn) as Self::Connection)
+                Ok(ManagedConnection::new(Box::new(conn)))
             }
 
             #[cfg(feature = "mysql")]
             QuaintManager::Mysql(url) => {
                 use crate::connector::Mysql;
-                Ok(Box::new(Mysql::new(url.clone())?) as Self::Connection)
+                Ok(ManagedConnection::new(Box::new(Mysql::new(url.clone())?)))
             }
 
             #[cfg(feature = "postgresql")]
             QuaintManager::Postgres(url) => {
                 use crate::connector::PostgreSql;
-                Ok(Box::new(PostgreSql::new(url.clone()).await?) as Self::Connection)
+                Ok(ManagedConnection::new(Box::new(PostgreSql::new(url.clone()).await?)))
             }
         }
     }
 
     async fn check(&self, conn: Self::Connection) -> crate::Result<Self::Connection> {
-        conn.raw_cmd("SELECT 1").await?;
+        conn.conn.raw_cmd("SELECT 1").await?;
         Ok(conn)
     }
+
+    fn validate(&self, conn: &mut Self::Connection) -> bool {
+        !*conn.broken.get_mut()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::pooled::Quaint;
+    #[cfg(feature = "sqlite")]
+    use crate::connector::TransactionCapable;
+    use crate::{error::ErrorKind, pooled::Quaint};
+
+    #[tokio::test]
+    async fn unknown_schemes_should_return_an_error() {
+        let err = Quaint::new("mssql://localhost/master").await.err().unwrap();
+        assert!(matches!(err.kind(), ErrorKind::DatabaseUrlIsInvalid(_)));
+    }
 
     #[tokio::test]
     #[cfg(feature = "mysql")]
@@ -158,4 +229,40 @@ mod tests {
 
         assert_eq!(10, pool.capacity().await as usize);
     }
+
+    #[tokio::test]
+    #[cfg(feature = "sqlite")]
+    async fn dropping_an_open_transaction_should_break_the_connection() {
+        let pool = Quaint::new("file:db/test.db").await.unwrap();
+        let conn = pool.check_out().await.unwrap();
+
+        {
+            let tx = conn.start_transaction().await.unwrap();
+            tx.commit().await.unwrap();
+        }
+
+        assert!(!conn.is_broken());
+
+        {
+            let _tx = conn.start_transaction().await.unwrap();
+        }
+
+        assert!(conn.is_broken());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "sqlite")]
+    async fn dropping_a_nested_transaction_should_not_break_the_connection() {
+        let pool = Quaint::new("file:db/test.db").await.unwrap();
+        let conn = pool.check_out().await.unwrap();
+        let tx = conn.start_transaction().await.unwrap();
+
+        {
+            let _nested = tx.start_transaction().await.unwrap();
+        }
+
+        tx.commit().await.unwrap();
+
+        assert!(!conn.is_broken());
+    }
 }
@@ -2,6 +2,7 @@
 pub use crate::ast::*;
 #[cfg(any(feature = "sqlite", feature = "mysql", feature = "postgresql"))]
 pub use crate::connector::{
-    ConnectionInfo, Queryable, ResultRow, ResultSet, SqlFamily, Transaction, TransactionCapable, DBIO,
+    ConnectionInfo, Queryable, ResultRow, ResultSet, SqlFamily, Transaction, TransactionCapable, TransactionOptions,
+    DBIO,
 };
 pub use crate::{col, val, values};
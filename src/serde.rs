@@ -114,6 +114,7 @@ impl<'de> Deserializer<'de> for ParameterizedValueDeserializer<'de> {
             ParameterizedValue::Char(c) => visitor.visit_char(c),
             ParameterizedValue::Null => visitor.visit_none(),
             ParameterizedValue::Real(real) => visitor.visit_f64(real.to_f64().unwrap()),
+            ParameterizedValue::Bytes(bytes) => visitor.visit_byte_buf(bytes.into_owned()),
 
             #[cfg(feature = "uuid-0_8")]
             ParameterizedValue::Uuid(uuid) => visitor.visit_string(uuid.to_string()),
@@ -127,6 +128,15 @@ impl<'de> Deserializer<'de> for ParameterizedValueDeserializer<'de> {
             #[cfg(feature = "chrono-0_4")]
             ParameterizedValue::DateTime(dt) => visitor.visit_string(dt.to_rfc3339()),
 
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::DateTimeTz(dt) => visitor.visit_string(dt.to_rfc3339()),
+
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Date(date) => visitor.visit_string(date.to_string()),
+
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Time(time) => visitor.visit_string(time.to_string()),
+
             #[cfg(all(feature = "array", feature = "postgresql"))]
             ParameterizedValue::Array(values) => {
                 let deserializer = serde::de::value::SeqDeserializer::new(values.into_iter());
@@ -135,9 +145,21 @@ impl<'de> Deserializer<'de> for ParameterizedValueDeserializer<'de> {
         }
     }
 
+    // A `Vec<u8>` is deserialized as a sequence, so bytes are visited one by
+    // one instead of as a buffer.
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            ParameterizedValue::Bytes(bytes) => {
+                let deserializer = serde::de::value::SeqDeserializer::new(bytes.into_owned().into_iter());
+                visitor.visit_seq(deserializer)
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
     serde::forward_to_deserialize_any! {
         bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes byte_buf
-        option unit unit_struct newtype_struct seq tuple tuple_struct map
+        option unit unit_struct newtype_struct tuple tuple_struct map
         struct enum identifier ignored_any
     }
 }
@@ -254,4 +276,28 @@ mod tests {
 
         assert_eq!(cat, expected_cat);
     }
+
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct Attachment {
+        name: String,
+        data: Vec<u8>,
+    }
+
+    #[test]
+    fn deserialize_bytes() {
+        let row = make_row(vec![
+            ("name", "cat.png".into()),
+            ("data", ParameterizedValue::from(vec![0u8, 159, 146, 150])),
+        ]);
+
+        let attachment: Attachment = from_row(row).unwrap();
+
+        assert_eq!(
+            attachment,
+            Attachment {
+                name: "cat.png".into(),
+                data: vec![0, 159, 146, 150],
+            }
+        );
+    }
 }
@@ -2,11 +2,17 @@
 
 use crate::{
     ast,
-    connector::{self, ConnectionInfo, Queryable, SqlFamily, TransactionCapable, DBIO},
+    connector::{self, ConnectionInfo, Introspect, Queryable, SchemaDescription, SqlFamily, TransactionCapable, DBIO},
+    error::{Error, ErrorKind},
+};
+use futures::lock::{Mutex, MutexGuard};
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
-use futures::lock::Mutex;
-use std::{fmt, sync::Arc};
-use url::Url;
 
 #[cfg(feature = "sqlite")]
 use std::convert::TryFrom;
@@ -14,10 +20,25 @@ use std::convert::TryFrom;
 /// The main entry point and an abstraction over a database connection.
 #[derive(Clone)]
 pub struct Quaint {
-    inner: Arc<Mutex<Box<dyn Queryable + Send + Sync>>>,
+    inner: Arc<dyn Queryable + Send + Sync>,
+    // Serializes the queries, a stream holds it until dropped.
+    lock: Arc<Mutex<()>>,
+    streaming: Arc<AtomicBool>,
     connection_info: Arc<ConnectionInfo>,
 }
 
+/// Holds the connection for a result stream.
+struct Streaming<'a> {
+    _guard: MutexGuard<'a, ()>,
+    streaming: &'a AtomicBool,
+}
+
+impl<'a> Drop for Streaming<'a> {
+    fn drop(&mut self) {
+        self.streaming.store(false, Ordering::SeqCst);
+    }
+}
+
 impl fmt::Debug for Quaint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self.connection_info)
@@ -26,6 +47,8 @@ impl fmt::Debug for Quaint {
 
 impl TransactionCapable for Quaint {}
 
+impl Introspect for Quaint {}
+
 impl Quaint {
     /// Create a new connection to the database. The connection string
     /// follows the specified format:
@@ -49,7 +72,9 @@ impl Quaint {
     ///   query namespacing.
     /// - `socket_timeout` defined in seconds. Acts as the busy timeout in
     ///   SQLite. When set, queries that are waiting for a lock to be released
-    ///   will return the `Timeout` error after the defined value.
+    ///   will return the `LockTimeout` error after the defined value.
+    /// - `datetime_format` either `millis` (default) or `iso8601`. How
+    ///   datetimes are stored, see [DateTimeFormat](../connector/enum.DateTimeFormat.html).
     ///
     /// PostgreSQL:
     ///
@@ -89,41 +114,45 @@ impl Quaint {
     ///   database will return a `ConnectTimeout` error if taking more than the
     ///   defined value.
     pub async fn new(url_str: &str) -> crate::Result<Self> {
-        let url = Url::parse(url_str)?;
+        let connection_info = ConnectionInfo::from_url(url_str)?;
 
-        let inner = match url.scheme() {
+        let inner = match &connection_info {
             #[cfg(feature = "sqlite")]
-            "file" | "sqlite" => {
+            ConnectionInfo::Sqlite { .. } => {
                 let params = connector::SqliteParams::try_from(url_str)?;
                 let mut sqlite = connector::Sqlite::new(&params.file_path)?;
+                sqlite.datetime_format = params.datetime_format;
 
                 sqlite.attach_database(&params.db_name).await?;
 
-                Mutex::new(Box::new(sqlite) as Box<dyn Queryable + Send + Sync>)
+                Arc::new(sqlite) as Arc<dyn Queryable + Send + Sync>
             }
             #[cfg(feature = "mysql")]
-            "mysql" => {
-                let url = connector::MysqlUrl::new(url)?;
-                let mysql = connector::Mysql::new(url)?;
+            ConnectionInfo::Mysql(url) => {
+                let mysql = connector::Mysql::new(url.clone())?;
 
-                Mutex::new(Box::new(mysql) as Box<dyn Queryable + Send + Sync>)
+                Arc::new(mysql) as Arc<dyn Queryable + Send + Sync>
             }
             #[cfg(feature = "postgresql")]
-            "postgres" | "postgresql" => {
-                let url = connector::PostgresUrl::new(url)?;
-                let psql = connector::PostgreSql::new(url).await?;
+            ConnectionInfo::Postgres(url) => {
+                let psql = connector::PostgreSql::new(url.clone()).await?;
 
-                Mutex::new(Box::new(psql) as Box<dyn Queryable + Send + Sync>)
+                Arc::new(psql) as Arc<dyn Queryable + Send + Sync>
             }
-            _ => unimplemented!("Supported url schemes: file or sqlite, mysql, postgres or postgresql."),
         };
 
-        let connection_info = Arc::new(ConnectionInfo::from_url(url_str)?);
+        let connection_info = Arc::new(connection_info);
         Self::log_start(connection_info.sql_family(), 1);
 
-        let inner = Arc::new(inner);
+        let lock = Arc::new(Mutex::new(()));
+        let streaming = Arc::new(AtomicBool::new(false));
 
-        Ok(Self { inner, connection_info })
+        Ok(Self {
+            inner,
+            lock,
+            streaming,
+            connection_info,
+        })
     }
 
     /// Info about the connection and underlying database.
@@ -131,6 +160,23 @@ impl Quaint {
         &self.connection_info
     }
 
+    /// Describes the tables and enums of the schema of the connection. See
+    /// [ConnectionInfo::schema_name](connector/enum.ConnectionInfo.html#method.schema_name).
+    pub fn describe(&self) -> DBIO<'_, SchemaDescription> {
+        self.describe_schema(self.connection_info.schema_name())
+    }
+
+    /// Locks the connection for a query. A query can't wait for a result
+    /// stream, which might be read only after the query finishes.
+    async fn lock(&self) -> crate::Result<MutexGuard<'_, ()>> {
+        if self.streaming.load(Ordering::SeqCst) {
+            let kind = ErrorKind::QueryError("The connection is reading a result stream".into());
+            return Err(Error::builder(kind).build());
+        }
+
+        Ok(self.lock.lock().await)
+    }
+
     fn log_start(family: SqlFamily, connection_limit: u32) {
         #[cfg(not(feature = "tracing-log"))]
         {
@@ -145,22 +191,71 @@ impl Quaint {
 
 impl Queryable for Quaint {
     fn query<'a>(&'a self, q: ast::Query<'a>) -> DBIO<'a, connector::ResultSet> {
-        DBIO::new(async move { self.inner.lock().await.query(q).await })
+        DBIO::new(async move {
+            let _guard = self.lock().await?;
+            self.inner.query(q).await
+        })
     }
 
     fn execute<'a>(&'a self, q: ast::Query<'a>) -> DBIO<'a, u64> {
-        DBIO::new(async move { self.inner.lock().await.execute(q).await })
+        DBIO::new(async move {
+            let _guard = self.lock().await?;
+            self.inner.execute(q).await
+        })
     }
 
     fn query_raw<'a>(&'a self, sql: &'a str, params: &'a [ast::ParameterizedValue]) -> DBIO<'a, connector::ResultSet> {
-        DBIO::new(async move { self.inner.lock().await.query_raw(sql, params).await })
+        DBIO::new(async move {
+            let _guard = self.lock().await?;
+            self.inner.query_raw(sql, params).await
+        })
+    }
+
+    /// Streams the rows of the query, see
+    /// [Queryable::query_stream](../connector/trait.Queryable.html#method.query_stream).
+    ///
+    /// The stream holds the connection, shared by all clones of the `Quaint`,
+    /// until it is dropped. Other queries return a `QueryError` in the
+    /// meantime instead of waiting for the stream.
+    fn query_stream<'a>(
+        &'a self,
+        sql: &'a str,
+        params: &'a [ast::ParameterizedValue<'a>],
+    ) -> DBIO<'a, connector::ResultStream<'a>> {
+        DBIO::new(async move {
+            let guard = self.lock().await?;
+            self.streaming.store(true, Ordering::SeqCst);
+
+            let streaming = Streaming {
+                _guard: guard,
+                streaming: &self.streaming,
+            };
+
+            let stream = self.inner.query_stream(sql, params).await?;
+
+            Ok(stream.holding(streaming))
+        })
     }
 
     fn execute_raw<'a>(&'a self, sql: &'a str, params: &'a [ast::ParameterizedValue]) -> DBIO<'a, u64> {
-        DBIO::new(async move { self.inner.lock().await.execute_raw(sql, params).await })
+        DBIO::new(async move {
+            let _guard = self.lock().await?;
+            self.inner.execute_raw(sql, params).await
+        })
     }
 
     fn raw_cmd<'a>(&'a self, cmd: &'a str) -> DBIO<'a, ()> {
-        DBIO::new(async move { self.inner.lock().await.raw_cmd(cmd).await })
+        DBIO::new(async move {
+            let _guard = self.lock().await?;
+            self.inner.raw_cmd(cmd).await
+        })
+    }
+
+    fn sql_family(&self) -> SqlFamily {
+        self.connection_info.sql_family()
+    }
+
+    fn transaction_dropped(&self, depth: u32, rollback: &str) {
+        self.inner.transaction_dropped(depth, rollback);
     }
 }
@@ -62,6 +62,10 @@ pub trait Visitor<'a> {
     /// replacing it with the `C_PARAM`, calling `add_parameter` with the replaced value.
     fn add_parameter(&mut self, value: ParameterizedValue<'a>);
 
+    /// True if the visitor writes values into the query as literals instead
+    /// of parameters. Statements changing the schema can't have parameters.
+    fn inline_values(&self) -> bool;
+
     /// The `LIMIT` and `OFFSET` statement in the query
     fn visit_limit_and_offset(
         &mut self,
@@ -80,10 +84,69 @@ pub trait Visitor<'a> {
 
     /// A visit to a value we parameterize
     fn visit_parameterized(&mut self, value: ParameterizedValue<'a>) -> fmt::Result {
+        if self.inline_values() {
+            return self.visit_literal(value);
+        }
+
         self.add_parameter(value);
         self.parameter_substitution()
     }
 
+    /// A value written into the query
+    fn visit_literal(&mut self, value: ParameterizedValue<'a>) -> fmt::Result {
+        match value {
+            ParameterizedValue::Null => self.write("NULL"),
+            ParameterizedValue::Integer(i) => self.write(i),
+            ParameterizedValue::Real(r) => self.write(r),
+            ParameterizedValue::Boolean(b) => self.write(if b { "TRUE" } else { "FALSE" }),
+            ParameterizedValue::Text(s) | ParameterizedValue::Enum(s) => self.visit_string_literal(&s),
+            ParameterizedValue::Char(c) => self.visit_string_literal(&c.to_string()),
+            ParameterizedValue::Bytes(bytes) => self.visit_bytes_literal(&bytes),
+            #[cfg(all(feature = "array", feature = "postgresql"))]
+            ParameterizedValue::Array(values) => self.surround_with("ARRAY[", "]", |ref mut s| {
+                let len = values.len();
+
+                for (i, value) in values.into_iter().enumerate() {
+                    s.visit_literal(value)?;
+
+                    if i < (len - 1) {
+                        s.write(", ")?;
+                    }
+                }
+
+                Ok(())
+            }),
+            #[cfg(feature = "json-1")]
+            ParameterizedValue::Json(json) => self.visit_string_literal(&json.to_string()),
+            #[cfg(feature = "uuid-0_8")]
+            ParameterizedValue::Uuid(uuid) => self.visit_string_literal(&uuid.to_hyphenated().to_string()),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::DateTime(dt) => self.visit_string_literal(&dt.to_rfc3339()),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::DateTimeTz(dt) => self.visit_string_literal(&dt.to_rfc3339()),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Date(date) => self.visit_string_literal(&date.to_string()),
+            #[cfg(feature = "chrono-0_4")]
+            ParameterizedValue::Time(time) => self.visit_string_literal(&time.to_string()),
+        }
+    }
+
+    /// A string literal, doubling the quotes in the value.
+    fn visit_string_literal(&mut self, value: &str) -> fmt::Result {
+        self.surround_with("'", "'", |ref mut s| s.write(value.replace('\'', "''")))
+    }
+
+    /// A binary literal in hexadecimal, `X'CAFE'`.
+    fn visit_bytes_literal(&mut self, bytes: &[u8]) -> fmt::Result {
+        self.surround_with("X'", "'", |ref mut s| {
+            for byte in bytes {
+                s.write(format!("{:02X}", byte))?;
+            }
+
+            Ok(())
+        })
+    }
+
     /// The join statements in the query
     fn visit_joins(&mut self, joins: Vec<Join<'a>>) -> fmt::Result {
         for j in joins {
@@ -116,10 +179,68 @@ pub trait Visitor<'a> {
         self.visit_conditions(data.conditions)
     }
 
+    /// The `WITH` clause of common table expressions before a statement
+    fn visit_with(&mut self, with: With<'a>) -> fmt::Result {
+        self.write("WITH ")?;
+
+        if with.recursive {
+            self.write("RECURSIVE ")?;
+        }
+
+        let len = with.expressions.len();
+
+        for (i, cte) in with.expressions.into_iter().enumerate() {
+            self.delimited_identifiers(&[&*cte.name])?;
+
+            if !cte.columns.is_empty() {
+                let columns = cte.columns.len();
+
+                self.write(" (")?;
+
+                for (j, column) in cte.columns.into_iter().enumerate() {
+                    self.delimited_identifiers(&[&*column])?;
+
+                    if j < (columns - 1) {
+                        self.write(", ")?;
+                    }
+                }
+
+                self.write(")")?;
+            }
+
+            self.write(" AS (")?;
+            self.visit_query(*cte.query);
+            self.write(")")?;
+
+            if i < (len - 1) {
+                self.write(", ")?;
+            }
+        }
+
+        self.write(" ")
+    }
+
+    /// The `DISTINCT ON (columns)` of a `SELECT`
+    fn visit_distinct_on(&mut self, columns: Vec<Column<'a>>) -> fmt::Result {
+        self.write("DISTINCT ON (")?;
+        self.visit_columns(columns.into_iter().map(|c| c.into()).collect())?;
+        self.write(") ")
+    }
+
     /// A walk through a `SELECT` statement
     fn visit_select(&mut self, select: Select<'a>) -> fmt::Result {
+        if let Some(with) = select.with {
+            self.visit_with(with)?;
+        }
+
         self.write("SELECT ")?;
 
+        match select.distinct {
+            Some(Distinct::All) => self.write("DISTINCT ")?,
+            Some(Distinct::On(columns)) => self.visit_distinct_on(columns)?,
+            None => (),
+        }
+
         if let Some(table) = select.table {
             if select.columns.is_empty() {
                 match table.typ {
@@ -156,10 +277,6 @@ pub trait Visitor<'a> {
                 self.write(" WHERE ")?;
                 self.visit_conditions(conditions)?;
             }
-            if !select.ordering.is_empty() {
-                self.write(" ORDER BY ")?;
-                self.visit_ordering(select.ordering)?;
-            }
             if !select.grouping.is_empty() {
                 self.write(" GROUP BY ")?;
                 self.visit_grouping(select.grouping)?;
@@ -168,6 +285,14 @@ pub trait Visitor<'a> {
                 self.write(" HAVING ")?;
                 self.visit_conditions(conditions)?;
             }
+            if !select.windows.is_empty() {
+                self.write(" WINDOW ")?;
+                self.visit_windows(select.windows)?;
+            }
+            if !select.ordering.is_empty() {
+                self.write(" ORDER BY ")?;
+                self.visit_ordering(select.ordering)?;
+            }
 
             self.visit_limit_and_offset(select.limit, select.offset)?;
         } else if select.columns.is_empty() {
@@ -181,25 +306,62 @@ pub trait Visitor<'a> {
 
     /// A walk through an `UPDATE` statement
     fn visit_update(&mut self, update: Update<'a>) -> fmt::Result {
+        if let Some(with) = update.with {
+            self.visit_with(with)?;
+        }
+
         self.write("UPDATE ")?;
         self.visit_table(update.table, true)?;
 
-        {
-            self.write(" SET ")?;
-            let pairs = update.columns.into_iter().zip(update.values.into_iter());
-            let len = pairs.len();
+        self.write(" SET ")?;
+        self.visit_assignments(update.columns, update.values)?;
 
-            for (i, (key, value)) in pairs.enumerate() {
-                self.visit_column(key)?;
-                self.write(" = ")?;
-                self.visit_database_value(value)?;
+        if let Some(conditions) = update.conditions {
+            self.write(" WHERE ")?;
+            self.visit_conditions(conditions)?;
+        }
 
-                if i < (len - 1) {
-                    self.write(", ")?;
-                }
+        self.visit_returning(update.returning)
+    }
+
+    /// The `RETURNING` clause of an `INSERT`, `UPDATE` or `DELETE` statement
+    fn visit_returning(&mut self, returning: Option<Vec<Column<'a>>>) -> fmt::Result {
+        if let Some(returning) = returning {
+            if !returning.is_empty() {
+                let values = returning.into_iter().map(|r| r.into()).collect();
+                self.write(" RETURNING ")?;
+                self.visit_columns(values)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The `column = value` pairs of an `UPDATE` statement
+    fn visit_assignments(&mut self, columns: Vec<Column<'a>>, values: Vec<DatabaseValue<'a>>) -> fmt::Result {
+        let pairs = columns.into_iter().zip(values);
+        let len = pairs.len();
+
+        for (i, (key, value)) in pairs.enumerate() {
+            self.visit_column(key)?;
+            self.write(" = ")?;
+            self.visit_database_value(value)?;
+
+            if i < (len - 1) {
+                self.write(", ")?;
             }
         }
 
+        Ok(())
+    }
+
+    /// The `ON CONFLICT DO UPDATE` part of an upsert
+    fn visit_upsert(&mut self, update: ConflictUpdate<'a>) -> fmt::Result {
+        self.write(" ON CONFLICT")?;
+        self.visit_conflict_target(update.target)?;
+        self.write(" DO UPDATE SET ")?;
+        self.visit_assignments(update.columns, update.values)?;
+
         if let Some(conditions) = update.conditions {
             self.write(" WHERE ")?;
             self.visit_conditions(conditions)?;
@@ -208,8 +370,35 @@ pub trait Visitor<'a> {
         Ok(())
     }
 
+    /// The unique index or constraint of an upsert
+    fn visit_conflict_target(&mut self, target: ConflictTarget<'a>) -> fmt::Result {
+        match target {
+            ConflictTarget::Columns(columns) => self.surround_with(" (", ")", |ref mut s| {
+                let len = columns.len();
+
+                for (i, column) in columns.into_iter().enumerate() {
+                    s.delimited_identifiers(&[&*column.name])?;
+
+                    if i < (len - 1) {
+                        s.write(", ")?;
+                    }
+                }
+
+                Ok(())
+            }),
+            ConflictTarget::Constraint(name) => {
+                self.write(" ON CONSTRAINT ")?;
+                self.delimited_identifiers(&[&*name])
+            }
+        }
+    }
+
     /// A walk through an `DELETE` statement
     fn visit_delete(&mut self, delete: Delete<'a>) -> fmt::Result {
+        if let Some(with) = delete.with {
+            self.visit_with(with)?;
+        }
+
         self.write("DELETE FROM ")?;
         self.visit_table(delete.table, true)?;
 
@@ -218,9 +407,235 @@ pub trait Visitor<'a> {
             self.visit_conditions(conditions)?;
         }
 
+        self.visit_returning(delete.returning)
+    }
+
+    /// A walk through a `CREATE TABLE` statement
+    fn visit_create_table(&mut self, create_table: CreateTable<'a>) -> fmt::Result {
+        self.write("CREATE TABLE ")?;
+
+        if create_table.if_not_exists {
+            self.write("IF NOT EXISTS ")?;
+        }
+
+        let CreateTable {
+            table,
+            columns,
+            primary_key,
+            unique,
+            foreign_keys,
+            ..
+        } = create_table;
+
+        self.visit_table(table, false)?;
+
+        self.surround_with(" (", ")", |ref mut s| {
+            let mut first = true;
+            let mut separate = |s: &mut Self| {
+                if first {
+                    first = false;
+                    Ok(())
+                } else {
+                    s.write(", ")
+                }
+            };
+
+            for column in columns {
+                separate(s)?;
+                s.visit_column_definition(column)?;
+            }
+
+            if !primary_key.is_empty() {
+                separate(s)?;
+                s.write("PRIMARY KEY ")?;
+                s.visit_identifier_list(primary_key)?;
+            }
+
+            for columns in unique {
+                separate(s)?;
+                s.write("UNIQUE ")?;
+                s.visit_identifier_list(columns)?;
+            }
+
+            for foreign_key in foreign_keys {
+                separate(s)?;
+                s.visit_foreign_key(foreign_key)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// A column with its type and constraints
+    fn visit_column_definition(&mut self, column: ColumnDefinition<'a>) -> fmt::Result {
+        self.delimited_identifiers(&[&*column.name])?;
+        self.write(" ")?;
+        self.visit_column_type(column.column_type, column.auto_increment)?;
+
+        if !column.nullable {
+            self.write(" NOT NULL")?;
+        }
+
+        match column.default {
+            Some(DefaultValue::Value(value)) => {
+                self.write(" DEFAULT ")?;
+                self.visit_database_value(value)?;
+            }
+            Some(DefaultValue::Expression(expression)) => {
+                self.write(" DEFAULT ")?;
+                self.write(expression)?;
+            }
+            None => (),
+        }
+
+        if column.primary_key {
+            self.write(" PRIMARY KEY")?;
+        }
+
+        if column.auto_increment {
+            self.visit_auto_increment()?;
+        }
+
+        if column.unique {
+            self.write(" UNIQUE")?;
+        }
+
+        Ok(())
+    }
+
+    /// The name of a column type in the database. An auto-incremented
+    /// column might need a different type.
+    fn visit_column_type(&mut self, column_type: ColumnType<'a>, auto_increment: bool) -> fmt::Result;
+
+    /// The keyword after the constraints of an auto-incremented column.
+    /// Nothing is needed when the column type generates the values.
+    fn visit_auto_increment(&mut self) -> fmt::Result {
+        Ok(())
+    }
+
+    /// A foreign key constraint of a table
+    fn visit_foreign_key(&mut self, foreign_key: ForeignKey<'a>) -> fmt::Result {
+        self.write("FOREIGN KEY ")?;
+        self.visit_identifier_list(foreign_key.columns)?;
+        self.write(" REFERENCES ")?;
+        self.visit_table(foreign_key.referenced_table, false)?;
+        self.write(" ")?;
+        self.visit_identifier_list(foreign_key.referenced_columns)?;
+
+        if let Some(action) = foreign_key.on_delete {
+            self.write(" ON DELETE ")?;
+            self.write(action)?;
+        }
+
+        if let Some(action) = foreign_key.on_update {
+            self.write(" ON UPDATE ")?;
+            self.write(action)?;
+        }
+
+        Ok(())
+    }
+
+    /// A walk through an `ALTER TABLE` statement
+    fn visit_alter_table(&mut self, alter_table: AlterTable<'a>) -> fmt::Result {
+        self.write("ALTER TABLE ")?;
+        self.visit_table(alter_table.table, false)?;
+
+        match alter_table.change {
+            AlterTableChange::Add(column) => {
+                self.write(" ADD COLUMN ")?;
+                self.visit_column_definition(column)
+            }
+            AlterTableChange::Drop(column) => {
+                self.write(" DROP COLUMN ")?;
+                self.delimited_identifiers(&[&*column])
+            }
+            AlterTableChange::Rename(from, to) => {
+                self.write(" RENAME COLUMN ")?;
+                self.delimited_identifiers(&[&*from])?;
+                self.write(" TO ")?;
+                self.delimited_identifiers(&[&*to])
+            }
+        }
+    }
+
+    /// A walk through a `CREATE INDEX` statement. The index is created in
+    /// the database or schema of the table.
+    fn visit_create_index(&mut self, create_index: CreateIndex<'a>) -> fmt::Result {
+        self.write("CREATE ")?;
+
+        if create_index.unique {
+            self.write("UNIQUE ")?;
+        }
+
+        self.write("INDEX ")?;
+
+        if create_index.if_not_exists {
+            self.write("IF NOT EXISTS ")?;
+        }
+
+        self.visit_index_target(create_index.name, create_index.table)?;
+        self.write(" ")?;
+        self.visit_identifier_list(create_index.columns)?;
+
+        if let Some(conditions) = create_index.conditions {
+            self.write(" WHERE ")?;
+            self.visit_conditions(conditions)?;
+        }
+
         Ok(())
     }
 
+    /// The name of a new index and its table, `"name" ON "table"`
+    fn visit_index_target(&mut self, name: Cow<'a, str>, table: Table<'a>) -> fmt::Result {
+        self.delimited_identifiers(&[&*name])?;
+        self.write(" ON ")?;
+        self.visit_table(table, false)
+    }
+
+    /// A walk through a `DROP TABLE` statement
+    fn visit_drop_table(&mut self, drop_table: DropTable<'a>) -> fmt::Result {
+        self.write("DROP TABLE ")?;
+
+        if drop_table.if_exists {
+            self.write("IF EXISTS ")?;
+        }
+
+        self.visit_table(drop_table.table, false)
+    }
+
+    /// A walk through a `DROP INDEX` statement. The index name is qualified
+    /// with the database or schema of the table.
+    fn visit_drop_index(&mut self, drop_index: DropIndex<'a>) -> fmt::Result {
+        self.write("DROP INDEX ")?;
+
+        if drop_index.if_exists {
+            self.write("IF EXISTS ")?;
+        }
+
+        match drop_index.table.database {
+            Some(database) => self.delimited_identifiers(&[&*database, &*drop_index.name]),
+            None => self.delimited_identifiers(&[&*drop_index.name]),
+        }
+    }
+
+    /// A parenthesized list of delimited identifiers, such as the columns of
+    /// an index
+    fn visit_identifier_list(&mut self, identifiers: Vec<Cow<'a, str>>) -> fmt::Result {
+        self.surround_with("(", ")", |ref mut s| {
+            let len = identifiers.len();
+
+            for (i, identifier) in identifiers.into_iter().enumerate() {
+                s.delimited_identifiers(&[&*identifier])?;
+
+                if i < (len - 1) {
+                    s.write(", ")?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
     /// A helper for delimiting an identifier, surrounding every part with `C_BACKTICK`
     /// and delimiting the values with a `.`
     fn delimited_identifiers(&mut self, parts: &[&str]) -> fmt::Result {
@@ -246,6 +661,11 @@ pub trait Visitor<'a> {
             Query::Delete(delete) => self.visit_delete(*delete).unwrap(),
             Query::Union(union) => self.visit_union(union).unwrap(),
             Query::Raw(string) => self.write(string).unwrap(),
+            Query::CreateTable(create_table) => self.visit_create_table(*create_table).unwrap(),
+            Query::AlterTable(alter_table) => self.visit_alter_table(*alter_table).unwrap(),
+            Query::CreateIndex(create_index) => self.visit_create_index(*create_index).unwrap(),
+            Query::DropTable(drop_table) => self.visit_drop_table(*drop_table).unwrap(),
+            Query::DropIndex(drop_index) => self.visit_drop_index(*drop_index).unwrap(),
         }
     }
 
@@ -314,6 +734,33 @@ pub trait Visitor<'a> {
         }
     }
 
+    /// A `CASE WHEN` expression
+    fn visit_case(&mut self, case: Case<'a>) -> fmt::Result {
+        // `CASE END` is not valid, the result is the `ELSE` value.
+        if case.conditions.is_empty() {
+            return match case.otherwise {
+                Some(otherwise) => self.visit_database_value(otherwise),
+                None => self.write("NULL"),
+            };
+        }
+
+        self.write("CASE")?;
+
+        for (condition, value) in case.conditions {
+            self.write(" WHEN ")?;
+            self.visit_conditions(condition)?;
+            self.write(" THEN ")?;
+            self.visit_database_value(value)?;
+        }
+
+        if let Some(otherwise) = case.otherwise {
+            self.write(" ELSE ")?;
+            self.visit_database_value(otherwise)?;
+        }
+
+        self.write(" END")
+    }
+
     /// A visit to a value used in an expression
     fn visit_database_value(&mut self, value: DatabaseValue<'a>) -> fmt::Result {
         match value {
@@ -324,6 +771,10 @@ pub trait Visitor<'a> {
             DatabaseValue::Function(function) => self.visit_function(function),
             DatabaseValue::Op(op) => self.visit_operation(*op),
             DatabaseValue::Values(values) => self.visit_values(*values),
+            DatabaseValue::Excluded(column) => self.visit_excluded(*column),
+            DatabaseValue::Case(case) => self.visit_case(*case),
+            #[cfg(feature = "json-1")]
+            DatabaseValue::JsonExtract(extract) => self.visit_json_extract(*extract),
             DatabaseValue::Asterisk(table) => match table {
                 Some(table) => {
                     self.visit_table(*table, false)?;
@@ -334,6 +785,12 @@ pub trait Visitor<'a> {
         }
     }
 
+    /// A column of the row proposed for insertion in an upsert
+    fn visit_excluded(&mut self, column: Column<'a>) -> fmt::Result {
+        self.write("excluded.")?;
+        self.delimited_identifiers(&[&*column.name])
+    }
+
     fn visit_values(&mut self, values: Values<'a>) -> fmt::Result {
         self.surround_with("(VALUES ", ")", |ref mut s| {
             let len = values.len();
@@ -490,107 +947,75 @@ pub trait Visitor<'a> {
             Compare::In(left, right) => match *right {
                 DatabaseValue::Row(ref row) if row.is_empty() => self.write("1=0"),
                 _ => match *right {
+                    #[cfg(all(feature = "array", feature = "postgresql"))]
+                    dbv @ DatabaseValue::Parameterized(ParameterizedValue::Array(_)) => {
+                        self.visit_in(*left, dbv, false)
+                    }
                     DatabaseValue::Parameterized(pv) => {
                         self.visit_database_value(*left)?;
                         self.write(" = ")?;
                         self.visit_parameterized(pv)
                     }
-                    dbv => {
-                        self.visit_database_value(*left)?;
-                        self.write(" IN ")?;
-                        self.visit_database_value(dbv)
-                    }
+                    dbv => self.visit_in(*left, dbv, false),
                 },
             },
             Compare::NotIn(left, right) => match *right {
                 DatabaseValue::Row(ref row) if row.is_empty() => self.write("1=1"),
                 _ => match *right {
+                    #[cfg(all(feature = "array", feature = "postgresql"))]
+                    dbv @ DatabaseValue::Parameterized(ParameterizedValue::Array(_)) => self.visit_in(*left, dbv, true),
                     DatabaseValue::Parameterized(pv) => {
                         self.visit_database_value(*left)?;
                         self.write(" <> ")?;
                         self.visit_parameterized(pv)
                     }
-                    dbv => {
-                        self.visit_database_value(*left)?;
-                        self.write(" NOT IN ")?;
-                        self.visit_database_value(dbv)
-                    }
+                    dbv => self.visit_in(*left, dbv, true),
                 },
             },
             Compare::Like(left, right) => {
-                self.visit_database_value(*left)?;
-
-                self.add_parameter(ParameterizedValue::Text(Cow::from(format!(
-                    "{}{}{}",
-                    Self::C_WILDCARD,
-                    right,
-                    Self::C_WILDCARD
-                ))));
-
-                self.write(" LIKE ")?;
-                self.parameter_substitution()
+                let pattern = like_pattern(Self::C_WILDCARD, &right, Self::C_WILDCARD);
+                self.visit_like(*left, pattern, false)
             }
             Compare::NotLike(left, right) => {
-                self.visit_database_value(*left)?;
-
-                self.add_parameter(ParameterizedValue::Text(Cow::from(format!(
-                    "{}{}{}",
-                    Self::C_WILDCARD,
-                    right,
-                    Self::C_WILDCARD
-                ))));
-
-                self.write(" NOT LIKE ")?;
-                self.parameter_substitution()
+                let pattern = like_pattern(Self::C_WILDCARD, &right, Self::C_WILDCARD);
+                self.visit_like(*left, pattern, true)
             }
             Compare::BeginsWith(left, right) => {
-                self.visit_database_value(*left)?;
-
-                self.add_parameter(ParameterizedValue::Text(Cow::from(format!(
-                    "{}{}",
-                    right,
-                    Self::C_WILDCARD
-                ))));
-
-                self.write(" LIKE ")?;
-                self.parameter_substitution()
+                let pattern = like_pattern("", &right, Self::C_WILDCARD);
+                self.visit_like(*left, pattern, false)
             }
             Compare::NotBeginsWith(left, right) => {
-                self.visit_database_value(*left)?;
-
-                self.add_parameter(ParameterizedValue::Text(Cow::from(format!(
-                    "{}{}",
-                    right,
-                    Self::C_WILDCARD
-                ))));
-
-                self.write(" NOT LIKE ")?;
-                self.parameter_substitution()
+                let pattern = like_pattern("", &right, Self::C_WILDCARD);
+                self.visit_like(*left, pattern, true)
             }
             Compare::EndsInto(left, right) => {
-                self.visit_database_value(*left)?;
-
-                self.add_parameter(ParameterizedValue::Text(Cow::from(format!(
-                    "{}{}",
-                    Self::C_WILDCARD,
-                    right,
-                ))));
-
-                self.write(" LIKE ")?;
-                self.parameter_substitution()
+                let pattern = like_pattern(Self::C_WILDCARD, &right, "");
+                self.visit_like(*left, pattern, false)
             }
             Compare::NotEndsInto(left, right) => {
-                self.visit_database_value(*left)?;
-
-                self.add_parameter(ParameterizedValue::Text(Cow::from(format!(
-                    "{}{}",
-                    Self::C_WILDCARD,
-                    right,
-                ))));
-
-                self.write(" NOT LIKE ")?;
-                self.parameter_substitution()
+                let pattern = like_pattern(Self::C_WILDCARD, &right, "");
+                self.visit_like(*left, pattern, true)
+            }
+            Compare::ILike(left, right) => {
+                let pattern = like_pattern(Self::C_WILDCARD, &right, Self::C_WILDCARD);
+                self.visit_ilike(*left, pattern, false)
             }
+            Compare::NotILike(left, right) => {
+                let pattern = like_pattern(Self::C_WILDCARD, &right, Self::C_WILDCARD);
+                self.visit_ilike(*left, pattern, true)
+            }
+            Compare::MatchesRegex(left, pattern) => self.visit_regex(*left, pattern),
+            #[cfg(feature = "json-1")]
+            Compare::JsonContains(left, right) => self.visit_json_contains(*left, *right),
+            #[cfg(feature = "json-1")]
+            Compare::JsonHasKey(left, key) => self.visit_json_has_key(*left, key),
+            #[cfg(feature = "json-1")]
+            Compare::JsonHasAnyKey(left, keys) => self.visit_json_has_any_key(*left, keys),
+            #[cfg(all(feature = "array", feature = "postgresql"))]
+            compare @ Compare::ArrayContains(..)
+            | compare @ Compare::ArrayContainedBy(..)
+            | compare @ Compare::ArrayOverlaps(..)
+            | compare @ Compare::EqualsAny(..) => self.visit_array_compare(compare),
             Compare::Null(column) => {
                 self.visit_database_value(*column)?;
                 self.write(" IS NULL")
@@ -613,9 +1038,169 @@ pub trait Visitor<'a> {
                 self.write(" AND ")?;
                 self.visit_database_value(*right)
             }
+            Compare::Exists(selection) => {
+                self.write("EXISTS ")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_select(*selection))
+            }
+            Compare::NotExists(selection) => {
+                self.write("NOT EXISTS ")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_select(*selection))
+            }
+            Compare::Any(left, operator, selection) => self.visit_any(*left, operator, *selection),
+            Compare::All(left, operator, selection) => self.visit_all(*left, operator, *selection),
+        }
+    }
+
+    /// A `LIKE` comparison. Wildcards and backslashes in the pattern given
+    /// by the user are escaped with a backslash.
+    fn visit_like(&mut self, left: DatabaseValue<'a>, pattern: LikePattern, negated: bool) -> fmt::Result {
+        self.visit_database_value(left)?;
+        self.write(if negated { " NOT LIKE " } else { " LIKE " })?;
+
+        self.visit_parameterized(ParameterizedValue::Text(Cow::from(pattern.value)))?;
+
+        if pattern.escaped {
+            self.visit_like_escape()?;
+        }
+
+        Ok(())
+    }
+
+    /// An array comparison, such as `@>`. Arrays only exist on PostgreSQL,
+    /// the other visitors mark the query as unsupported.
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    fn visit_array_compare(&mut self, compare: Compare<'a>) -> fmt::Result;
+
+    /// An `IN` comparison against a row of values, a subselect or an array
+    /// parameter.
+    fn visit_in(&mut self, left: DatabaseValue<'a>, right: DatabaseValue<'a>, negated: bool) -> fmt::Result {
+        self.visit_database_value(left)?;
+        self.write(if negated { " NOT IN " } else { " IN " })?;
+        self.visit_database_value(right)
+    }
+
+    /// A case-insensitive `LIKE` comparison, comparing the lowercase values.
+    fn visit_ilike(&mut self, left: DatabaseValue<'a>, pattern: LikePattern, negated: bool) -> fmt::Result {
+        self.write("LOWER")?;
+        self.surround_with("(", ")", |ref mut s| s.visit_database_value(left))?;
+        self.write(if negated { " NOT LIKE " } else { " LIKE " })?;
+
+        let LikePattern { value, escaped } = pattern;
+
+        self.write("LOWER")?;
+        self.surround_with("(", ")", |ref mut s| {
+            s.visit_parameterized(ParameterizedValue::Text(Cow::from(value)))
+        })?;
+
+        if escaped {
+            self.visit_like_escape()?;
+        }
+
+        Ok(())
+    }
+
+    /// The `ESCAPE` clause of a `LIKE` comparison with escaped characters in
+    /// the pattern. Nothing is needed when the database escapes with a
+    /// backslash by default.
+    fn visit_like_escape(&mut self) -> fmt::Result {
+        Ok(())
+    }
+
+    /// A regular expression match
+    fn visit_regex(&mut self, left: DatabaseValue<'a>, pattern: Cow<'a, str>) -> fmt::Result {
+        self.visit_database_value(left)?;
+        self.write(" REGEXP ")?;
+
+        self.visit_parameterized(ParameterizedValue::Text(pattern))
+    }
+
+    /// A value extracted from a JSON document with `JSON_EXTRACT`
+    #[cfg(feature = "json-1")]
+    fn visit_json_extract(&mut self, extract: JsonExtract<'a>) -> fmt::Result {
+        let JsonExtract { expr, path, as_text } = extract;
+        let path = json_path(&path);
+
+        let extract_value = |s: &mut Self| {
+            s.write("JSON_EXTRACT")?;
+            s.surround_with("(", ")", |ref mut s| {
+                s.visit_database_value(*expr)?;
+                s.write(", ")?;
+                s.visit_parameterized(ParameterizedValue::Text(path.into()))
+            })
+        };
+
+        if as_text {
+            self.write("JSON_UNQUOTE")?;
+            self.surround_with("(", ")", extract_value)
+        } else {
+            extract_value(self)
         }
     }
 
+    /// Tests if the left side contains the right side JSON document
+    #[cfg(feature = "json-1")]
+    fn visit_json_contains(&mut self, left: DatabaseValue<'a>, right: DatabaseValue<'a>) -> fmt::Result {
+        self.write("JSON_CONTAINS")?;
+        self.surround_with("(", ")", |ref mut s| {
+            s.visit_database_value(left)?;
+            s.write(", ")?;
+            s.visit_database_value(right)
+        })
+    }
+
+    /// Tests if the left side is an object with the given key
+    #[cfg(feature = "json-1")]
+    fn visit_json_has_key(&mut self, left: DatabaseValue<'a>, key: Cow<'a, str>) -> fmt::Result {
+        self.visit_json_has_any_key(left, vec![key])
+    }
+
+    /// Tests if the left side is an object with any of the given keys
+    #[cfg(feature = "json-1")]
+    fn visit_json_has_any_key(&mut self, left: DatabaseValue<'a>, keys: Vec<Cow<'a, str>>) -> fmt::Result {
+        if keys.is_empty() {
+            return self.write("1=0");
+        }
+
+        self.write("JSON_CONTAINS_PATH")?;
+        self.surround_with("(", ")", |ref mut s| {
+            s.visit_database_value(left)?;
+            s.write(", 'one'")?;
+
+            for key in keys {
+                let path = json_path(&[JsonPathElement::Key(key)]);
+
+                s.write(", ")?;
+                s.visit_parameterized(ParameterizedValue::Text(path.into()))?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// A comparison that is true for at least one row of the query
+    fn visit_any(
+        &mut self,
+        left: DatabaseValue<'a>,
+        operator: ComparisonOperator,
+        selection: Select<'a>,
+    ) -> fmt::Result {
+        self.visit_database_value(left)?;
+        self.write(format!(" {} ANY ", operator))?;
+        self.surround_with("(", ")", |ref mut s| s.visit_select(selection))
+    }
+
+    /// A comparison that is true for every row of the query
+    fn visit_all(
+        &mut self,
+        left: DatabaseValue<'a>,
+        operator: ComparisonOperator,
+        selection: Select<'a>,
+    ) -> fmt::Result {
+        self.visit_database_value(left)?;
+        self.write(format!(" {} ALL ", operator))?;
+        self.surround_with("(", ")", |ref mut s| s.visit_select(selection))
+    }
+
     /// A visit in the `ORDER BY` section of the query
     fn visit_ordering(&mut self, ordering: Ordering<'a>) -> fmt::Result {
         let len = ordering.0.len();
@@ -655,16 +1240,15 @@ pub trait Visitor<'a> {
     fn visit_function(&mut self, fun: Function<'a>) -> fmt::Result {
         match fun.typ_ {
             FunctionType::RowNumber(fun_rownum) => {
-                if fun_rownum.over.is_empty() {
-                    self.write("ROW_NUMBER() OVER()")?;
-                } else {
-                    self.write("ROW_NUMBER() OVER")?;
-                    self.surround_with("(", ")", |ref mut s| s.visit_partitioning(fun_rownum.over))?;
-                }
+                self.write("ROW_NUMBER() ")?;
+                self.visit_over(fun_rownum.over)?;
             }
             FunctionType::Count(fun_count) => {
                 if fun_count.exprs.is_empty() {
                     self.write("COUNT(*)")?;
+                } else if fun_count.distinct {
+                    self.write("COUNT")?;
+                    self.surround_with("(DISTINCT ", ")", |ref mut s| s.visit_columns(fun_count.exprs))?;
                 } else {
                     self.write("COUNT")?;
                     self.surround_with("(", ")", |ref mut s| s.visit_columns(fun_count.exprs))?;
@@ -673,8 +1257,101 @@ pub trait Visitor<'a> {
             FunctionType::AggregateToString(agg) => {
                 self.visit_aggregate_to_string(agg.value.as_ref().clone())?;
             }
+            FunctionType::Sum(sum) => {
+                self.write("SUM")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_database_value(*sum.expr))?;
+            }
+            FunctionType::Average(avg) => {
+                self.write("AVG")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_database_value(*avg.expr))?;
+            }
+            FunctionType::Minimum(min) => {
+                self.write("MIN")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_database_value(*min.expr))?;
+            }
+            FunctionType::Maximum(max) => {
+                self.write("MAX")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_database_value(*max.expr))?;
+            }
+            FunctionType::Coalesce(coalesce) => {
+                self.write("COALESCE")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_columns(coalesce.exprs))?;
+            }
+            FunctionType::Lower(lower) => {
+                self.write("LOWER")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_database_value(*lower.expr))?;
+            }
+            FunctionType::Upper(upper) => {
+                self.write("UPPER")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_database_value(*upper.expr))?;
+            }
+            FunctionType::Length(length) => {
+                self.visit_length(*length.expr)?;
+            }
+            FunctionType::Abs(abs) => {
+                self.write("ABS")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_database_value(*abs.expr))?;
+            }
+            #[cfg(all(feature = "array", feature = "postgresql"))]
+            FunctionType::ArrayLength(array_length) => {
+                self.write("ARRAY_LENGTH")?;
+                self.surround_with("(", ")", |ref mut s| {
+                    s.visit_database_value(*array_length.expr)?;
+                    s.write(", 1")
+                })?;
+            }
+            FunctionType::Concat(concat) => {
+                self.visit_concat(concat.exprs)?;
+            }
+            FunctionType::Cast(cast) => {
+                self.write("CAST(")?;
+                self.visit_database_value(*cast.expr)?;
+                self.write(" AS ")?;
+                self.visit_cast_type(cast.typ)?;
+                self.write(")")?;
+            }
+            FunctionType::CurrentTimestamp => {
+                self.visit_current_timestamp()?;
+            }
+            FunctionType::Rank(rank) => {
+                self.write("RANK() ")?;
+                self.visit_over(rank.over)?;
+            }
+            FunctionType::DenseRank(rank) => {
+                self.write("DENSE_RANK() ")?;
+                self.visit_over(rank.over)?;
+            }
+            FunctionType::Lag(lag) => {
+                self.write("LAG")?;
+                self.visit_offset_arguments(*lag.expr, lag.offset, lag.default.map(|d| *d))?;
+                self.write(" ")?;
+                self.visit_over(lag.over)?;
+            }
+            FunctionType::Lead(lead) => {
+                self.write("LEAD")?;
+                self.visit_offset_arguments(*lead.expr, lead.offset, lead.default.map(|d| *d))?;
+                self.write(" ")?;
+                self.visit_over(lead.over)?;
+            }
+            FunctionType::FirstValue(FirstValue { expr, over }) => {
+                self.write("FIRST_VALUE")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_database_value(*expr))?;
+                self.write(" ")?;
+                self.visit_over(over)?;
+            }
+            FunctionType::LastValue(LastValue { expr, over }) => {
+                self.write("LAST_VALUE")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_database_value(*expr))?;
+                self.write(" ")?;
+                self.visit_over(over)?;
+            }
         };
 
+        if let Some(over) = fun.over {
+            self.write(" ")?;
+            self.visit_over(*over)?;
+        }
+
         if let Some(alias) = fun.alias {
             self.write(" AS ")?;
             self.delimited_identifiers(&[&*alias])?;
@@ -683,9 +1360,100 @@ pub trait Visitor<'a> {
         Ok(())
     }
 
+    /// The number of characters in a string
+    fn visit_length(&mut self, value: DatabaseValue<'a>) -> fmt::Result {
+        self.write("LENGTH")?;
+        self.surround_with("(", ")", |ref mut s| s.visit_database_value(value))
+    }
+
+    /// The current date and time
+    fn visit_current_timestamp(&mut self) -> fmt::Result {
+        self.write("CURRENT_TIMESTAMP")
+    }
+
+    /// The concatenation of strings
+    fn visit_concat(&mut self, values: Vec<DatabaseValue<'a>>) -> fmt::Result {
+        self.write("CONCAT")?;
+        self.surround_with("(", ")", |ref mut s| s.visit_columns(values))
+    }
+
+    /// The type name in a `CAST`
+    fn visit_cast_type(&mut self, typ: CastType<'a>) -> fmt::Result {
+        match typ {
+            CastType::Int => self.write("BIGINT"),
+            CastType::Float => self.write("DOUBLE PRECISION"),
+            CastType::Decimal => self.write("NUMERIC"),
+            CastType::Text => self.write("TEXT"),
+            CastType::Custom(name) => self.write(name),
+        }
+    }
+
+    /// The arguments of `LAG` and `LEAD`
+    fn visit_offset_arguments(
+        &mut self,
+        value: DatabaseValue<'a>,
+        offset: Option<u64>,
+        default: Option<DatabaseValue<'a>>,
+    ) -> fmt::Result {
+        self.surround_with("(", ")", |ref mut s| {
+            s.visit_database_value(value)?;
+
+            if offset.is_some() || default.is_some() {
+                s.write(", ")?;
+                s.write(offset.unwrap_or(1))?;
+            }
+
+            if let Some(default) = default {
+                s.write(", ")?;
+                s.visit_database_value(default)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// The `OVER` clause of a window function
+    fn visit_over(&mut self, over: Over<'a>) -> fmt::Result {
+        match over.window {
+            Some(ref name) if over.partitioning.is_empty() && over.ordering.is_empty() && over.frame.is_none() => {
+                self.write("OVER ")?;
+                self.delimited_identifiers(&[name.as_ref()])
+            }
+            _ => {
+                self.write("OVER")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_partitioning(over))
+            }
+        }
+    }
+
+    /// The named windows of the `WINDOW` clause
+    fn visit_windows(&mut self, windows: Vec<(Cow<'a, str>, Over<'a>)>) -> fmt::Result {
+        let len = windows.len();
+
+        for (i, (name, over)) in windows.into_iter().enumerate() {
+            self.delimited_identifiers(&[&*name])?;
+            self.write(" AS ")?;
+            self.surround_with("(", ")", |ref mut s| s.visit_partitioning(over))?;
+
+            if i < (len - 1) {
+                self.write(", ")?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn visit_partitioning(&mut self, over: Over<'a>) -> fmt::Result {
+        let mut separator = "";
+
+        if let Some(window) = over.window {
+            self.delimited_identifiers(&[&*window])?;
+            separator = " ";
+        }
+
         if !over.partitioning.is_empty() {
             let len = over.partitioning.len();
+            self.write(separator)?;
             self.write("PARTITION BY ")?;
 
             for (i, partition) in over.partitioning.into_iter().enumerate() {
@@ -696,16 +1464,55 @@ pub trait Visitor<'a> {
                 }
             }
 
-            if !over.ordering.is_empty() {
-                self.write(" ")?;
-            }
+            separator = " ";
         }
 
         if !over.ordering.is_empty() {
+            self.write(separator)?;
             self.write("ORDER BY ")?;
             self.visit_ordering(over.ordering)?;
+            separator = " ";
+        }
+
+        if let Some(frame) = over.frame {
+            self.write(separator)?;
+            self.write(frame.units)?;
+            self.write(" BETWEEN ")?;
+            self.write(frame.start)?;
+            self.write(" AND ")?;
+            self.write(frame.end)?;
         }
 
         Ok(())
     }
 }
+
+/// A `LIKE` pattern, with the wildcards and the escape character of the
+/// user-given part escaped.
+pub struct LikePattern {
+    value: String,
+    escaped: bool,
+}
+
+fn like_pattern(prefix: &str, value: &str, suffix: &str) -> LikePattern {
+    let mut pattern = String::with_capacity(prefix.len() + value.len() + suffix.len());
+    let mut escaped = false;
+
+    pattern.push_str(prefix);
+
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            pattern.push('\\');
+            escaped = true;
+        }
+
+        pattern.push(c);
+    }
+
+    pattern.push_str(suffix);
+
+    LikePattern {
+        value: pattern,
+        escaped,
+    }
+}
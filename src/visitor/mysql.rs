@@ -7,6 +7,8 @@ use std::fmt::{self, Write};
 pub struct Mysql<'a> {
     query: String,
     parameters: Vec<ParameterizedValue<'a>>,
+    inline_values: bool,
+    unsupported: Option<&'static str>,
 }
 
 impl<'a> Visitor<'a> for Mysql<'a> {
@@ -17,13 +19,7 @@ impl<'a> Visitor<'a> for Mysql<'a> {
     where
         Q: Into<Query<'a>>,
     {
-        let mut mysql = Mysql {
-            query: String::with_capacity(4096),
-            parameters: Vec::with_capacity(128),
-        };
-
-        Mysql::visit_query(&mut mysql, query.into());
-
+        let mysql = Mysql::render(query.into());
         (mysql.query, mysql.parameters)
     }
 
@@ -31,16 +27,37 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         write!(&mut self.query, "{}", s)
     }
 
+    /// MySQL has no `DISTINCT ON`, the connector refuses the query.
+    fn visit_distinct_on(&mut self, columns: Vec<Column<'a>>) -> fmt::Result {
+        self.unsupported("DISTINCT ON");
+
+        self.write("DISTINCT ON (")?;
+        self.visit_columns(columns.into_iter().map(|c| c.into()).collect())?;
+        self.write(") ")
+    }
+
+    /// MySQL has no arrays, the connector refuses the query and nothing is
+    /// rendered.
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    fn visit_array_compare(&mut self, _: Compare<'a>) -> fmt::Result {
+        self.unsupported("Array comparisons");
+        Ok(())
+    }
+
     fn visit_insert(&mut self, insert: Insert<'a>) -> fmt::Result {
+        if let Some(with) = insert.with {
+            self.visit_with(with)?;
+        }
+
         match insert.on_conflict {
             Some(OnConflict::DoNothing) => self.write("INSERT IGNORE INTO ")?,
-            None => self.write("INSERT INTO ")?,
+            _ => self.write("INSERT INTO ")?,
         };
 
         self.visit_table(insert.table, true)?;
 
         if insert.values.is_empty() {
-            self.write(" () VALUES ()")
+            self.write(" () VALUES ()")?;
         } else {
             let columns = insert.columns.len();
 
@@ -64,9 +81,22 @@ impl<'a> Visitor<'a> for Mysql<'a> {
                     self.write(", ")?;
                 }
             }
+        }
 
-            Ok(())
+        if let Some(OnConflict::DoUpdate(update)) = insert.on_conflict {
+            self.visit_upsert(update)?;
         }
+
+        Ok(())
+    }
+
+    fn visit_upsert(&mut self, update: ConflictUpdate<'a>) -> fmt::Result {
+        self.write(" ON DUPLICATE KEY UPDATE ")?;
+        self.visit_assignments(update.columns, update.values)
+    }
+
+    fn visit_excluded(&mut self, column: Column<'a>) -> fmt::Result {
+        self.surround_with("VALUES(", ")", |ref mut s| s.delimited_identifiers(&[&*column.name]))
     }
 
     fn parameter_substitution(&mut self) -> fmt::Result {
@@ -77,6 +107,10 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         self.parameters.push(value);
     }
 
+    fn inline_values(&self) -> bool {
+        self.inline_values
+    }
+
     fn visit_limit_and_offset(
         &mut self,
         limit: Option<ParameterizedValue<'a>>,
@@ -111,6 +145,22 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         self.surround_with("(", ")", |ref mut s| s.visit_database_value(value))
     }
 
+    /// `LENGTH` counts bytes on MySQL.
+    fn visit_length(&mut self, value: DatabaseValue<'a>) -> fmt::Result {
+        self.write("CHAR_LENGTH")?;
+        self.surround_with("(", ")", |ref mut s| s.visit_database_value(value))
+    }
+
+    fn visit_cast_type(&mut self, typ: CastType<'a>) -> fmt::Result {
+        match typ {
+            CastType::Int => self.write("SIGNED"),
+            CastType::Float => self.write("DOUBLE"),
+            CastType::Decimal => self.write("DECIMAL(65, 30)"),
+            CastType::Text => self.write("CHAR"),
+            CastType::Custom(name) => self.write(name),
+        }
+    }
+
     fn visit_values(&mut self, values: Values<'a>) -> fmt::Result {
         self.surround_with("(VALUES ", ")", |ref mut s| {
             let len = values.len();
@@ -125,6 +175,85 @@ impl<'a> Visitor<'a> for Mysql<'a> {
             Ok(())
         })
     }
+
+    fn visit_column_type(&mut self, column_type: ColumnType<'a>, _: bool) -> fmt::Result {
+        match column_type {
+            ColumnType::Integer => self.write("INT"),
+            ColumnType::BigInt => self.write("BIGINT"),
+            ColumnType::Boolean => self.write("BOOLEAN"),
+            ColumnType::Text => self.write("TEXT"),
+            ColumnType::Varchar(length) => self.write(format!("VARCHAR({})", length)),
+            ColumnType::Double => self.write("DOUBLE"),
+            ColumnType::Decimal(precision, scale) => self.write(format!("DECIMAL({}, {})", precision, scale)),
+            ColumnType::Date => self.write("DATE"),
+            ColumnType::Time => self.write("TIME"),
+            ColumnType::DateTime => self.write("DATETIME(3)"),
+            ColumnType::Bytes => self.write("BLOB"),
+            ColumnType::Json => self.write("JSON"),
+            ColumnType::Uuid => self.write("CHAR(36)"),
+            ColumnType::Custom(name) => self.write(name),
+        }
+    }
+
+    fn visit_auto_increment(&mut self) -> fmt::Result {
+        self.write(" AUTO_INCREMENT")
+    }
+
+    /// MySQL escapes with backslashes in string literals.
+    fn visit_string_literal(&mut self, value: &str) -> fmt::Result {
+        let escaped = value.replace('\\', "\\\\").replace('\'', "''");
+        self.surround_with("'", "'", |ref mut s| s.write(escaped))
+    }
+
+    /// MySQL drops the index of a table, and has no `IF EXISTS`.
+    fn visit_drop_index(&mut self, drop_index: DropIndex<'a>) -> fmt::Result {
+        self.write("DROP INDEX ")?;
+        self.delimited_identifiers(&[&*drop_index.name])?;
+        self.write(" ON ")?;
+        self.visit_table(drop_index.table, false)
+    }
+}
+
+impl<'a> Mysql<'a> {
+    /// Renders the query like `build`, returning an `UnsupportedFeature`
+    /// error if it uses something MySQL doesn't have.
+    #[cfg(feature = "mysql")]
+    pub(crate) fn build_supported<Q>(query: Q) -> crate::Result<(String, Vec<ParameterizedValue<'a>>)>
+    where
+        Q: Into<Query<'a>>,
+    {
+        let mysql = Mysql::render(query.into());
+
+        match mysql.unsupported {
+            Some(feature) => {
+                let kind = crate::error::ErrorKind::UnsupportedFeature {
+                    feature,
+                    database: "MySQL",
+                };
+
+                Err(crate::error::Error::builder(kind).build())
+            }
+            None => Ok((mysql.query, mysql.parameters)),
+        }
+    }
+
+    fn render(query: Query<'a>) -> Self {
+        let mut mysql = Mysql {
+            query: String::with_capacity(4096),
+            parameters: Vec::with_capacity(128),
+            inline_values: query.is_ddl(),
+            unsupported: None,
+        };
+
+        Mysql::visit_query(&mut mysql, query);
+
+        mysql
+    }
+
+    /// Marks the query as unsupported, keeping the first feature found.
+    fn unsupported(&mut self, feature: &'static str) {
+        self.unsupported.get_or_insert(feature);
+    }
 }
 
 #[cfg(test)]
@@ -179,6 +308,26 @@ mod tests {
         assert_eq!(expected.1, params);
     }
 
+    #[test]
+    fn test_insert_on_duplicate_key_update() {
+        let expected = expected_values(
+            "INSERT INTO `users` (`id`,`name`) VALUES (?,?) ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)",
+            vec![ParameterizedValue::from(1), ParameterizedValue::from("Musti")],
+        );
+
+        let update = ConflictUpdate::columns(vec!["id"]).set("name", excluded("name"));
+        let query = Insert::single_into("users")
+            .value("id", 1)
+            .value("name", "Musti")
+            .build()
+            .on_conflict(OnConflict::DoUpdate(update));
+
+        let (sql, params) = Mysql::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
     #[test]
     fn test_limit_and_offset_when_both_are_set() {
         let expected = expected_values("SELECT `users`.* FROM `users` LIMIT ? OFFSET ?", vec![10, 2]);
@@ -255,4 +404,192 @@ mod tests {
             params
         );
     }
+
+    #[test]
+    fn test_with_delete() {
+        let expected_sql =
+            "WITH `old` AS (SELECT `id` FROM `posts` WHERE `year` < ?) DELETE FROM `posts` WHERE `id` IN (SELECT `id` FROM `old`)";
+
+        let old = Select::from_table("posts").column("id").so_that("year".less_than(2000));
+
+        let query = Delete::from_table("posts")
+            .so_that("id".in_selection(Select::from_table("old").column("id")))
+            .with(With::new().query("old", old));
+
+        let (sql, params) = Mysql::build(query);
+
+        assert_eq!(expected_sql, sql);
+        assert_eq!(vec![ParameterizedValue::Integer(2000)], params);
+    }
+
+    #[test]
+    fn test_distinct() {
+        let expected_sql = "SELECT DISTINCT `name`, `age` FROM `users`";
+        let query = Select::from_table("users").columns(vec!["name", "age"]).distinct();
+        let (sql, _) = Mysql::build(query);
+
+        assert_eq!(expected_sql, sql);
+    }
+
+    #[test]
+    fn test_string_functions() {
+        let expected_sql = "SELECT CHAR_LENGTH(`name`), LOWER(`name`), UPPER(`name`), CONCAT(`name`, `id`), \
+                            CAST(`id` AS DECIMAL(65, 30)) FROM `users` WHERE `created_at` < CURRENT_TIMESTAMP";
+
+        let query = Select::from_table("users")
+            .value(length(Column::new("name")))
+            .value(lower(Column::new("name")))
+            .value(upper(Column::new("name")))
+            .value(concat(vec![Column::new("name"), Column::new("id")]))
+            .value(cast(Column::new("id"), CastType::Decimal))
+            .so_that("created_at".less_than(now()));
+
+        let (sql, _) = Mysql::build(query);
+
+        assert_eq!(expected_sql, sql);
+    }
+
+    #[test]
+    fn test_named_windows() {
+        let expected_sql =
+            "SELECT `id`, RANK() OVER `w`, FIRST_VALUE(`id`) OVER(`w` ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) \
+                            FROM `users` WINDOW `w` AS (PARTITION BY `team` ORDER BY `score` DESC) ORDER BY `id`";
+
+        let frame = Over::window("w").rows(FrameBound::Preceding(1), FrameBound::Following(1));
+
+        let query = Select::from_table("users")
+            .column("id")
+            .value(rank().over(Over::window("w")))
+            .value(first_value(Column::new("id")).over(frame))
+            .window("w", Over::new().partition_by("team").order_by("score".descend()))
+            .order_by("id");
+
+        let (sql, _) = Mysql::build(query);
+
+        assert_eq!(expected_sql, sql);
+    }
+
+    #[test]
+    fn test_ilike_and_regex() {
+        let expected_sql = "SELECT `users`.* FROM `users` WHERE (LOWER(`name`) LIKE LOWER(?) \
+                            AND `email` REGEXP ? AND `nick` LIKE ?)";
+
+        let conditions = "name"
+            .ilike("musti")
+            .and("email".matches_regex("^m"))
+            .and("nick".ends_into("100%"));
+
+        let query = Select::from_table("users").so_that(conditions);
+        let (sql, params) = Mysql::build(query);
+
+        assert_eq!(expected_sql, sql);
+
+        assert_eq!(
+            vec![
+                ParameterizedValue::from("%musti%"),
+                ParameterizedValue::from("^m"),
+                ParameterizedValue::from("%100\\%"),
+            ],
+            params
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "json-1")]
+    fn test_json_operations() {
+        let expected_sql = "SELECT JSON_UNQUOTE(JSON_EXTRACT(`data`, ?)) FROM `users` \
+                            WHERE (JSON_CONTAINS_PATH(`data`, 'one', ?) AND JSON_CONTAINS(JSON_EXTRACT(`data`, ?), ?))";
+
+        let conditions = "data"
+            .json_has_key("email")
+            .and("data".json_get("tags").json_contains(serde_json::json!("cat")));
+
+        let query = Select::from_table("users")
+            .value("data".json_get_text("name"))
+            .so_that(conditions);
+
+        let (sql, params) = Mysql::build(query);
+
+        assert_eq!(expected_sql, sql);
+
+        assert_eq!(
+            vec![
+                ParameterizedValue::from("$.\"name\""),
+                ParameterizedValue::from("$.\"email\""),
+                ParameterizedValue::from("$.\"tags\""),
+                ParameterizedValue::from(serde_json::json!("cat")),
+            ],
+            params
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "json-1")]
+    fn test_json_has_any_of_no_keys() {
+        let query = Select::from_table("users").so_that("data".json_has_any_key(Vec::<&str>::new()));
+        let (sql, params) = Mysql::build(query);
+
+        assert_eq!("SELECT `users`.* FROM `users` WHERE 1=0", sql);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_exists_and_quantified_comparisons() {
+        let expected_sql = "SELECT `users`.* FROM `users` WHERE (NOT EXISTS \
+                            (SELECT `bans`.* FROM `bans` WHERE `bans`.`user_id` = `users`.`id`) \
+                            AND `score` >= ALL (SELECT `score` FROM `users`))";
+
+        let bans = Select::from_table("bans").so_that(("bans", "user_id").equals(Column::from(("users", "id"))));
+        let scores = Select::from_table("users").column("score");
+
+        let conditions = not_exists(bans).and("score".all(ComparisonOperator::GreaterThanOrEquals, scores));
+        let query = Select::from_table("users").so_that(conditions);
+        let (sql, _) = Mysql::build(query);
+
+        assert_eq!(expected_sql, sql);
+    }
+
+    #[test]
+    fn test_create_table() {
+        let expected_sql = "CREATE TABLE `users` (`id` INT PRIMARY KEY AUTO_INCREMENT, \
+                            `name` TEXT NOT NULL DEFAULT 'C:\\\\Users', `created_at` DATETIME(3), \
+                            `external_id` CHAR(36) UNIQUE, `settings` JSON)";
+
+        let query = CreateTable::new("users")
+            .column(
+                ColumnDefinition::new("id", ColumnType::Integer)
+                    .primary_key()
+                    .auto_increment(),
+            )
+            .column(
+                ColumnDefinition::new("name", ColumnType::Text)
+                    .not_null()
+                    .default("C:\\Users"),
+            )
+            .column(ColumnDefinition::new("created_at", ColumnType::DateTime))
+            .column(ColumnDefinition::new("external_id", ColumnType::Uuid).unique())
+            .column(ColumnDefinition::new("settings", ColumnType::Json));
+
+        let (sql, params) = Mysql::build(query);
+
+        assert_eq!(expected_sql, sql);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_alter_table_and_drop_index() {
+        let column = ColumnDefinition::new("score", ColumnType::Decimal(10, 2)).default(ParameterizedValue::Null);
+        let (sql, _) = Mysql::build(AlterTable::add_column("users", column));
+
+        assert_eq!(
+            "ALTER TABLE `users` ADD COLUMN `score` DECIMAL(10, 2) DEFAULT NULL",
+            sql
+        );
+
+        let (sql, _) = Mysql::build(AlterTable::rename_column("users", "score", "points"));
+        assert_eq!("ALTER TABLE `users` RENAME COLUMN `score` TO `points`", sql);
+
+        let (sql, _) = Mysql::build(DropIndex::new("users_score_idx", "users"));
+        assert_eq!("DROP INDEX `users_score_idx` ON `users`", sql);
+    }
 }
@@ -1,5 +1,11 @@
-use crate::{ast::*, visitor::Visitor};
-use std::fmt::{self, Write};
+use crate::{
+    ast::*,
+    visitor::{LikePattern, Visitor},
+};
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
 
 /// A visitor to generate queries for the PostgreSQL database.
 ///
@@ -8,6 +14,7 @@ use std::fmt::{self, Write};
 pub struct Postgres<'a> {
     query: String,
     parameters: Vec<ParameterizedValue<'a>>,
+    inline_values: bool,
 }
 
 impl<'a> Visitor<'a> for Postgres<'a> {
@@ -18,12 +25,15 @@ impl<'a> Visitor<'a> for Postgres<'a> {
     where
         Q: Into<Query<'a>>,
     {
+        let query = query.into();
+
         let mut postgres = Postgres {
             query: String::with_capacity(4096),
             parameters: Vec::with_capacity(128),
+            inline_values: query.is_ddl(),
         };
 
-        Postgres::visit_query(&mut postgres, query.into());
+        Postgres::visit_query(&mut postgres, query);
 
         (postgres.query, postgres.parameters)
     }
@@ -36,6 +46,10 @@ impl<'a> Visitor<'a> for Postgres<'a> {
         self.parameters.push(value);
     }
 
+    fn inline_values(&self) -> bool {
+        self.inline_values
+    }
+
     fn parameter_substitution(&mut self) -> fmt::Result {
         self.write("$")?;
         self.write(self.parameters.len())
@@ -67,6 +81,10 @@ impl<'a> Visitor<'a> for Postgres<'a> {
     }
 
     fn visit_insert(&mut self, insert: Insert<'a>) -> fmt::Result {
+        if let Some(with) = insert.with {
+            self.visit_with(with)?;
+        }
+
         self.write("INSERT INTO ")?;
         self.visit_table(insert.table, true)?;
 
@@ -97,19 +115,13 @@ impl<'a> Visitor<'a> for Postgres<'a> {
             }
         }
 
-        if let Some(OnConflict::DoNothing) = insert.on_conflict {
-            self.write(" ON CONFLICT DO NOTHING")?;
-        };
-
-        if let Some(returning) = insert.returning {
-            if !returning.is_empty() {
-                let values = returning.into_iter().map(|r| r.into()).collect();
-                self.write(" RETURNING ")?;
-                self.visit_columns(values)?;
-            }
+        match insert.on_conflict {
+            Some(OnConflict::DoNothing) => self.write(" ON CONFLICT DO NOTHING")?,
+            Some(OnConflict::DoUpdate(update)) => self.visit_upsert(update)?,
+            None => (),
         };
 
-        Ok(())
+        self.visit_returning(insert.returning)
     }
 
     fn visit_aggregate_to_string(&mut self, value: DatabaseValue<'a>) -> fmt::Result {
@@ -122,6 +134,266 @@ impl<'a> Visitor<'a> for Postgres<'a> {
         self.write("','")?;
         self.write(")")
     }
+
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    fn visit_in(&mut self, left: DatabaseValue<'a>, right: DatabaseValue<'a>, negated: bool) -> fmt::Result {
+        self.visit_database_value(left)?;
+
+        match right {
+            DatabaseValue::Parameterized(array @ ParameterizedValue::Array(_)) => {
+                self.write(if negated { " <> ALL" } else { " = ANY" })?;
+                self.surround_with("(", ")", |ref mut s| s.visit_parameterized(array))
+            }
+            DatabaseValue::Row(row) if row.values.len() > IN_LIST_ARRAY_THRESHOLD => match bindable_array(row) {
+                Ok(array) => {
+                    self.write(if negated { " <> ALL" } else { " = ANY" })?;
+                    self.surround_with("(", ")", |ref mut s| s.visit_parameterized(array))
+                }
+                Err(row) => {
+                    self.write(if negated { " NOT IN " } else { " IN " })?;
+                    self.visit_row(row)
+                }
+            },
+            right => {
+                self.write(if negated { " NOT IN " } else { " IN " })?;
+                self.visit_database_value(right)
+            }
+        }
+    }
+
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    fn visit_array_compare(&mut self, compare: Compare<'a>) -> fmt::Result {
+        match compare {
+            Compare::ArrayContains(left, right) => {
+                self.visit_database_value(*left)?;
+                self.write(" @> ")?;
+                self.visit_database_value(*right)
+            }
+            Compare::ArrayContainedBy(left, right) => {
+                self.visit_database_value(*left)?;
+                self.write(" <@ ")?;
+                self.visit_database_value(*right)
+            }
+            Compare::ArrayOverlaps(left, right) => {
+                self.visit_database_value(*left)?;
+                self.write(" && ")?;
+                self.visit_database_value(*right)
+            }
+            Compare::EqualsAny(left, right) => {
+                self.visit_database_value(*left)?;
+                self.write(" = ANY")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_database_value(*right))
+            }
+            compare => self.visit_compare(compare),
+        }
+    }
+
+    fn visit_ilike(&mut self, left: DatabaseValue<'a>, pattern: LikePattern, negated: bool) -> fmt::Result {
+        self.visit_database_value(left)?;
+        self.write(if negated { " NOT ILIKE " } else { " ILIKE " })?;
+
+        self.visit_parameterized(ParameterizedValue::Text(pattern.value.into()))
+    }
+
+    fn visit_regex(&mut self, left: DatabaseValue<'a>, pattern: Cow<'a, str>) -> fmt::Result {
+        self.visit_database_value(left)?;
+        self.write(" ~ ")?;
+
+        self.visit_parameterized(ParameterizedValue::Text(pattern))
+    }
+
+    #[cfg(feature = "json-1")]
+    fn visit_json_extract(&mut self, extract: JsonExtract<'a>) -> fmt::Result {
+        let JsonExtract {
+            expr,
+            mut path,
+            as_text,
+        } = extract;
+
+        self.surround_with("(", ")", |ref mut s| {
+            s.visit_database_value(*expr)?;
+
+            if path.len() == 1 {
+                s.write(if as_text { " ->> " } else { " -> " })?;
+
+                match path.pop().unwrap() {
+                    JsonPathElement::Key(key) => s.visit_parameterized(ParameterizedValue::Text(key)),
+                    JsonPathElement::Index(index) => s.write(index),
+                }
+            } else {
+                s.write(if as_text { " #>> " } else { " #> " })?;
+
+                let elements = path.into_iter().map(|element| match element {
+                    JsonPathElement::Key(key) => key,
+                    JsonPathElement::Index(index) => index.to_string().into(),
+                });
+
+                s.visit_text_array(elements)
+            }
+        })
+    }
+
+    #[cfg(feature = "json-1")]
+    fn visit_json_contains(&mut self, left: DatabaseValue<'a>, right: DatabaseValue<'a>) -> fmt::Result {
+        self.visit_database_value(left)?;
+        self.write(" @> ")?;
+        self.visit_database_value(right)
+    }
+
+    #[cfg(feature = "json-1")]
+    fn visit_json_has_key(&mut self, left: DatabaseValue<'a>, key: Cow<'a, str>) -> fmt::Result {
+        self.visit_database_value(left)?;
+        self.write(" ? ")?;
+
+        self.visit_parameterized(ParameterizedValue::Text(key))
+    }
+
+    #[cfg(feature = "json-1")]
+    fn visit_json_has_any_key(&mut self, left: DatabaseValue<'a>, keys: Vec<Cow<'a, str>>) -> fmt::Result {
+        if keys.is_empty() {
+            return self.write("1=0");
+        }
+
+        self.visit_database_value(left)?;
+        self.write(" ?| ")?;
+        self.visit_text_array(keys)
+    }
+
+    fn visit_column_type(&mut self, column_type: ColumnType<'a>, auto_increment: bool) -> fmt::Result {
+        match column_type {
+            ColumnType::Integer if auto_increment => self.write("SERIAL"),
+            ColumnType::Integer => self.write("INTEGER"),
+            ColumnType::BigInt if auto_increment => self.write("BIGSERIAL"),
+            ColumnType::BigInt => self.write("BIGINT"),
+            ColumnType::Boolean => self.write("BOOLEAN"),
+            ColumnType::Text => self.write("TEXT"),
+            ColumnType::Varchar(length) => self.write(format!("VARCHAR({})", length)),
+            ColumnType::Double => self.write("DOUBLE PRECISION"),
+            ColumnType::Decimal(precision, scale) => self.write(format!("DECIMAL({}, {})", precision, scale)),
+            ColumnType::Date => self.write("DATE"),
+            ColumnType::Time => self.write("TIME"),
+            ColumnType::DateTime => self.write("TIMESTAMPTZ"),
+            ColumnType::Bytes => self.write("BYTEA"),
+            ColumnType::Json => self.write("JSONB"),
+            ColumnType::Uuid => self.write("UUID"),
+            ColumnType::Custom(name) => self.write(name),
+        }
+    }
+
+    fn visit_bytes_literal(&mut self, bytes: &[u8]) -> fmt::Result {
+        self.surround_with("'\\x", "'::bytea", |ref mut s| {
+            for byte in bytes {
+                s.write(format!("{:02X}", byte))?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl<'a> Postgres<'a> {
+    /// Renders the query with the values written into it as literals, for
+    /// statements that can't have parameters, such as `COPY`.
+    #[cfg(feature = "postgresql")]
+    pub(crate) fn build_inlined<Q>(query: Q) -> String
+    where
+        Q: Into<Query<'a>>,
+    {
+        let mut postgres = Postgres {
+            query: String::with_capacity(4096),
+            parameters: Vec::new(),
+            inline_values: true,
+        };
+
+        Postgres::visit_query(&mut postgres, query.into());
+        postgres.query
+    }
+
+    /// A `COPY FROM STDIN` statement loading the given columns of the table
+    /// in the binary format.
+    #[cfg(feature = "postgresql")]
+    pub(crate) fn build_copy_in(table: Table<'a>, columns: Vec<Column<'a>>) -> String {
+        let mut postgres = Postgres {
+            query: String::with_capacity(256),
+            parameters: Vec::new(),
+            inline_values: false,
+        };
+
+        postgres.visit_copy_in(table, columns).unwrap();
+        postgres.query
+    }
+
+    #[cfg(feature = "postgresql")]
+    fn visit_copy_in(&mut self, table: Table<'a>, columns: Vec<Column<'a>>) -> fmt::Result {
+        self.write("COPY ")?;
+        self.visit_table(table, false)?;
+
+        self.surround_with(" (", ")", |ref mut s| {
+            for (i, column) in columns.into_iter().enumerate() {
+                if i > 0 {
+                    s.write(",")?;
+                }
+
+                s.visit_column(column)?;
+            }
+
+            Ok(())
+        })?;
+
+        self.write(" FROM STDIN (FORMAT binary)")
+    }
+
+    /// An `ARRAY[$1, $2]` of text parameters.
+    #[cfg(feature = "json-1")]
+    fn visit_text_array<I>(&mut self, values: I) -> fmt::Result
+    where
+        I: IntoIterator<Item = Cow<'a, str>>,
+    {
+        self.surround_with("ARRAY[", "]", |ref mut s| {
+            for (i, value) in values.into_iter().enumerate() {
+                if i > 0 {
+                    s.write(", ")?;
+                }
+
+                s.visit_parameterized(ParameterizedValue::Text(value))?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// `IN` comparisons against more values than this are bound as a single
+/// array parameter, if possible, so huge lists don't hit the parameter limit.
+#[cfg(all(feature = "array", feature = "postgresql"))]
+const IN_LIST_ARRAY_THRESHOLD: usize = 1000;
+
+/// Converts a row of parameters of the same type into an array parameter,
+/// returning the row back if it has anything else.
+#[cfg(all(feature = "array", feature = "postgresql"))]
+fn bindable_array(row: Row<'_>) -> Result<ParameterizedValue<'_>, Row<'_>> {
+    let mut element_type = None;
+
+    let bindable = row.values.iter().all(|value| match value {
+        DatabaseValue::Parameterized(ParameterizedValue::Null) => true,
+        DatabaseValue::Parameterized(ParameterizedValue::Array(_)) => false,
+        DatabaseValue::Parameterized(pv) => {
+            let discriminant = std::mem::discriminant(pv);
+            *element_type.get_or_insert(discriminant) == discriminant
+        }
+        _ => false,
+    });
+
+    if !bindable {
+        return Err(row);
+    }
+
+    let values = row.values.into_iter().map(|value| match value {
+        DatabaseValue::Parameterized(pv) => pv,
+        _ => unreachable!(),
+    });
+
+    Ok(ParameterizedValue::Array(values.collect()))
 }
 
 #[cfg(test)]
@@ -176,6 +448,48 @@ mod tests {
         assert_eq!(expected.1, params);
     }
 
+    #[test]
+    fn test_insert_on_conflict_do_update() {
+        let expected = expected_values(
+            "INSERT INTO \"users\" (\"id\",\"name\") VALUES ($1,$2) ON CONFLICT (\"id\") DO UPDATE SET \"name\" = excluded.\"name\" WHERE \"users\".\"locked\" = $3",
+            vec![ParameterizedValue::from(1), ParameterizedValue::from("Musti"), ParameterizedValue::from(false)],
+        );
+
+        let update = ConflictUpdate::columns(vec!["id"])
+            .set("name", excluded("name"))
+            .so_that(("users", "locked").equals(false));
+
+        let query = Insert::single_into("users")
+            .value("id", 1)
+            .value("name", "Musti")
+            .build()
+            .on_conflict(OnConflict::DoUpdate(update));
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_insert_on_conflict_on_constraint() {
+        let expected = expected_values(
+            "INSERT INTO \"users\" (\"id\") VALUES ($1) ON CONFLICT ON CONSTRAINT \"users_pkey\" DO UPDATE SET \"visits\" = $2",
+            vec![1, 2],
+        );
+
+        let update = ConflictUpdate::constraint("users_pkey").set("visits", 2);
+        let query = Insert::single_into("users")
+            .value("id", 1)
+            .build()
+            .on_conflict(OnConflict::DoUpdate(update));
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
     #[test]
     fn test_limit_and_offset_when_both_are_set() {
         let expected = expected_values("SELECT \"users\".* FROM \"users\" LIMIT $1 OFFSET $2", vec![10, 2]);
@@ -205,4 +519,387 @@ mod tests {
         assert_eq!(expected.0, sql);
         assert_eq!(expected.1, params);
     }
+
+    #[test]
+    fn test_with_multiple_expressions() {
+        let expected = expected_values(
+            "WITH \"a\" AS (SELECT $1), \"b\" AS (SELECT \"a\".* FROM \"a\") SELECT \"b\".* FROM \"b\" WHERE \"x\" = $2",
+            vec![1, 2],
+        );
+
+        let with = With::new()
+            .query("a", Select::default().value(1))
+            .query("b", Select::from_table("a"));
+
+        let query = Select::from_table("b").so_that("x".equals(2)).with(with);
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_with_recursive() {
+        use crate::{col, val};
+
+        let expected = expected_values(
+            "WITH RECURSIVE \"nums\" (\"n\") AS ((SELECT $1) UNION ALL (SELECT (\"n\" + $2) FROM \"nums\" WHERE \"n\" < $3)) \
+             SELECT \"nums\".* FROM \"nums\"",
+            vec![1, 1, 3],
+        );
+
+        let start = Select::default().value(1);
+        let step = Select::from_table("nums")
+            .value(col!("n") + val!(1))
+            .so_that("n".less_than(3));
+
+        let nums = CommonTableExpression::new("nums", Union::new(start).all(step)).columns(vec!["n"]);
+        let query = Select::from_table("nums").with(With::new().recursive().cte(nums));
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_delete_returning() {
+        let expected = expected_values(
+            "DELETE FROM \"users\" WHERE \"id\" = $1 RETURNING \"users\".\"id\"",
+            vec![1],
+        );
+
+        let query = Delete::from_table("users")
+            .so_that("id".equals(1))
+            .returning(vec![("users", "id")]);
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_distinct_on() {
+        let expected = expected_values(
+            "SELECT DISTINCT ON (\"user_id\", \"kind\") \"id\" FROM \"posts\" WHERE \"published\" = $1 ORDER BY \"user_id\", \"kind\"",
+            vec![true],
+        );
+
+        let query = Select::from_table("posts")
+            .column("id")
+            .distinct_on(vec!["user_id", "kind"])
+            .so_that("published".equals(true))
+            .order_by("user_id")
+            .order_by("kind");
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_aggregate_functions() {
+        let expected = expected_values(
+            "SELECT COUNT(DISTINCT \"user_id\"), AVG(\"score\"), MIN(\"score\"), MAX(\"score\"), \
+             COALESCE(\"nickname\", $1), CAST(\"score\" AS DOUBLE PRECISION) FROM \"posts\"",
+            vec!["none"],
+        );
+
+        let query = Select::from_table("posts")
+            .value(count(Column::new("user_id")).distinct())
+            .value(avg(Column::new("score")))
+            .value(min(Column::new("score")))
+            .value(max(Column::new("score")))
+            .value(coalesce(vec![
+                DatabaseValue::from(Column::new("nickname")),
+                DatabaseValue::from("none"),
+            ]))
+            .value(cast(Column::new("score"), CastType::Float));
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_window_frames() {
+        let expected = expected_values(
+            "SELECT SUM(\"amount\") OVER(ORDER BY \"day\" RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW), \
+             LEAD(\"amount\", 1, $1) OVER(PARTITION BY \"user_id\" ORDER BY \"day\"), \
+             LAST_VALUE(\"amount\") OVER(ORDER BY \"day\" ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) \
+             FROM \"orders\"",
+            vec![0],
+        );
+
+        let running = Over::new()
+            .order_by("day")
+            .range(FrameBound::UnboundedPreceding, FrameBound::CurrentRow);
+
+        let rest = Over::new()
+            .order_by("day")
+            .rows(FrameBound::CurrentRow, FrameBound::UnboundedFollowing);
+
+        let query = Select::from_table("orders")
+            .value(sum(Column::new("amount")).over(running))
+            .value(
+                lead(Column::new("amount"))
+                    .default(0)
+                    .partition_by("user_id")
+                    .order_by("day"),
+            )
+            .value(last_value(Column::new("amount")).over(rest));
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_case_in_group_by() {
+        let expected = expected_values(
+            "SELECT CASE WHEN \"age\" < $1 THEN $2 ELSE $3 END, COUNT(*) FROM \"users\" \
+             GROUP BY CASE WHEN \"age\" < $4 THEN $5 ELSE $6 END",
+            vec![
+                ParameterizedValue::from(18),
+                ParameterizedValue::from("minor"),
+                ParameterizedValue::from("adult"),
+                ParameterizedValue::from(18),
+                ParameterizedValue::from("minor"),
+                ParameterizedValue::from("adult"),
+            ],
+        );
+
+        let age_group = case().when("age".less_than(18), "minor").otherwise("adult");
+
+        let query = Select::from_table("users")
+            .value(age_group.clone())
+            .value(count(asterisk()))
+            .group_by(age_group);
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_case_without_branches() {
+        let expected = expected_values("SELECT $1, NULL FROM \"users\"", vec!["adult"]);
+
+        let query = Select::from_table("users")
+            .value(case().otherwise("adult"))
+            .value(case());
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_ilike_and_regex() {
+        let expected = expected_values(
+            "SELECT \"users\".* FROM \"users\" WHERE (\"name\" ILIKE $1 AND \"email\" ~ $2 \
+             AND \"nick\" NOT LIKE $3)",
+            vec!["%mus\\_ti%", "^m", "%100\\%%"],
+        );
+
+        let conditions = "name"
+            .ilike("mus_ti")
+            .and("email".matches_regex("^m"))
+            .and("nick".not_like("100%"));
+
+        let query = Select::from_table("users").so_that(conditions);
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    #[cfg(feature = "json-1")]
+    fn test_json_operations() {
+        let expected = expected_values(
+            "SELECT (\"data\" #>> ARRAY[$1, $2]) FROM \"users\" \
+             WHERE (((\"data\" -> $3) ->> 0) = $4 AND \"data\" ? $5)",
+            vec!["owner", "name", "tags", "cat", "email"],
+        );
+
+        let owner = "data".json_get_path_text(vec!["owner", "name"]);
+        let conditions = "data"
+            .json_get("tags")
+            .json_get_text(0)
+            .equals("cat")
+            .and("data".json_has_key("email"));
+
+        let query = Select::from_table("users").value(owner).so_that(conditions);
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    #[cfg(feature = "json-1")]
+    fn test_json_has_any_of_no_keys() {
+        let query = Select::from_table("users").so_that("data".json_has_any_key(Vec::<&str>::new()));
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!("SELECT \"users\".* FROM \"users\" WHERE 1=0", sql);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_exists_and_quantified_comparisons() {
+        let expected = expected_values(
+            "SELECT \"users\".* FROM \"users\" WHERE (EXISTS \
+             (SELECT \"orders\".* FROM \"orders\" WHERE \"orders\".\"user_id\" = \"users\".\"id\") \
+             AND \"id\" <> ANY (SELECT \"user_id\" FROM \"bans\" WHERE \"active\" = $1))",
+            vec![true],
+        );
+
+        let orders = Select::from_table("orders").so_that(("orders", "user_id").equals(Column::from(("users", "id"))));
+        let bans = Select::from_table("bans")
+            .column("user_id")
+            .so_that("active".equals(true));
+
+        let conditions = exists(orders).and("id".any(ComparisonOperator::NotEquals, bans));
+        let query = Select::from_table("users").so_that(conditions);
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    fn test_array_operators() {
+        let tags = ParameterizedValue::array(vec!["rust", "sql"]);
+        let expected = (
+            "SELECT ARRAY_LENGTH(\"tags\", 1) FROM \"posts\" WHERE \
+             (\"tags\" @> $1 AND \"tags\" <@ \"allowed_tags\" AND \"tags\" && $2 AND $3 = ANY(\"tags\"))",
+            vec![tags.clone(), tags.clone(), ParameterizedValue::from("cat")],
+        );
+
+        let conditions = "tags"
+            .array_contains(tags.clone())
+            .and("tags".array_contained_by(Column::from("allowed_tags")))
+            .and("tags".array_overlaps(tags))
+            .and(DatabaseValue::from("cat").equals_any(Column::from("tags")));
+
+        let query = Select::from_table("posts")
+            .value(array_length(Column::from("tags")))
+            .so_that(conditions);
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    fn test_in_selection_with_a_bound_array() {
+        let ids = ParameterizedValue::array(vec![1, 2, 3]);
+        let expected = (
+            "SELECT \"users\".* FROM \"users\" WHERE (\"id\" = ANY($1) AND \"group_id\" <> ALL($2))",
+            vec![ids.clone(), ids.clone()],
+        );
+
+        let conditions = "id".in_selection(ids.clone()).and("group_id".not_in_selection(ids));
+        let query = Select::from_table("users").so_that(conditions);
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    fn test_huge_in_lists_are_bound_as_arrays() {
+        let ids: Vec<i64> = (0..1001).collect();
+        let query = Select::from_table("users").so_that("id".in_selection(ids.clone()));
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!("SELECT \"users\".* FROM \"users\" WHERE \"id\" = ANY($1)", sql);
+        assert_eq!(vec![ParameterizedValue::array(ids.clone())], params);
+
+        let query = Select::from_table("users").so_that("id".not_in_selection(ids.clone()));
+        let (sql, _) = Postgres::build(query);
+
+        assert_eq!("SELECT \"users\".* FROM \"users\" WHERE \"id\" <> ALL($1)", sql);
+
+        let mut mixed: Vec<ParameterizedValue> = ids.into_iter().map(ParameterizedValue::from).collect();
+        mixed.push(ParameterizedValue::from("musti"));
+
+        let query = Select::from_table("users").so_that("id".in_selection(mixed));
+        let (sql, params) = Postgres::build(query);
+
+        assert!(sql.ends_with("$1001,$1002)"));
+        assert_eq!(1002, params.len());
+    }
+
+    #[test]
+    #[cfg(feature = "postgresql")]
+    fn test_copy_in() {
+        let sql = Postgres::build_copy_in(Table::from("users").alias("u"), vec!["id".into(), "name".into()]);
+
+        assert_eq!("COPY \"users\" (\"id\",\"name\") FROM STDIN (FORMAT binary)", sql);
+    }
+
+    #[test]
+    fn test_create_table() {
+        let expected_sql = "CREATE TABLE \"app\".\"posts\" (\"id\" BIGSERIAL PRIMARY KEY, \
+                            \"author_id\" UUID NOT NULL, \"body\" BYTEA DEFAULT '\\xCAFE'::bytea, \
+                            \"rating\" DOUBLE PRECISION, \"created_at\" TIMESTAMPTZ NOT NULL DEFAULT now(), \
+                            FOREIGN KEY (\"author_id\") REFERENCES \"app\".\"users\" (\"id\"))";
+
+        let query = CreateTable::new(Table::from("posts").database("app"))
+            .column(
+                ColumnDefinition::new("id", ColumnType::BigInt)
+                    .primary_key()
+                    .auto_increment(),
+            )
+            .column(ColumnDefinition::new("author_id", ColumnType::Uuid).not_null())
+            .column(
+                ColumnDefinition::new("body", ColumnType::Bytes)
+                    .default(ParameterizedValue::Bytes(vec![0xca, 0xfe].into())),
+            )
+            .column(ColumnDefinition::new("rating", ColumnType::Double))
+            .column(
+                ColumnDefinition::new("created_at", ColumnType::DateTime)
+                    .not_null()
+                    .default_expression("now()"),
+            )
+            .foreign_key(ForeignKey::new(
+                vec!["author_id"],
+                Table::from("users").database("app"),
+                vec!["id"],
+            ));
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected_sql, sql);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_partial_index_values_are_written_into_the_statement() {
+        let conditions = "status".equals("active").and("deleted_at".is_null());
+        let query = CreateIndex::new("users_email_key", Table::from("users").database("app"), vec!["email"])
+            .unique()
+            .so_that(conditions);
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(
+            "CREATE UNIQUE INDEX \"users_email_key\" ON \"app\".\"users\" (\"email\") \
+             WHERE (\"status\" = 'active' AND \"deleted_at\" IS NULL)",
+            sql
+        );
+        assert!(params.is_empty());
+    }
 }
@@ -1,6 +1,9 @@
 use crate::{ast::*, visitor::Visitor};
 
-use std::fmt::{self, Write};
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
 
 /// A visitor to generate queries for the SQLite database.
 ///
@@ -9,6 +12,10 @@ use std::fmt::{self, Write};
 pub struct Sqlite<'a> {
     query: String,
     parameters: Vec<ParameterizedValue<'a>>,
+    inline_values: bool,
+    iso8601_datetimes: bool,
+    quantified_depth: usize,
+    unsupported: Option<&'static str>,
 }
 
 impl<'a> Visitor<'a> for Sqlite<'a> {
@@ -19,13 +26,7 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
     where
         Q: Into<Query<'a>>,
     {
-        let mut sqlite = Sqlite {
-            query: String::with_capacity(4096),
-            parameters: Vec::with_capacity(128),
-        };
-
-        Sqlite::visit_query(&mut sqlite, query.into());
-
+        let sqlite = Sqlite::render(query.into(), false);
         (sqlite.query, sqlite.parameters)
     }
 
@@ -33,10 +34,31 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
         write!(&mut self.query, "{}", s)
     }
 
+    /// SQLite has no `DISTINCT ON`, the connector refuses the query.
+    fn visit_distinct_on(&mut self, columns: Vec<Column<'a>>) -> fmt::Result {
+        self.unsupported("DISTINCT ON");
+
+        self.write("DISTINCT ON (")?;
+        self.visit_columns(columns.into_iter().map(|c| c.into()).collect())?;
+        self.write(") ")
+    }
+
+    /// SQLite has no arrays, the connector refuses the query and nothing is
+    /// rendered.
+    #[cfg(all(feature = "array", feature = "postgresql"))]
+    fn visit_array_compare(&mut self, _: Compare<'a>) -> fmt::Result {
+        self.unsupported("Array comparisons");
+        Ok(())
+    }
+
     fn visit_insert(&mut self, insert: Insert<'a>) -> fmt::Result {
+        if let Some(with) = insert.with {
+            self.visit_with(with)?;
+        }
+
         match insert.on_conflict {
             Some(OnConflict::DoNothing) => self.write("INSERT OR IGNORE")?,
-            None => self.write("INSERT")?,
+            _ => self.write("INSERT")?,
         };
 
         self.write(" INTO ")?;
@@ -69,6 +91,12 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
             }
         }
 
+        if let Some(OnConflict::DoUpdate(update)) = insert.on_conflict {
+            self.visit_upsert(update)?;
+        }
+
+        // The bundled SQLite has no `RETURNING`, the result set carries the
+        // last insert id instead.
         Ok(())
     }
 
@@ -80,6 +108,10 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
         self.parameters.push(value);
     }
 
+    fn inline_values(&self) -> bool {
+        self.inline_values
+    }
+
     fn visit_limit_and_offset(
         &mut self,
         limit: Option<ParameterizedValue<'a>>,
@@ -112,6 +144,335 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
         self.write("GROUP_CONCAT")?;
         self.surround_with("(", ")", |ref mut s| s.visit_database_value(value))
     }
+
+    /// `CURRENT_TIMESTAMP` is text, which SQLite sorts after every integer.
+    /// The current time is written in the format the connector stores
+    /// datetimes in instead, by default milliseconds since the Unix epoch.
+    fn visit_current_timestamp(&mut self) -> fmt::Result {
+        if self.iso8601_datetimes {
+            self.write("(strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))")
+        } else {
+            self.write("(CAST(ROUND((julianday('now') - 2440587.5) * 86400000) AS INTEGER))")
+        }
+    }
+
+    /// SQLite binds at most 999 parameters, so long lists of integers and
+    /// strings are bound as a single JSON array, read with `json_each`.
+    #[cfg(feature = "json-1")]
+    fn visit_in(&mut self, left: DatabaseValue<'a>, right: DatabaseValue<'a>, negated: bool) -> fmt::Result {
+        self.visit_database_value(left)?;
+        self.write(if negated { " NOT IN " } else { " IN " })?;
+
+        match right {
+            DatabaseValue::Row(row) if row.values.len() > IN_LIST_JSON_THRESHOLD && !self.inline_values => {
+                match json_array(row) {
+                    Ok(array) => self.surround_with("(SELECT value FROM json_each(", "))", |ref mut s| {
+                        s.visit_parameterized(ParameterizedValue::Json(array))
+                    }),
+                    Err(row) => self.visit_row(row),
+                }
+            }
+            right => self.visit_database_value(right),
+        }
+    }
+
+    /// SQLite has no `CONCAT` function.
+    fn visit_concat(&mut self, values: Vec<DatabaseValue<'a>>) -> fmt::Result {
+        let len = values.len();
+
+        self.surround_with("(", ")", |ref mut s| {
+            for (i, value) in values.into_iter().enumerate() {
+                s.visit_database_value(value)?;
+
+                if i < (len - 1) {
+                    s.write(" || ")?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    fn visit_cast_type(&mut self, typ: CastType<'a>) -> fmt::Result {
+        match typ {
+            CastType::Int => self.write("INTEGER"),
+            CastType::Float => self.write("REAL"),
+            CastType::Decimal => self.write("NUMERIC"),
+            CastType::Text => self.write("TEXT"),
+            CastType::Custom(name) => self.write(name),
+        }
+    }
+
+    /// SQLite has no default escape character for `LIKE`.
+    fn visit_like_escape(&mut self) -> fmt::Result {
+        self.write(" ESCAPE '\\'")
+    }
+
+    /// SQLite returns strings without quotes, so the value is the same with
+    /// and without `as_text`.
+    #[cfg(feature = "json-1")]
+    fn visit_json_extract(&mut self, extract: JsonExtract<'a>) -> fmt::Result {
+        let path = json_path(&extract.path);
+
+        self.write("json_extract")?;
+        self.surround_with("(", ")", |ref mut s| {
+            s.visit_database_value(*extract.expr)?;
+            s.write(", ")?;
+            s.visit_parameterized(ParameterizedValue::Text(path.into()))
+        })
+    }
+
+    /// Uses the `json_contains` function registered by the connector.
+    #[cfg(feature = "json-1")]
+    fn visit_json_contains(&mut self, left: DatabaseValue<'a>, right: DatabaseValue<'a>) -> fmt::Result {
+        self.write("json_contains")?;
+        self.surround_with("(", ")", |ref mut s| {
+            s.visit_database_value(left)?;
+            s.write(", ")?;
+            s.visit_database_value(right)
+        })
+    }
+
+    /// A key exists when `json_type` finds it, including keys with a `null`
+    /// value.
+    #[cfg(feature = "json-1")]
+    fn visit_json_has_any_key(&mut self, left: DatabaseValue<'a>, keys: Vec<Cow<'a, str>>) -> fmt::Result {
+        if keys.is_empty() {
+            return self.write("1=0");
+        }
+
+        let has_key = |s: &mut Self, key| {
+            let path = json_path(&[JsonPathElement::Key(key)]);
+
+            s.write("json_type")?;
+            s.surround_with("(", ")", |ref mut s| {
+                s.visit_database_value(left.clone())?;
+                s.write(", ")?;
+                s.visit_parameterized(ParameterizedValue::Text(path.into()))
+            })?;
+
+            s.write(" IS NOT NULL")
+        };
+
+        if keys.len() == 1 {
+            return has_key(self, keys.into_iter().next().unwrap());
+        }
+
+        self.surround_with("(", ")", |ref mut s| {
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    s.write(" OR ")?;
+                }
+
+                has_key(s, key)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// SQLite has no quantified comparisons, so the query is checked for a
+    /// matching row with `EXISTS`. Unlike `ANY`, the result is false instead
+    /// of `NULL` when no comparison is true and some are `NULL`, which only
+    /// makes a difference when negated or selected as a value.
+    fn visit_any(
+        &mut self,
+        left: DatabaseValue<'a>,
+        operator: ComparisonOperator,
+        selection: Select<'a>,
+    ) -> fmt::Result {
+        let (inner, value) = quantified_selection(selection, self.quantified_depth);
+        let inner = inner.so_that(operator.compare(left, value));
+
+        self.write("EXISTS ")?;
+        self.visit_quantified(inner)
+    }
+
+    /// SQLite has no quantified comparisons, so the query is checked for a
+    /// row where the comparison is false or `NULL` with `NOT EXISTS`. Unlike
+    /// `ALL`, the result is false instead of `NULL` when no comparison is
+    /// false and some are `NULL`, which only makes a difference when negated
+    /// or selected as a value.
+    fn visit_all(
+        &mut self,
+        left: DatabaseValue<'a>,
+        operator: ComparisonOperator,
+        selection: Select<'a>,
+    ) -> fmt::Result {
+        let (inner, value) = quantified_selection(selection, self.quantified_depth);
+        let matches = case().when(operator.compare(left, value), 1).otherwise(0);
+        let inner = inner.so_that(DatabaseValue::from(matches).equals(0));
+
+        self.write("NOT EXISTS ")?;
+        self.visit_quantified(inner)
+    }
+
+    /// SQLite does not allow parentheses around the `SELECT` statements of a
+    /// compound query, nor ordering or limits in them. Such members are
+    /// selected from a subquery instead.
+    fn visit_union(&mut self, mut ua: Union<'a>) -> fmt::Result {
+        let len = ua.selects.len();
+        let mut types = ua.types.drain(0..);
+
+        for (i, sel) in ua.selects.into_iter().enumerate() {
+            if sel.ordering.is_empty() && sel.limit.is_none() && sel.offset.is_none() {
+                self.visit_select(sel)?;
+            } else {
+                self.write("SELECT * FROM ")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_select(sel))?;
+            }
+
+            if i < (len - 1) {
+                let typ = types.next().unwrap();
+
+                self.write(" ")?;
+                self.write(typ)?;
+                self.write(" ")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_column_type(&mut self, column_type: ColumnType<'a>, auto_increment: bool) -> fmt::Result {
+        match column_type {
+            // Only an `INTEGER PRIMARY KEY` can be auto-incremented.
+            ColumnType::Integer => self.write("INTEGER"),
+            ColumnType::BigInt if auto_increment => self.write("INTEGER"),
+            ColumnType::BigInt => self.write("BIGINT"),
+            ColumnType::Boolean => self.write("BOOLEAN"),
+            ColumnType::Text => self.write("TEXT"),
+            ColumnType::Varchar(length) => self.write(format!("VARCHAR({})", length)),
+            ColumnType::Double => self.write("REAL"),
+            ColumnType::Decimal(precision, scale) => self.write(format!("DECIMAL({}, {})", precision, scale)),
+            ColumnType::Date => self.write("DATE"),
+            ColumnType::Time => self.write("TIME"),
+            ColumnType::DateTime => self.write("DATETIME"),
+            ColumnType::Bytes => self.write("BLOB"),
+            ColumnType::Json => self.write("TEXT"),
+            ColumnType::Uuid => self.write("TEXT"),
+            ColumnType::Custom(name) => self.write(name),
+        }
+    }
+
+    fn visit_auto_increment(&mut self) -> fmt::Result {
+        self.write(" AUTOINCREMENT")
+    }
+
+    /// SQLite qualifies the index name with the database, the table can't
+    /// be qualified.
+    fn visit_index_target(&mut self, name: Cow<'a, str>, mut table: Table<'a>) -> fmt::Result {
+        match table.database.take() {
+            Some(database) => self.delimited_identifiers(&[&*database, &*name])?,
+            None => self.delimited_identifiers(&[&*name])?,
+        }
+
+        self.write(" ON ")?;
+        self.visit_table(table, false)
+    }
+}
+
+impl<'a> Sqlite<'a> {
+    /// Renders the query like `build` for datetimes stored in the given
+    /// format, returning an `UnsupportedFeature` error if it uses something
+    /// SQLite doesn't have.
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn build_supported<Q>(
+        query: Q,
+        datetime_format: crate::connector::DateTimeFormat,
+    ) -> crate::Result<(String, Vec<ParameterizedValue<'a>>)>
+    where
+        Q: Into<Query<'a>>,
+    {
+        let iso8601_datetimes = datetime_format == crate::connector::DateTimeFormat::Iso8601;
+        let sqlite = Sqlite::render(query.into(), iso8601_datetimes);
+
+        match sqlite.unsupported {
+            Some(feature) => {
+                let kind = crate::error::ErrorKind::UnsupportedFeature {
+                    feature,
+                    database: "SQLite",
+                };
+
+                Err(crate::error::Error::builder(kind).build())
+            }
+            None => Ok((sqlite.query, sqlite.parameters)),
+        }
+    }
+
+    fn render(query: Query<'a>, iso8601_datetimes: bool) -> Self {
+        let mut sqlite = Sqlite {
+            query: String::with_capacity(4096),
+            parameters: Vec::with_capacity(128),
+            inline_values: query.is_ddl(),
+            iso8601_datetimes,
+            quantified_depth: 0,
+            unsupported: None,
+        };
+
+        Sqlite::visit_query(&mut sqlite, query);
+
+        sqlite
+    }
+
+    /// Marks the query as unsupported, keeping the first feature found.
+    fn unsupported(&mut self, feature: &'static str) {
+        self.unsupported.get_or_insert(feature);
+    }
+
+    /// The query of a quantified comparison, rendered one level deeper so
+    /// the comparisons nested in it name their queries differently.
+    fn visit_quantified(&mut self, inner: Select<'a>) -> fmt::Result {
+        self.quantified_depth += 1;
+        let result = self.surround_with("(", ")", |ref mut s| s.visit_select(inner));
+        self.quantified_depth -= 1;
+
+        result
+    }
+}
+
+/// `IN` comparisons against more values than this are bound as a single
+/// JSON array, if possible, so huge lists don't hit the parameter limit.
+#[cfg(feature = "json-1")]
+const IN_LIST_JSON_THRESHOLD: usize = 100;
+
+/// Converts a row of integers, strings and nulls into a JSON array,
+/// returning the row back if it has anything else.
+#[cfg(feature = "json-1")]
+fn json_array(row: Row<'_>) -> Result<serde_json::Value, Row<'_>> {
+    let bindable = row.values.iter().all(|value| {
+        matches!(
+            value,
+            DatabaseValue::Parameterized(ParameterizedValue::Null)
+                | DatabaseValue::Parameterized(ParameterizedValue::Integer(_))
+                | DatabaseValue::Parameterized(ParameterizedValue::Text(_))
+                | DatabaseValue::Parameterized(ParameterizedValue::Enum(_))
+        )
+    });
+
+    if !bindable {
+        return Err(row);
+    }
+
+    let values = row.values.into_iter().map(|value| match value {
+        DatabaseValue::Parameterized(ParameterizedValue::Integer(i)) => serde_json::Value::from(i),
+        DatabaseValue::Parameterized(ParameterizedValue::Text(s))
+        | DatabaseValue::Parameterized(ParameterizedValue::Enum(s)) => serde_json::Value::from(s.into_owned()),
+        _ => serde_json::Value::Null,
+    });
+
+    Ok(serde_json::Value::Array(values.collect()))
+}
+
+/// Names the single column of a quantified comparison's query, returning a
+/// selection over its rows and the column to compare against. The name of
+/// the query includes the nesting depth, to not hide the outer queries.
+fn quantified_selection<'a>(selection: Select<'a>, depth: usize) -> (Select<'a>, DatabaseValue<'a>) {
+    let name = format!("quaint_quantified_{}", depth);
+    let cte = CommonTableExpression::new(name.clone(), selection).columns(vec!["quaint_value"]);
+    let inner = Select::from_table(name.clone()).value(1).with(With::new().cte(cte));
+
+    (inner, Column::from((name, "quaint_value")).into())
 }
 
 #[cfg(test)]
@@ -461,6 +822,161 @@ mod tests {
         assert_eq!(default_params(vec![ParameterizedValue::Boolean(true),]), params);
     }
 
+    #[test]
+    fn test_select_distinct_star() {
+        let expected_sql = "SELECT DISTINCT `users`.* FROM `users`";
+        let query = Select::from_table("users").distinct();
+        let (sql, _) = Sqlite::build(query);
+
+        assert_eq!(expected_sql, sql);
+    }
+
+    #[test]
+    fn test_aliased_functions() {
+        let expected_sql = "SELECT SUM(`amount`) AS `total`, CAST(`amount` AS INTEGER) AS `rounded`, \
+                            (`first_name` || `last_name`) AS `name` FROM `orders`";
+
+        let names = vec![Column::new("first_name"), Column::new("last_name")];
+
+        let query = Select::from_table("orders")
+            .value(Function::from(sum(Column::new("amount"))).alias("total"))
+            .value(Function::from(cast(Column::new("amount"), CastType::Int)).alias("rounded"))
+            .value(Function::from(concat(names)).alias("name"));
+
+        let (sql, _) = Sqlite::build(query);
+
+        assert_eq!(expected_sql, sql);
+    }
+
+    #[test]
+    fn test_group_by_before_order_by() {
+        let expected_sql =
+            "SELECT `name`, COUNT(*) FROM `users` GROUP BY `name` HAVING `name` <> ? ORDER BY `name` LIMIT ?";
+
+        let query = Select::from_table("users")
+            .column("name")
+            .value(count(asterisk()))
+            .order_by("name")
+            .group_by("name")
+            .having("name".not_equals("Musti"))
+            .limit(10);
+
+        let (sql, _) = Sqlite::build(query);
+
+        assert_eq!(expected_sql, sql);
+    }
+
+    #[test]
+    fn test_like_with_escaped_wildcards() {
+        let expected = expected_values(
+            "SELECT `products`.* FROM `products` WHERE (`name` LIKE ? ESCAPE '\\' \
+             AND LOWER(`code`) NOT LIKE LOWER(?) ESCAPE '\\' AND `tag` LIKE ?)",
+            vec!["50\\%\\_off%", "%a\\\\b%", "%sale%"],
+        );
+
+        let conditions = "name"
+            .begins_with("50%_off")
+            .and("code".not_ilike("a\\b"))
+            .and("tag".like("sale"));
+
+        let query = Select::from_table("products").so_that(conditions);
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    #[cfg(feature = "json-1")]
+    fn test_json_operations() {
+        let expected = expected_values(
+            "SELECT json_extract(`data`, ?) FROM `users` WHERE \
+             ((json_type(`data`, ?) IS NOT NULL OR json_type(`data`, ?) IS NOT NULL) \
+             AND json_contains(`data`, ?))",
+            vec![
+                ParameterizedValue::from("$.\"tags\"[0]"),
+                ParameterizedValue::from("$.\"email\""),
+                ParameterizedValue::from("$.\"say \\\"hi\\\"\""),
+                ParameterizedValue::from(serde_json::json!({ "admin": true })),
+            ],
+        );
+
+        let conditions = "data"
+            .json_has_any_key(vec!["email", "say \"hi\""])
+            .and("data".json_contains(serde_json::json!({ "admin": true })));
+
+        let query = Select::from_table("users")
+            .value("data".json_get_path(vec![JsonPathElement::from("tags"), 0.into()]))
+            .so_that(conditions);
+
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    #[cfg(feature = "json-1")]
+    fn test_json_has_any_of_no_keys() {
+        let query = Select::from_table("users").so_that("data".json_has_any_key(Vec::<&str>::new()));
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!("SELECT `users`.* FROM `users` WHERE 1=0", sql);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_quantified_comparisons() {
+        let expected = expected_values(
+            "SELECT `products`.* FROM `products` WHERE (\
+             EXISTS (WITH `quaint_quantified_0` (`quaint_value`) AS (SELECT `price` FROM `offers`) \
+             SELECT ? FROM `quaint_quantified_0` WHERE `price` > `quaint_quantified_0`.`quaint_value`) AND \
+             NOT EXISTS (WITH `quaint_quantified_0` (`quaint_value`) AS (SELECT `stock` FROM `warehouses`) \
+             SELECT ? FROM `quaint_quantified_0` WHERE CASE WHEN `stock` <= `quaint_quantified_0`.`quaint_value` \
+             THEN ? ELSE ? END = ?))",
+            vec![1, 1, 1, 0, 0],
+        );
+
+        let conditions = "price"
+            .any(
+                ComparisonOperator::GreaterThan,
+                Select::from_table("offers").column("price"),
+            )
+            .and("stock".all(
+                ComparisonOperator::LessThanOrEquals,
+                Select::from_table("warehouses").column("stock"),
+            ));
+
+        let query = Select::from_table("products").so_that(conditions);
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_nested_quantified_comparisons() {
+        let expected = expected_values(
+            "SELECT `products`.* FROM `products` WHERE \
+             EXISTS (WITH `quaint_quantified_0` (`quaint_value`) AS (SELECT `offers`.`price` FROM `offers` WHERE \
+             EXISTS (WITH `quaint_quantified_1` (`quaint_value`) AS (SELECT `limit` FROM `budgets`) \
+             SELECT ? FROM `quaint_quantified_1` WHERE `offers`.`price` < `quaint_quantified_1`.`quaint_value`)) \
+             SELECT ? FROM `quaint_quantified_0` WHERE `price` > `quaint_quantified_0`.`quaint_value`)",
+            vec![1, 1],
+        );
+
+        let budgets = Select::from_table("budgets").column("limit");
+        let offers = Select::from_table("offers")
+            .column(("offers", "price"))
+            .so_that(("offers", "price").any(ComparisonOperator::LessThan, budgets));
+
+        let query = Select::from_table("products").so_that("price".any(ComparisonOperator::GreaterThan, offers));
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
     #[test]
     fn test_column_aliasing() {
         let expected_sql = "SELECT `bar` AS `foo` FROM `meow`";
@@ -470,6 +986,173 @@ mod tests {
         assert_eq!(expected_sql, sql);
     }
 
+    #[test]
+    fn test_insert_on_conflict_do_update() {
+        let expected = expected_values(
+            "INSERT INTO `users` (`id`, `name`) VALUES (?,?) ON CONFLICT (`id`) DO UPDATE SET `name` = excluded.`name`",
+            vec![ParameterizedValue::from(1), ParameterizedValue::from("Musti")],
+        );
+
+        let update = ConflictUpdate::columns(vec!["id"]).set("name", excluded("name"));
+        let query = Insert::single_into("users")
+            .value("id", 1)
+            .value("name", "Musti")
+            .build()
+            .on_conflict(OnConflict::DoUpdate(update));
+
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_union_without_parentheses() {
+        let expected = expected_values("SELECT ? UNION ALL SELECT ? UNION SELECT ?", vec![1, 2, 3]);
+
+        let query = Union::new(Select::default().value(1))
+            .all(Select::default().value(2))
+            .distinct(Select::default().value(3));
+
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_union_members_with_ordering_or_limits() {
+        let expected = expected_values(
+            "SELECT * FROM (SELECT `users`.`id` FROM `users` ORDER BY `id` DESC LIMIT ?) UNION ALL SELECT ?",
+            vec![10, 1],
+        );
+
+        let newest = Select::from_table("users")
+            .column(("users", "id"))
+            .order_by("id".descend())
+            .limit(10);
+
+        let query = Union::new(newest).all(Select::default().value(1));
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_with_insert() {
+        let expected = expected_values(
+            "WITH `defaults` AS (SELECT ?) INSERT INTO `users` (`id`) VALUES (?)",
+            vec![1, 2],
+        );
+
+        let query = Insert::single_into("users")
+            .value("id", 2)
+            .build()
+            .with(With::new().query("defaults", Select::default().value(1)));
+
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_insert_returning_is_ignored() {
+        let expected = expected_values(
+            "INSERT INTO `users` (`name`) VALUES (?)",
+            vec![ParameterizedValue::from("Musti")],
+        );
+
+        let query = Insert::single_into("users")
+            .value("name", "Musti")
+            .build()
+            .returning(vec!["id"]);
+
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_update_returning() {
+        let expected = expected_values(
+            "UPDATE `users` SET `name` = ? WHERE `id` = ? RETURNING `id`, `name`",
+            vec![ParameterizedValue::from("Musti"), ParameterizedValue::from(1)],
+        );
+
+        let query = Update::table("users")
+            .set("name", "Musti")
+            .so_that("id".equals(1))
+            .returning(vec!["id", "name"]);
+
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_create_table() {
+        let expected_sql = "CREATE TABLE IF NOT EXISTS `posts` (`id` INTEGER PRIMARY KEY AUTOINCREMENT, \
+                            `user_id` INTEGER NOT NULL, `title` VARCHAR(255) NOT NULL DEFAULT 'it''s new', \
+                            `published` BOOLEAN NOT NULL DEFAULT FALSE, `data` BLOB DEFAULT X'CAFE', \
+                            UNIQUE (`user_id`, `title`), FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) \
+                            ON DELETE CASCADE ON UPDATE SET NULL)";
+
+        let query = CreateTable::new("posts")
+            .if_not_exists()
+            .column(
+                ColumnDefinition::new("id", ColumnType::BigInt)
+                    .primary_key()
+                    .auto_increment(),
+            )
+            .column(ColumnDefinition::new("user_id", ColumnType::Integer).not_null())
+            .column(
+                ColumnDefinition::new("title", ColumnType::Varchar(255))
+                    .not_null()
+                    .default("it's new"),
+            )
+            .column(
+                ColumnDefinition::new("published", ColumnType::Boolean)
+                    .not_null()
+                    .default(false),
+            )
+            .column(
+                ColumnDefinition::new("data", ColumnType::Bytes)
+                    .default(ParameterizedValue::Bytes(vec![0xca, 0xfe].into())),
+            )
+            .unique(vec!["user_id", "title"])
+            .foreign_key(
+                ForeignKey::new(vec!["user_id"], "users", vec!["id"])
+                    .on_delete(ReferentialAction::Cascade)
+                    .on_update(ReferentialAction::SetNull),
+            );
+
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(expected_sql, sql);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_create_and_drop_index_in_an_attached_database() {
+        let table = Table::from("users").database("other");
+
+        let query = CreateIndex::new("users_name_idx", table.clone(), vec!["name"]).so_that("name".like("A"));
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(
+            "CREATE INDEX `other`.`users_name_idx` ON `users` (`name`) WHERE `name` LIKE '%A%'",
+            sql
+        );
+        assert!(params.is_empty());
+
+        let (sql, _) = Sqlite::build(DropIndex::new("users_name_idx", table).if_exists());
+        assert_eq!("DROP INDEX IF EXISTS `other`.`users_name_idx`", sql);
+    }
+
     #[cfg(feature = "sqlite")]
     fn sqlite_harness() -> ::rusqlite::Connection {
         let conn = ::rusqlite::Connection::open_in_memory().unwrap();
@@ -492,6 +1175,36 @@ mod tests {
         conn
     }
 
+    #[test]
+    #[cfg(feature = "json-1")]
+    fn test_huge_in_lists_are_bound_as_json() {
+        let ids: Vec<i64> = (0..101).collect();
+        let query = Select::from_table("users").so_that("id".in_selection(ids.clone()));
+        let (sql, params) = Sqlite::build(query);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE `id` IN (SELECT value FROM json_each(?))",
+            sql
+        );
+        assert_eq!(vec![ParameterizedValue::Json(serde_json::json!(ids))], params);
+
+        let query = Select::from_table("users").so_that("id".not_in_selection(ids.clone()));
+        let (sql, _) = Sqlite::build(query);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE `id` NOT IN (SELECT value FROM json_each(?))",
+            sql
+        );
+
+        let mut mixed: Vec<ParameterizedValue> = ids.into_iter().map(ParameterizedValue::from).collect();
+        mixed.push(ParameterizedValue::from(1.5));
+
+        let query = Select::from_table("users").so_that("id".in_selection(mixed));
+        let (_, params) = Sqlite::build(query);
+
+        assert_eq!(102, params.len());
+    }
+
     #[test]
     #[cfg(feature = "sqlite")]
     fn bind_test_1() {